@@ -6,6 +6,11 @@ use std::io::Error;
 include!("src/cli.rs");
 
 fn main() -> Result<(), Error> {
+    // completions should be generated again when the cli changes, and embedded migrations should
+    // be updated when they change
+    println!("cargo:rerun-if-changed=src/cli.rs");
+    println!("cargo:rerun-if-changed=migrations");
+
     let mut cmd = Cli::command();
     for &shell in Shell::value_variants() {
         let path = generate_to(shell, &mut cmd, "forest", "./completions")?;
@@ -21,37 +21,75 @@ Register-ArgumentCompleter -Native -CommandName 'forest' -ScriptBlock {
 
     $completions = @(switch ($command) {
         'forest' {
-            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
-            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--forest', '--forest', [CompletionResultType]::ParameterName, 'Name of the forest to use instead of the current one')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
             [CompletionResult]::new('-V', '-V ', [CompletionResultType]::ParameterName, 'Print version')
             [CompletionResult]::new('--version', '--version', [CompletionResultType]::ParameterName, 'Print version')
             [CompletionResult]::new('task', 'task', [CompletionResultType]::ParameterValue, 'Perform operations on tasks')
             [CompletionResult]::new('tree', 'tree', [CompletionResultType]::ParameterValue, 'Perform operations on trees')
             [CompletionResult]::new('note', 'note', [CompletionResultType]::ParameterValue, 'Perform operations on notes')
+            [CompletionResult]::new('frame', 'frame', [CompletionResultType]::ParameterValue, 'Perform operations on time tracking frames')
+            [CompletionResult]::new('profile', 'profile', [CompletionResultType]::ParameterValue, 'Manage separate forests, each with its own trees, frames and notes')
+            [CompletionResult]::new('db', 'db', [CompletionResultType]::ParameterValue, 'Manage the schema of the forest database')
             [CompletionResult]::new('switch', 'switch', [CompletionResultType]::ParameterValue, 'Switch to another tree')
             [CompletionResult]::new('start', 'start', [CompletionResultType]::ParameterValue, 'Start recording time')
             [CompletionResult]::new('stop', 'stop', [CompletionResultType]::ParameterValue, 'Stop current time recording')
+            [CompletionResult]::new('cancel', 'cancel', [CompletionResultType]::ParameterValue, 'Discard current time recording')
+            [CompletionResult]::new('restart', 'restart', [CompletionResultType]::ParameterValue, 'Start recording time on the most recently tracked tree or task')
+            [CompletionResult]::new('import', 'import', [CompletionResultType]::ParameterValue, 'Import time records from another time tracker')
+            [CompletionResult]::new('backup', 'backup', [CompletionResultType]::ParameterValue, 'Save the whole forest, including notes, to an archive')
+            [CompletionResult]::new('restore', 'restore', [CompletionResultType]::ParameterValue, 'Restore the forest from an archive created by `forest backup`')
+            [CompletionResult]::new('export', 'export', [CompletionResultType]::ParameterValue, 'Export the forest to files readable by other applications')
+            [CompletionResult]::new('pause', 'pause', [CompletionResultType]::ParameterValue, 'Pause current time recording, without creating a note')
+            [CompletionResult]::new('resume', 'resume', [CompletionResultType]::ParameterValue, 'Resume paused time recording on the same tree or task')
             [CompletionResult]::new('status', 'status', [CompletionResultType]::ParameterValue, 'Show current time recording')
-            [CompletionResult]::new('report', 'report', [CompletionResultType]::ParameterValue, 'Reports time spent on each tree')
+            [CompletionResult]::new('report', 'report', [CompletionResultType]::ParameterValue, 'Reports time spent on each tree, or on each task of a tree')
+            [CompletionResult]::new('config', 'config', [CompletionResultType]::ParameterValue, 'Get or set configuration options')
+            [CompletionResult]::new('agenda', 'agenda', [CompletionResultType]::ParameterValue, 'Show overdue, today''s and upcoming tasks across all trees')
             [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
             break
         }
         'forest;task' {
-            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
-            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--forest', '--forest', [CompletionResultType]::ParameterName, 'Name of the forest to use instead of the current one')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
             [CompletionResult]::new('list', 'list', [CompletionResultType]::ParameterValue, 'List tasks in the current tree')
             [CompletionResult]::new('add', 'add', [CompletionResultType]::ParameterValue, 'Add a new task to the current tree')
+            [CompletionResult]::new('import', 'import', [CompletionResultType]::ParameterValue, 'Import tasks from another task manager into the current tree')
             [CompletionResult]::new('remove', 'remove', [CompletionResultType]::ParameterValue, 'Remove a task from the current tree')
             [CompletionResult]::new('rename', 'rename', [CompletionResultType]::ParameterValue, 'Rename a task in the current tree')
             [CompletionResult]::new('show', 'show', [CompletionResultType]::ParameterValue, 'Show description of a task in the current tree')
             [CompletionResult]::new('edit', 'edit', [CompletionResultType]::ParameterValue, 'Edit description of a task in the current tree')
             [CompletionResult]::new('priority', 'priority', [CompletionResultType]::ParameterValue, 'Set priority of a task in the current tree')
+            [CompletionResult]::new('done', 'done', [CompletionResultType]::ParameterValue, 'Mark a task of the current tree as done')
+            [CompletionResult]::new('start', 'start', [CompletionResultType]::ParameterValue, 'Mark a task of the current tree as in progress')
+            [CompletionResult]::new('cancel', 'cancel', [CompletionResultType]::ParameterValue, 'Mark a task of the current tree as cancelled')
+            [CompletionResult]::new('reopen', 'reopen', [CompletionResultType]::ParameterValue, 'Mark a done or cancelled task of the current tree as todo again')
+            [CompletionResult]::new('move', 'move', [CompletionResultType]::ParameterValue, 'Move a task of the current tree and its subtasks under another parent, possibly in another tree')
+            [CompletionResult]::new('copy', 'copy', [CompletionResultType]::ParameterValue, 'Copy a task of the current tree and its subtasks under another parent, possibly in another tree')
+            [CompletionResult]::new('depend', 'depend', [CompletionResultType]::ParameterValue, 'Make a task of the current tree depend on another task, possibly in another tree')
+            [CompletionResult]::new('undepend', 'undepend', [CompletionResultType]::ParameterValue, 'Remove a dependency of a task of the current tree')
+            [CompletionResult]::new('recur', 'recur', [CompletionResultType]::ParameterValue, 'Set the recurrence rule of a task in the current tree')
+            [CompletionResult]::new('due', 'due', [CompletionResultType]::ParameterValue, 'Set due and scheduled dates of a task in the current tree')
+            [CompletionResult]::new('tag', 'tag', [CompletionResultType]::ParameterValue, 'Add tags to a task in the current tree')
+            [CompletionResult]::new('untag', 'untag', [CompletionResultType]::ParameterValue, 'Remove tags from a task in the current tree')
             [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
             break
         }
         'forest;task;list' {
-            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
-            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('-s', '-s', [CompletionResultType]::ParameterName, 'Only display tasks with the given status(es)')
+            [CompletionResult]::new('--status', '--status', [CompletionResultType]::ParameterName, 'Only display tasks with the given status(es)')
+            [CompletionResult]::new('--tag', '--tag', [CompletionResultType]::ParameterName, 'Only display tasks with one of the given tag(s)')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--forest', '--forest', [CompletionResultType]::ParameterName, 'Name of the forest to use instead of the current one')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
             break
         }
         'forest;task;add' {
@@ -59,45 +97,211 @@ Register-ArgumentCompleter -Native -CommandName 'forest' -ScriptBlock {
             [CompletionResult]::new('--parent', '--parent', [CompletionResultType]::ParameterName, 'Uid of parent task. By default, adds the task to the tree root')
             [CompletionResult]::new('-d', '-d', [CompletionResultType]::ParameterName, 'Description of the new task')
             [CompletionResult]::new('--description', '--description', [CompletionResultType]::ParameterName, 'Description of the new task')
+            [CompletionResult]::new('--due', '--due', [CompletionResultType]::ParameterName, 'Date and time at which the new task is due')
+            [CompletionResult]::new('--scheduled', '--scheduled', [CompletionResultType]::ParameterName, 'Date and time at which the new task is planned to be worked on')
+            [CompletionResult]::new('--every', '--every', [CompletionResultType]::ParameterName, 'Recurrence rule of the new task, eg. "weekly", "every 3 days" or "FREQ=MONTHLY;INTERVAL=2"')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--forest', '--forest', [CompletionResultType]::ParameterName, 'Name of the forest to use instead of the current one')
             [CompletionResult]::new('-e', '-e', [CompletionResultType]::ParameterName, 'Opens an editor to write the description of the new task')
             [CompletionResult]::new('--edit', '--edit', [CompletionResultType]::ParameterName, 'Opens an editor to write the description of the new task')
-            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
-            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'forest;task;import' {
+            [CompletionResult]::new('-f', '-f', [CompletionResultType]::ParameterName, 'Format of the file to import')
+            [CompletionResult]::new('--format', '--format', [CompletionResultType]::ParameterName, 'Format of the file to import')
+            [CompletionResult]::new('-p', '-p', [CompletionResultType]::ParameterName, 'Uid of the task under which to import tasks. By default, imports them under the tree root')
+            [CompletionResult]::new('--parent', '--parent', [CompletionResultType]::ParameterName, 'Uid of the task under which to import tasks. By default, imports them under the tree root')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--forest', '--forest', [CompletionResultType]::ParameterName, 'Name of the forest to use instead of the current one')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
             break
         }
         'forest;task;remove' {
-            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
-            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--forest', '--forest', [CompletionResultType]::ParameterName, 'Name of the forest to use instead of the current one')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
             break
         }
         'forest;task;rename' {
-            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
-            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--forest', '--forest', [CompletionResultType]::ParameterName, 'Name of the forest to use instead of the current one')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
             break
         }
         'forest;task;show' {
-            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
-            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--forest', '--forest', [CompletionResultType]::ParameterName, 'Name of the forest to use instead of the current one')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
             break
         }
         'forest;task;edit' {
-            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
-            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--forest', '--forest', [CompletionResultType]::ParameterName, 'Name of the forest to use instead of the current one')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
             break
         }
         'forest;task;priority' {
-            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
-            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--forest', '--forest', [CompletionResultType]::ParameterName, 'Name of the forest to use instead of the current one')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'forest;task;done' {
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--forest', '--forest', [CompletionResultType]::ParameterName, 'Name of the forest to use instead of the current one')
+            [CompletionResult]::new('-r', '-r', [CompletionResultType]::ParameterName, 'Also mark all open subtasks as done')
+            [CompletionResult]::new('--recursive', '--recursive', [CompletionResultType]::ParameterName, 'Also mark all open subtasks as done')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'forest;task;start' {
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--forest', '--forest', [CompletionResultType]::ParameterName, 'Name of the forest to use instead of the current one')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'forest;task;cancel' {
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--forest', '--forest', [CompletionResultType]::ParameterName, 'Name of the forest to use instead of the current one')
+            [CompletionResult]::new('-r', '-r', [CompletionResultType]::ParameterName, 'Also mark all open subtasks as cancelled')
+            [CompletionResult]::new('--recursive', '--recursive', [CompletionResultType]::ParameterName, 'Also mark all open subtasks as cancelled')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'forest;task;reopen' {
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--forest', '--forest', [CompletionResultType]::ParameterName, 'Name of the forest to use instead of the current one')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'forest;task;move' {
+            [CompletionResult]::new('-t', '-t', [CompletionResultType]::ParameterName, 'Name of the destination tree. By default, the task is moved within the current tree')
+            [CompletionResult]::new('--tree', '--tree', [CompletionResultType]::ParameterName, 'Name of the destination tree. By default, the task is moved within the current tree')
+            [CompletionResult]::new('-p', '-p', [CompletionResultType]::ParameterName, 'Uid of the new parent task in the destination tree. By default, the task is moved to the tree root')
+            [CompletionResult]::new('--parent', '--parent', [CompletionResultType]::ParameterName, 'Uid of the new parent task in the destination tree. By default, the task is moved to the tree root')
+            [CompletionResult]::new('-n', '-n', [CompletionResultType]::ParameterName, 'Position of the task among the children of its new parent. By default, the task is moved after the last child')
+            [CompletionResult]::new('--position', '--position', [CompletionResultType]::ParameterName, 'Position of the task among the children of its new parent. By default, the task is moved after the last child')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--forest', '--forest', [CompletionResultType]::ParameterName, 'Name of the forest to use instead of the current one')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'forest;task;copy' {
+            [CompletionResult]::new('-t', '-t', [CompletionResultType]::ParameterName, 'Name of the destination tree. By default, the task is copied within the current tree')
+            [CompletionResult]::new('--tree', '--tree', [CompletionResultType]::ParameterName, 'Name of the destination tree. By default, the task is copied within the current tree')
+            [CompletionResult]::new('-p', '-p', [CompletionResultType]::ParameterName, 'Uid of the new parent task in the destination tree. By default, the task is copied to the tree root')
+            [CompletionResult]::new('--parent', '--parent', [CompletionResultType]::ParameterName, 'Uid of the new parent task in the destination tree. By default, the task is copied to the tree root')
+            [CompletionResult]::new('-n', '-n', [CompletionResultType]::ParameterName, 'Position of the task among the children of its new parent. By default, the task is copied after the last child')
+            [CompletionResult]::new('--position', '--position', [CompletionResultType]::ParameterName, 'Position of the task among the children of its new parent. By default, the task is copied after the last child')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--forest', '--forest', [CompletionResultType]::ParameterName, 'Name of the forest to use instead of the current one')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'forest;task;depend' {
+            [CompletionResult]::new('--on', '--on', [CompletionResultType]::ParameterName, 'Uid of the task that must be done first')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--forest', '--forest', [CompletionResultType]::ParameterName, 'Name of the forest to use instead of the current one')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'forest;task;undepend' {
+            [CompletionResult]::new('--on', '--on', [CompletionResultType]::ParameterName, 'Uid of the task it should no longer depend on')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--forest', '--forest', [CompletionResultType]::ParameterName, 'Name of the forest to use instead of the current one')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'forest;task;recur' {
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--forest', '--forest', [CompletionResultType]::ParameterName, 'Name of the forest to use instead of the current one')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Stop repeating the task')
+            [CompletionResult]::new('--clear', '--clear', [CompletionResultType]::ParameterName, 'Stop repeating the task')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'forest;task;due' {
+            [CompletionResult]::new('-s', '-s', [CompletionResultType]::ParameterName, 'Date and time at which the task is planned to be worked on')
+            [CompletionResult]::new('--scheduled', '--scheduled', [CompletionResultType]::ParameterName, 'Date and time at which the task is planned to be worked on')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--forest', '--forest', [CompletionResultType]::ParameterName, 'Name of the forest to use instead of the current one')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Remove the current due and scheduled dates of the task')
+            [CompletionResult]::new('--clear', '--clear', [CompletionResultType]::ParameterName, 'Remove the current due and scheduled dates of the task')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'forest;task;tag' {
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--forest', '--forest', [CompletionResultType]::ParameterName, 'Name of the forest to use instead of the current one')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'forest;task;untag' {
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--forest', '--forest', [CompletionResultType]::ParameterName, 'Name of the forest to use instead of the current one')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
             break
         }
         'forest;task;help' {
             [CompletionResult]::new('list', 'list', [CompletionResultType]::ParameterValue, 'List tasks in the current tree')
             [CompletionResult]::new('add', 'add', [CompletionResultType]::ParameterValue, 'Add a new task to the current tree')
+            [CompletionResult]::new('import', 'import', [CompletionResultType]::ParameterValue, 'Import tasks from another task manager into the current tree')
             [CompletionResult]::new('remove', 'remove', [CompletionResultType]::ParameterValue, 'Remove a task from the current tree')
             [CompletionResult]::new('rename', 'rename', [CompletionResultType]::ParameterValue, 'Rename a task in the current tree')
             [CompletionResult]::new('show', 'show', [CompletionResultType]::ParameterValue, 'Show description of a task in the current tree')
             [CompletionResult]::new('edit', 'edit', [CompletionResultType]::ParameterValue, 'Edit description of a task in the current tree')
             [CompletionResult]::new('priority', 'priority', [CompletionResultType]::ParameterValue, 'Set priority of a task in the current tree')
+            [CompletionResult]::new('done', 'done', [CompletionResultType]::ParameterValue, 'Mark a task of the current tree as done')
+            [CompletionResult]::new('start', 'start', [CompletionResultType]::ParameterValue, 'Mark a task of the current tree as in progress')
+            [CompletionResult]::new('cancel', 'cancel', [CompletionResultType]::ParameterValue, 'Mark a task of the current tree as cancelled')
+            [CompletionResult]::new('reopen', 'reopen', [CompletionResultType]::ParameterValue, 'Mark a done or cancelled task of the current tree as todo again')
+            [CompletionResult]::new('move', 'move', [CompletionResultType]::ParameterValue, 'Move a task of the current tree and its subtasks under another parent, possibly in another tree')
+            [CompletionResult]::new('copy', 'copy', [CompletionResultType]::ParameterValue, 'Copy a task of the current tree and its subtasks under another parent, possibly in another tree')
+            [CompletionResult]::new('depend', 'depend', [CompletionResultType]::ParameterValue, 'Make a task of the current tree depend on another task, possibly in another tree')
+            [CompletionResult]::new('undepend', 'undepend', [CompletionResultType]::ParameterValue, 'Remove a dependency of a task of the current tree')
+            [CompletionResult]::new('recur', 'recur', [CompletionResultType]::ParameterValue, 'Set the recurrence rule of a task in the current tree')
+            [CompletionResult]::new('due', 'due', [CompletionResultType]::ParameterValue, 'Set due and scheduled dates of a task in the current tree')
+            [CompletionResult]::new('tag', 'tag', [CompletionResultType]::ParameterValue, 'Add tags to a task in the current tree')
+            [CompletionResult]::new('untag', 'untag', [CompletionResultType]::ParameterValue, 'Remove tags from a task in the current tree')
             [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
             break
         }
@@ -107,6 +311,9 @@ Register-ArgumentCompleter -Native -CommandName 'forest' -ScriptBlock {
         'forest;task;help;add' {
             break
         }
+        'forest;task;help;import' {
+            break
+        }
         'forest;task;help;remove' {
             break
         }
@@ -122,12 +329,51 @@ Register-ArgumentCompleter -Native -CommandName 'forest' -ScriptBlock {
         'forest;task;help;priority' {
             break
         }
+        'forest;task;help;done' {
+            break
+        }
+        'forest;task;help;start' {
+            break
+        }
+        'forest;task;help;cancel' {
+            break
+        }
+        'forest;task;help;reopen' {
+            break
+        }
+        'forest;task;help;move' {
+            break
+        }
+        'forest;task;help;copy' {
+            break
+        }
+        'forest;task;help;depend' {
+            break
+        }
+        'forest;task;help;undepend' {
+            break
+        }
+        'forest;task;help;recur' {
+            break
+        }
+        'forest;task;help;due' {
+            break
+        }
+        'forest;task;help;tag' {
+            break
+        }
+        'forest;task;help;untag' {
+            break
+        }
         'forest;task;help;help' {
             break
         }
         'forest;tree' {
-            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
-            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--forest', '--forest', [CompletionResultType]::ParameterName, 'Name of the forest to use instead of the current one')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
             [CompletionResult]::new('list', 'list', [CompletionResultType]::ParameterValue, 'List trees in the current forest')
             [CompletionResult]::new('add', 'add', [CompletionResultType]::ParameterValue, 'Add a new tree')
             [CompletionResult]::new('remove', 'remove', [CompletionResultType]::ParameterValue, 'Remove a tree')
@@ -140,6 +386,9 @@ Register-ArgumentCompleter -Native -CommandName 'forest' -ScriptBlock {
         'forest;tree;list' {
             [CompletionResult]::new('-f', '-f', [CompletionResultType]::ParameterName, 'Formatting options')
             [CompletionResult]::new('--format', '--format', [CompletionResultType]::ParameterName, 'Formatting options')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--forest', '--forest', [CompletionResultType]::ParameterName, 'Name of the forest to use instead of the current one')
             [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
             [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
             break
@@ -147,30 +396,45 @@ Register-ArgumentCompleter -Native -CommandName 'forest' -ScriptBlock {
         'forest;tree;add' {
             [CompletionResult]::new('-d', '-d', [CompletionResultType]::ParameterName, 'Description of the new tree')
             [CompletionResult]::new('--description', '--description', [CompletionResultType]::ParameterName, 'Description of the new tree')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--forest', '--forest', [CompletionResultType]::ParameterName, 'Name of the forest to use instead of the current one')
             [CompletionResult]::new('-e', '-e', [CompletionResultType]::ParameterName, 'Opens an editor to write the description of the new tree')
             [CompletionResult]::new('--edit', '--edit', [CompletionResultType]::ParameterName, 'Opens an editor to write the description of the new tree')
-            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
-            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
             break
         }
         'forest;tree;remove' {
-            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
-            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--forest', '--forest', [CompletionResultType]::ParameterName, 'Name of the forest to use instead of the current one')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
             break
         }
         'forest;tree;rename' {
-            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
-            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--forest', '--forest', [CompletionResultType]::ParameterName, 'Name of the forest to use instead of the current one')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
             break
         }
         'forest;tree;show' {
-            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
-            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--forest', '--forest', [CompletionResultType]::ParameterName, 'Name of the forest to use instead of the current one')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
             break
         }
         'forest;tree;edit' {
-            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
-            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--forest', '--forest', [CompletionResultType]::ParameterName, 'Name of the forest to use instead of the current one')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
             break
         }
         'forest;tree;help' {
@@ -205,41 +469,78 @@ Register-ArgumentCompleter -Native -CommandName 'forest' -ScriptBlock {
             break
         }
         'forest;note' {
-            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
-            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--forest', '--forest', [CompletionResultType]::ParameterName, 'Name of the forest to use instead of the current one')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
             [CompletionResult]::new('list', 'list', [CompletionResultType]::ParameterValue, 'List all notes')
             [CompletionResult]::new('add', 'add', [CompletionResultType]::ParameterValue, 'Create a new note associated to the current tree')
             [CompletionResult]::new('remove', 'remove', [CompletionResultType]::ParameterValue, 'Remove a note')
             [CompletionResult]::new('show', 'show', [CompletionResultType]::ParameterValue, 'Show content of a note')
             [CompletionResult]::new('edit', 'edit', [CompletionResultType]::ParameterValue, 'Edit a note')
+            [CompletionResult]::new('tag', 'tag', [CompletionResultType]::ParameterValue, 'Add tags to a note')
+            [CompletionResult]::new('untag', 'untag', [CompletionResultType]::ParameterValue, 'Remove tags from a note')
             [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
             break
         }
         'forest;note;list' {
+            [CompletionResult]::new('--tag', '--tag', [CompletionResultType]::ParameterName, 'Only display notes with one of the given tag(s)')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--forest', '--forest', [CompletionResultType]::ParameterName, 'Name of the forest to use instead of the current one')
             [CompletionResult]::new('-t', '-t', [CompletionResultType]::ParameterName, 'Show time tracking notes (hidden by default)')
             [CompletionResult]::new('--show-tt', '--show-tt', [CompletionResultType]::ParameterName, 'Show time tracking notes (hidden by default)')
-            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
-            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
             break
         }
         'forest;note;add' {
-            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
-            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--forest', '--forest', [CompletionResultType]::ParameterName, 'Name of the forest to use instead of the current one')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
             break
         }
         'forest;note;remove' {
-            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
-            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--forest', '--forest', [CompletionResultType]::ParameterName, 'Name of the forest to use instead of the current one')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
             break
         }
         'forest;note;show' {
-            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
-            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--forest', '--forest', [CompletionResultType]::ParameterName, 'Name of the forest to use instead of the current one')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
             break
         }
         'forest;note;edit' {
-            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
-            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--forest', '--forest', [CompletionResultType]::ParameterName, 'Name of the forest to use instead of the current one')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'forest;note;tag' {
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--forest', '--forest', [CompletionResultType]::ParameterName, 'Name of the forest to use instead of the current one')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'forest;note;untag' {
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--forest', '--forest', [CompletionResultType]::ParameterName, 'Name of the forest to use instead of the current one')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
             break
         }
         'forest;note;help' {
@@ -248,6 +549,8 @@ Register-ArgumentCompleter -Native -CommandName 'forest' -ScriptBlock {
             [CompletionResult]::new('remove', 'remove', [CompletionResultType]::ParameterValue, 'Remove a note')
             [CompletionResult]::new('show', 'show', [CompletionResultType]::ParameterValue, 'Show content of a note')
             [CompletionResult]::new('edit', 'edit', [CompletionResultType]::ParameterValue, 'Edit a note')
+            [CompletionResult]::new('tag', 'tag', [CompletionResultType]::ParameterValue, 'Add tags to a note')
+            [CompletionResult]::new('untag', 'untag', [CompletionResultType]::ParameterValue, 'Remove tags from a note')
             [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
             break
         }
@@ -266,58 +569,466 @@ Register-ArgumentCompleter -Native -CommandName 'forest' -ScriptBlock {
         'forest;note;help;edit' {
             break
         }
+        'forest;note;help;tag' {
+            break
+        }
+        'forest;note;help;untag' {
+            break
+        }
         'forest;note;help;help' {
             break
         }
+        'forest;frame' {
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--forest', '--forest', [CompletionResultType]::ParameterName, 'Name of the forest to use instead of the current one')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('list', 'list', [CompletionResultType]::ParameterValue, 'List time tracking frames, most recent first')
+            [CompletionResult]::new('add', 'add', [CompletionResultType]::ParameterValue, 'Add a time tracking frame')
+            [CompletionResult]::new('edit', 'edit', [CompletionResultType]::ParameterValue, 'Edit a time tracking frame')
+            [CompletionResult]::new('remove', 'remove', [CompletionResultType]::ParameterValue, 'Remove a time tracking frame')
+            [CompletionResult]::new('split', 'split', [CompletionResultType]::ParameterValue, 'Split a time tracking frame in two at the given date and time')
+            [CompletionResult]::new('merge', 'merge', [CompletionResultType]::ParameterValue, 'Merge two time tracking frames of the same tree or task')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'forest;frame;list' {
+            [CompletionResult]::new('-t', '-t', [CompletionResultType]::ParameterName, 'Only list frames of the given tree')
+            [CompletionResult]::new('--tree', '--tree', [CompletionResultType]::ParameterName, 'Only list frames of the given tree')
+            [CompletionResult]::new('--from', '--from', [CompletionResultType]::ParameterName, 'Only list frames ending after the given date and time')
+            [CompletionResult]::new('--to', '--to', [CompletionResultType]::ParameterName, 'Only list frames starting before the given date and time')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--forest', '--forest', [CompletionResultType]::ParameterName, 'Name of the forest to use instead of the current one')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'forest;frame;add' {
+            [CompletionResult]::new('--task', '--task', [CompletionResultType]::ParameterName, 'Uid of task on which time was spent')
+            [CompletionResult]::new('--start', '--start', [CompletionResultType]::ParameterName, 'Start date and time of the frame')
+            [CompletionResult]::new('--end', '--end', [CompletionResultType]::ParameterName, 'End date and time of the frame')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--forest', '--forest', [CompletionResultType]::ParameterName, 'Name of the forest to use instead of the current one')
+            [CompletionResult]::new('--adjust', '--adjust', [CompletionResultType]::ParameterName, 'Trim, split or remove overlapping frames instead of failing')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'forest;frame;edit' {
+            [CompletionResult]::new('--start', '--start', [CompletionResultType]::ParameterName, 'New start date and time of the frame')
+            [CompletionResult]::new('--end', '--end', [CompletionResultType]::ParameterName, 'New end date and time of the frame')
+            [CompletionResult]::new('--tree', '--tree', [CompletionResultType]::ParameterName, 'Attach the frame to the given tree')
+            [CompletionResult]::new('--task', '--task', [CompletionResultType]::ParameterName, 'Attach the frame to the given task')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--forest', '--forest', [CompletionResultType]::ParameterName, 'Name of the forest to use instead of the current one')
+            [CompletionResult]::new('--adjust', '--adjust', [CompletionResultType]::ParameterName, 'Trim, split or remove overlapping frames instead of failing')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'forest;frame;remove' {
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--forest', '--forest', [CompletionResultType]::ParameterName, 'Name of the forest to use instead of the current one')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'forest;frame;split' {
+            [CompletionResult]::new('--at', '--at', [CompletionResultType]::ParameterName, 'Date and time at which to split the frame')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--forest', '--forest', [CompletionResultType]::ParameterName, 'Name of the forest to use instead of the current one')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'forest;frame;merge' {
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--forest', '--forest', [CompletionResultType]::ParameterName, 'Name of the forest to use instead of the current one')
+            [CompletionResult]::new('--adjust', '--adjust', [CompletionResultType]::ParameterName, 'Trim, split or remove overlapping frames instead of failing')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'forest;frame;help' {
+            [CompletionResult]::new('list', 'list', [CompletionResultType]::ParameterValue, 'List time tracking frames, most recent first')
+            [CompletionResult]::new('add', 'add', [CompletionResultType]::ParameterValue, 'Add a time tracking frame')
+            [CompletionResult]::new('edit', 'edit', [CompletionResultType]::ParameterValue, 'Edit a time tracking frame')
+            [CompletionResult]::new('remove', 'remove', [CompletionResultType]::ParameterValue, 'Remove a time tracking frame')
+            [CompletionResult]::new('split', 'split', [CompletionResultType]::ParameterValue, 'Split a time tracking frame in two at the given date and time')
+            [CompletionResult]::new('merge', 'merge', [CompletionResultType]::ParameterValue, 'Merge two time tracking frames of the same tree or task')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'forest;frame;help;list' {
+            break
+        }
+        'forest;frame;help;add' {
+            break
+        }
+        'forest;frame;help;edit' {
+            break
+        }
+        'forest;frame;help;remove' {
+            break
+        }
+        'forest;frame;help;split' {
+            break
+        }
+        'forest;frame;help;merge' {
+            break
+        }
+        'forest;frame;help;help' {
+            break
+        }
+        'forest;profile' {
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--forest', '--forest', [CompletionResultType]::ParameterName, 'Name of the forest to use instead of the current one')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('list', 'list', [CompletionResultType]::ParameterValue, 'List all forests')
+            [CompletionResult]::new('create', 'create', [CompletionResultType]::ParameterValue, 'Create a new empty forest')
+            [CompletionResult]::new('use', 'use', [CompletionResultType]::ParameterValue, 'Use another forest by default')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'forest;profile;list' {
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--forest', '--forest', [CompletionResultType]::ParameterName, 'Name of the forest to use instead of the current one')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'forest;profile;create' {
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--forest', '--forest', [CompletionResultType]::ParameterName, 'Name of the forest to use instead of the current one')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'forest;profile;use' {
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--forest', '--forest', [CompletionResultType]::ParameterName, 'Name of the forest to use instead of the current one')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'forest;profile;help' {
+            [CompletionResult]::new('list', 'list', [CompletionResultType]::ParameterValue, 'List all forests')
+            [CompletionResult]::new('create', 'create', [CompletionResultType]::ParameterValue, 'Create a new empty forest')
+            [CompletionResult]::new('use', 'use', [CompletionResultType]::ParameterValue, 'Use another forest by default')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'forest;profile;help;list' {
+            break
+        }
+        'forest;profile;help;create' {
+            break
+        }
+        'forest;profile;help;use' {
+            break
+        }
+        'forest;profile;help;help' {
+            break
+        }
+        'forest;db' {
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--forest', '--forest', [CompletionResultType]::ParameterName, 'Name of the forest to use instead of the current one')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('migrate', 'migrate', [CompletionResultType]::ParameterValue, 'Apply pending migrations to the database schema')
+            [CompletionResult]::new('status', 'status', [CompletionResultType]::ParameterValue, 'Show the schema version of the database and pending migrations')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'forest;db;migrate' {
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--forest', '--forest', [CompletionResultType]::ParameterName, 'Name of the forest to use instead of the current one')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'forest;db;status' {
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--forest', '--forest', [CompletionResultType]::ParameterName, 'Name of the forest to use instead of the current one')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'forest;db;help' {
+            [CompletionResult]::new('migrate', 'migrate', [CompletionResultType]::ParameterValue, 'Apply pending migrations to the database schema')
+            [CompletionResult]::new('status', 'status', [CompletionResultType]::ParameterValue, 'Show the schema version of the database and pending migrations')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'forest;db;help;migrate' {
+            break
+        }
+        'forest;db;help;status' {
+            break
+        }
+        'forest;db;help;help' {
+            break
+        }
         'forest;switch' {
-            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
-            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--forest', '--forest', [CompletionResultType]::ParameterName, 'Name of the forest to use instead of the current one')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
             break
         }
         'forest;start' {
+            [CompletionResult]::new('-t', '-t', [CompletionResultType]::ParameterName, 'Uid of task for which to record time')
+            [CompletionResult]::new('--task', '--task', [CompletionResultType]::ParameterName, 'Uid of task for which to record time')
             [CompletionResult]::new('--at', '--at', [CompletionResultType]::ParameterName, 'Start date and time of recording')
-            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
-            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--forest', '--forest', [CompletionResultType]::ParameterName, 'Name of the forest to use instead of the current one')
+            [CompletionResult]::new('--adjust', '--adjust', [CompletionResultType]::ParameterName, 'Trim, split or remove overlapping frames instead of failing')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
             break
         }
         'forest;stop' {
             [CompletionResult]::new('--at', '--at', [CompletionResultType]::ParameterName, 'Stop date and time of recording')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--forest', '--forest', [CompletionResultType]::ParameterName, 'Name of the forest to use instead of the current one')
             [CompletionResult]::new('-n', '-n', [CompletionResultType]::ParameterName, 'Do not create a new note')
             [CompletionResult]::new('--no-note', '--no-note', [CompletionResultType]::ParameterName, 'Do not create a new note')
-            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
-            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'forest;cancel' {
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--forest', '--forest', [CompletionResultType]::ParameterName, 'Name of the forest to use instead of the current one')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'forest;restart' {
+            [CompletionResult]::new('--at', '--at', [CompletionResultType]::ParameterName, 'Start date and time of recording')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--forest', '--forest', [CompletionResultType]::ParameterName, 'Name of the forest to use instead of the current one')
+            [CompletionResult]::new('--adjust', '--adjust', [CompletionResultType]::ParameterName, 'Trim, split or remove overlapping frames instead of failing')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'forest;import' {
+            [CompletionResult]::new('-f', '-f', [CompletionResultType]::ParameterName, 'Time tracker the records come from')
+            [CompletionResult]::new('--from', '--from', [CompletionResultType]::ParameterName, 'Time tracker the records come from')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--forest', '--forest', [CompletionResultType]::ParameterName, 'Name of the forest to use instead of the current one')
+            [CompletionResult]::new('-n', '-n', [CompletionResultType]::ParameterName, 'Show the records that would be imported without importing them')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Show the records that would be imported without importing them')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'forest;backup' {
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--forest', '--forest', [CompletionResultType]::ParameterName, 'Name of the forest to use instead of the current one')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'forest;restore' {
+            [CompletionResult]::new('-m', '-m', [CompletionResultType]::ParameterName, 'Whether to replace the forest with the archive, or to add what is missing from it')
+            [CompletionResult]::new('--mode', '--mode', [CompletionResultType]::ParameterName, 'Whether to replace the forest with the archive, or to add what is missing from it')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--forest', '--forest', [CompletionResultType]::ParameterName, 'Name of the forest to use instead of the current one')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'forest;export' {
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--forest', '--forest', [CompletionResultType]::ParameterName, 'Name of the forest to use instead of the current one')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('ics', 'ics', [CompletionResultType]::ParameterValue, 'Write time tracking frames as events and due dates of tasks as to-dos, in iCalendar format')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'forest;export;ics' {
+            [CompletionResult]::new('--from', '--from', [CompletionResultType]::ParameterName, 'Only export frames ending and tasks due after the given date and time')
+            [CompletionResult]::new('--to', '--to', [CompletionResultType]::ParameterName, 'Only export frames starting and tasks due before the given date and time')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--forest', '--forest', [CompletionResultType]::ParameterName, 'Name of the forest to use instead of the current one')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'forest;export;help' {
+            [CompletionResult]::new('ics', 'ics', [CompletionResultType]::ParameterValue, 'Write time tracking frames as events and due dates of tasks as to-dos, in iCalendar format')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'forest;export;help;ics' {
+            break
+        }
+        'forest;export;help;help' {
+            break
+        }
+        'forest;pause' {
+            [CompletionResult]::new('--at', '--at', [CompletionResultType]::ParameterName, 'Pause date and time of recording')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--forest', '--forest', [CompletionResultType]::ParameterName, 'Name of the forest to use instead of the current one')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'forest;resume' {
+            [CompletionResult]::new('--at', '--at', [CompletionResultType]::ParameterName, 'Resume date and time of recording')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--forest', '--forest', [CompletionResultType]::ParameterName, 'Name of the forest to use instead of the current one')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
             break
         }
         'forest;status' {
-            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
-            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--forest', '--forest', [CompletionResultType]::ParameterName, 'Name of the forest to use instead of the current one')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
             break
         }
         'forest;report' {
-            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
-            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--tag', '--tag', [CompletionResultType]::ParameterName, 'Only count time spent on tasks with one of the given tag(s)')
+            [CompletionResult]::new('-t', '-t', [CompletionResultType]::ParameterName, 'Only report time spent on the given tree (defaults to the current tree with `--by task`)')
+            [CompletionResult]::new('--tree', '--tree', [CompletionResultType]::ParameterName, 'Only report time spent on the given tree (defaults to the current tree with `--by task`)')
+            [CompletionResult]::new('-b', '-b', [CompletionResultType]::ParameterName, 'Report time spent on each tree or on each task')
+            [CompletionResult]::new('--by', '--by', [CompletionResultType]::ParameterName, 'Report time spent on each tree or on each task')
+            [CompletionResult]::new('-d', '-d', [CompletionResultType]::ParameterName, 'With `--by task`, collapse tasks deeper than the given depth into their ancestors')
+            [CompletionResult]::new('--depth', '--depth', [CompletionResultType]::ParameterName, 'With `--by task`, collapse tasks deeper than the given depth into their ancestors')
+            [CompletionResult]::new('--from', '--from', [CompletionResultType]::ParameterName, 'Only count time spent from the given date and time')
+            [CompletionResult]::new('--to', '--to', [CompletionResultType]::ParameterName, 'Only count time spent until the given date and time')
+            [CompletionResult]::new('-g', '-g', [CompletionResultType]::ParameterName, 'Show a timesheet grid with one column per day, for the week or month containing `--from` (defaults to now)')
+            [CompletionResult]::new('--grid', '--grid', [CompletionResultType]::ParameterName, 'Show a timesheet grid with one column per day, for the week or month containing `--from` (defaults to now)')
+            [CompletionResult]::new('--round', '--round', [CompletionResultType]::ParameterName, 'Round the time of each cell of the grid to a multiple of the given duration, eg. "15m"')
+            [CompletionResult]::new('--round-mode', '--round-mode', [CompletionResultType]::ParameterName, 'How to round the time of each cell of the grid')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--forest', '--forest', [CompletionResultType]::ParameterName, 'Name of the forest to use instead of the current one')
+            [CompletionResult]::new('--today', '--today', [CompletionResultType]::ParameterName, 'Only count time spent today')
+            [CompletionResult]::new('--week', '--week', [CompletionResultType]::ParameterName, 'Only count time spent this week')
+            [CompletionResult]::new('--month', '--month', [CompletionResultType]::ParameterName, 'Only count time spent this month')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'forest;config' {
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--forest', '--forest', [CompletionResultType]::ParameterName, 'Name of the forest to use instead of the current one')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('week-start', 'week-start', [CompletionResultType]::ParameterValue, 'Get or set the first day of the week, used by `report --week`')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'forest;config;week-start' {
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--forest', '--forest', [CompletionResultType]::ParameterName, 'Name of the forest to use instead of the current one')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'forest;config;help' {
+            [CompletionResult]::new('week-start', 'week-start', [CompletionResultType]::ParameterValue, 'Get or set the first day of the week, used by `report --week`')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'forest;config;help;week-start' {
+            break
+        }
+        'forest;config;help;help' {
+            break
+        }
+        'forest;agenda' {
+            [CompletionResult]::new('-d', '-d', [CompletionResultType]::ParameterName, 'Number of days after today to include in upcoming tasks')
+            [CompletionResult]::new('--days', '--days', [CompletionResultType]::ParameterName, 'Number of days after today to include in upcoming tasks')
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format of listings, reports and status')
+            [CompletionResult]::new('--forest', '--forest', [CompletionResultType]::ParameterName, 'Name of the forest to use instead of the current one')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
             break
         }
         'forest;help' {
             [CompletionResult]::new('task', 'task', [CompletionResultType]::ParameterValue, 'Perform operations on tasks')
             [CompletionResult]::new('tree', 'tree', [CompletionResultType]::ParameterValue, 'Perform operations on trees')
             [CompletionResult]::new('note', 'note', [CompletionResultType]::ParameterValue, 'Perform operations on notes')
+            [CompletionResult]::new('frame', 'frame', [CompletionResultType]::ParameterValue, 'Perform operations on time tracking frames')
+            [CompletionResult]::new('profile', 'profile', [CompletionResultType]::ParameterValue, 'Manage separate forests, each with its own trees, frames and notes')
+            [CompletionResult]::new('db', 'db', [CompletionResultType]::ParameterValue, 'Manage the schema of the forest database')
             [CompletionResult]::new('switch', 'switch', [CompletionResultType]::ParameterValue, 'Switch to another tree')
             [CompletionResult]::new('start', 'start', [CompletionResultType]::ParameterValue, 'Start recording time')
             [CompletionResult]::new('stop', 'stop', [CompletionResultType]::ParameterValue, 'Stop current time recording')
+            [CompletionResult]::new('cancel', 'cancel', [CompletionResultType]::ParameterValue, 'Discard current time recording')
+            [CompletionResult]::new('restart', 'restart', [CompletionResultType]::ParameterValue, 'Start recording time on the most recently tracked tree or task')
+            [CompletionResult]::new('import', 'import', [CompletionResultType]::ParameterValue, 'Import time records from another time tracker')
+            [CompletionResult]::new('backup', 'backup', [CompletionResultType]::ParameterValue, 'Save the whole forest, including notes, to an archive')
+            [CompletionResult]::new('restore', 'restore', [CompletionResultType]::ParameterValue, 'Restore the forest from an archive created by `forest backup`')
+            [CompletionResult]::new('export', 'export', [CompletionResultType]::ParameterValue, 'Export the forest to files readable by other applications')
+            [CompletionResult]::new('pause', 'pause', [CompletionResultType]::ParameterValue, 'Pause current time recording, without creating a note')
+            [CompletionResult]::new('resume', 'resume', [CompletionResultType]::ParameterValue, 'Resume paused time recording on the same tree or task')
             [CompletionResult]::new('status', 'status', [CompletionResultType]::ParameterValue, 'Show current time recording')
-            [CompletionResult]::new('report', 'report', [CompletionResultType]::ParameterValue, 'Reports time spent on each tree')
+            [CompletionResult]::new('report', 'report', [CompletionResultType]::ParameterValue, 'Reports time spent on each tree, or on each task of a tree')
+            [CompletionResult]::new('config', 'config', [CompletionResultType]::ParameterValue, 'Get or set configuration options')
+            [CompletionResult]::new('agenda', 'agenda', [CompletionResultType]::ParameterValue, 'Show overdue, today''s and upcoming tasks across all trees')
             [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
             break
         }
         'forest;help;task' {
             [CompletionResult]::new('list', 'list', [CompletionResultType]::ParameterValue, 'List tasks in the current tree')
             [CompletionResult]::new('add', 'add', [CompletionResultType]::ParameterValue, 'Add a new task to the current tree')
+            [CompletionResult]::new('import', 'import', [CompletionResultType]::ParameterValue, 'Import tasks from another task manager into the current tree')
             [CompletionResult]::new('remove', 'remove', [CompletionResultType]::ParameterValue, 'Remove a task from the current tree')
             [CompletionResult]::new('rename', 'rename', [CompletionResultType]::ParameterValue, 'Rename a task in the current tree')
             [CompletionResult]::new('show', 'show', [CompletionResultType]::ParameterValue, 'Show description of a task in the current tree')
             [CompletionResult]::new('edit', 'edit', [CompletionResultType]::ParameterValue, 'Edit description of a task in the current tree')
             [CompletionResult]::new('priority', 'priority', [CompletionResultType]::ParameterValue, 'Set priority of a task in the current tree')
+            [CompletionResult]::new('done', 'done', [CompletionResultType]::ParameterValue, 'Mark a task of the current tree as done')
+            [CompletionResult]::new('start', 'start', [CompletionResultType]::ParameterValue, 'Mark a task of the current tree as in progress')
+            [CompletionResult]::new('cancel', 'cancel', [CompletionResultType]::ParameterValue, 'Mark a task of the current tree as cancelled')
+            [CompletionResult]::new('reopen', 'reopen', [CompletionResultType]::ParameterValue, 'Mark a done or cancelled task of the current tree as todo again')
+            [CompletionResult]::new('move', 'move', [CompletionResultType]::ParameterValue, 'Move a task of the current tree and its subtasks under another parent, possibly in another tree')
+            [CompletionResult]::new('copy', 'copy', [CompletionResultType]::ParameterValue, 'Copy a task of the current tree and its subtasks under another parent, possibly in another tree')
+            [CompletionResult]::new('depend', 'depend', [CompletionResultType]::ParameterValue, 'Make a task of the current tree depend on another task, possibly in another tree')
+            [CompletionResult]::new('undepend', 'undepend', [CompletionResultType]::ParameterValue, 'Remove a dependency of a task of the current tree')
+            [CompletionResult]::new('recur', 'recur', [CompletionResultType]::ParameterValue, 'Set the recurrence rule of a task in the current tree')
+            [CompletionResult]::new('due', 'due', [CompletionResultType]::ParameterValue, 'Set due and scheduled dates of a task in the current tree')
+            [CompletionResult]::new('tag', 'tag', [CompletionResultType]::ParameterValue, 'Add tags to a task in the current tree')
+            [CompletionResult]::new('untag', 'untag', [CompletionResultType]::ParameterValue, 'Remove tags from a task in the current tree')
             break
         }
         'forest;help;task;list' {
@@ -326,6 +1037,9 @@ Register-ArgumentCompleter -Native -CommandName 'forest' -ScriptBlock {
         'forest;help;task;add' {
             break
         }
+        'forest;help;task;import' {
+            break
+        }
         'forest;help;task;remove' {
             break
         }
@@ -341,6 +1055,42 @@ Register-ArgumentCompleter -Native -CommandName 'forest' -ScriptBlock {
         'forest;help;task;priority' {
             break
         }
+        'forest;help;task;done' {
+            break
+        }
+        'forest;help;task;start' {
+            break
+        }
+        'forest;help;task;cancel' {
+            break
+        }
+        'forest;help;task;reopen' {
+            break
+        }
+        'forest;help;task;move' {
+            break
+        }
+        'forest;help;task;copy' {
+            break
+        }
+        'forest;help;task;depend' {
+            break
+        }
+        'forest;help;task;undepend' {
+            break
+        }
+        'forest;help;task;recur' {
+            break
+        }
+        'forest;help;task;due' {
+            break
+        }
+        'forest;help;task;tag' {
+            break
+        }
+        'forest;help;task;untag' {
+            break
+        }
         'forest;help;tree' {
             [CompletionResult]::new('list', 'list', [CompletionResultType]::ParameterValue, 'List trees in the current forest')
             [CompletionResult]::new('add', 'add', [CompletionResultType]::ParameterValue, 'Add a new tree')
@@ -374,6 +1124,8 @@ Register-ArgumentCompleter -Native -CommandName 'forest' -ScriptBlock {
             [CompletionResult]::new('remove', 'remove', [CompletionResultType]::ParameterValue, 'Remove a note')
             [CompletionResult]::new('show', 'show', [CompletionResultType]::ParameterValue, 'Show content of a note')
             [CompletionResult]::new('edit', 'edit', [CompletionResultType]::ParameterValue, 'Edit a note')
+            [CompletionResult]::new('tag', 'tag', [CompletionResultType]::ParameterValue, 'Add tags to a note')
+            [CompletionResult]::new('untag', 'untag', [CompletionResultType]::ParameterValue, 'Remove tags from a note')
             break
         }
         'forest;help;note;list' {
@@ -391,6 +1143,65 @@ Register-ArgumentCompleter -Native -CommandName 'forest' -ScriptBlock {
         'forest;help;note;edit' {
             break
         }
+        'forest;help;note;tag' {
+            break
+        }
+        'forest;help;note;untag' {
+            break
+        }
+        'forest;help;frame' {
+            [CompletionResult]::new('list', 'list', [CompletionResultType]::ParameterValue, 'List time tracking frames, most recent first')
+            [CompletionResult]::new('add', 'add', [CompletionResultType]::ParameterValue, 'Add a time tracking frame')
+            [CompletionResult]::new('edit', 'edit', [CompletionResultType]::ParameterValue, 'Edit a time tracking frame')
+            [CompletionResult]::new('remove', 'remove', [CompletionResultType]::ParameterValue, 'Remove a time tracking frame')
+            [CompletionResult]::new('split', 'split', [CompletionResultType]::ParameterValue, 'Split a time tracking frame in two at the given date and time')
+            [CompletionResult]::new('merge', 'merge', [CompletionResultType]::ParameterValue, 'Merge two time tracking frames of the same tree or task')
+            break
+        }
+        'forest;help;frame;list' {
+            break
+        }
+        'forest;help;frame;add' {
+            break
+        }
+        'forest;help;frame;edit' {
+            break
+        }
+        'forest;help;frame;remove' {
+            break
+        }
+        'forest;help;frame;split' {
+            break
+        }
+        'forest;help;frame;merge' {
+            break
+        }
+        'forest;help;profile' {
+            [CompletionResult]::new('list', 'list', [CompletionResultType]::ParameterValue, 'List all forests')
+            [CompletionResult]::new('create', 'create', [CompletionResultType]::ParameterValue, 'Create a new empty forest')
+            [CompletionResult]::new('use', 'use', [CompletionResultType]::ParameterValue, 'Use another forest by default')
+            break
+        }
+        'forest;help;profile;list' {
+            break
+        }
+        'forest;help;profile;create' {
+            break
+        }
+        'forest;help;profile;use' {
+            break
+        }
+        'forest;help;db' {
+            [CompletionResult]::new('migrate', 'migrate', [CompletionResultType]::ParameterValue, 'Apply pending migrations to the database schema')
+            [CompletionResult]::new('status', 'status', [CompletionResultType]::ParameterValue, 'Show the schema version of the database and pending migrations')
+            break
+        }
+        'forest;help;db;migrate' {
+            break
+        }
+        'forest;help;db;status' {
+            break
+        }
         'forest;help;switch' {
             break
         }
@@ -400,12 +1211,50 @@ Register-ArgumentCompleter -Native -CommandName 'forest' -ScriptBlock {
         'forest;help;stop' {
             break
         }
+        'forest;help;cancel' {
+            break
+        }
+        'forest;help;restart' {
+            break
+        }
+        'forest;help;import' {
+            break
+        }
+        'forest;help;backup' {
+            break
+        }
+        'forest;help;restore' {
+            break
+        }
+        'forest;help;export' {
+            [CompletionResult]::new('ics', 'ics', [CompletionResultType]::ParameterValue, 'Write time tracking frames as events and due dates of tasks as to-dos, in iCalendar format')
+            break
+        }
+        'forest;help;export;ics' {
+            break
+        }
+        'forest;help;pause' {
+            break
+        }
+        'forest;help;resume' {
+            break
+        }
         'forest;help;status' {
             break
         }
         'forest;help;report' {
             break
         }
+        'forest;help;config' {
+            [CompletionResult]::new('week-start', 'week-start', [CompletionResultType]::ParameterValue, 'Get or set the first day of the week, used by `report --week`')
+            break
+        }
+        'forest;help;config;week-start' {
+            break
+        }
+        'forest;help;agenda' {
+            break
+        }
         'forest;help;help' {
             break
         }
@@ -1,68 +1,260 @@
 # Print an optspec for argparse to handle cmd's options that are independent of any subcommand.
 function __fish_forest_global_optspecs
-	string join \n h/help V/version
+    string join \n o/output= forest= h/help V/version
 end
 
 function __fish_forest_needs_command
-	# Figure out if the current invocation already has a command.
-	set -l cmd (commandline -opc)
-	set -e cmd[1]
-	argparse -s (__fish_forest_global_optspecs) -- $cmd 2>/dev/null
-	or return
-	if set -q argv[1]
-		# Also print the command, so this can be used to figure out what it is.
-		echo $argv[1]
-		return 1
-	end
-	return 0
+    # Figure out if the current invocation already has a command.
+    set -l cmd (commandline -opc)
+    set -e cmd[1]
+    argparse -s (__fish_forest_global_optspecs) -- $cmd 2>/dev/null
+    or return
+    if set -q argv[1]
+        # Also print the command, so this can be used to figure out what it is.
+        echo $argv[1]
+        return 1
+    end
+    return 0
 end
 
 function __fish_forest_using_subcommand
-	set -l cmd (__fish_forest_needs_command)
-	test -z "$cmd"
-	and return 1
-	contains -- $cmd[1] $argv
+    set -l cmd (__fish_forest_needs_command)
+    test -z "$cmd"
+    and return 1
+    contains -- $cmd[1] $argv
 end
 
-complete -c forest -n "__fish_forest_needs_command" -s h -l help -d 'Print help'
+complete -c forest -n "__fish_forest_needs_command" -s o -l output -d 'Output format of listings, reports and status' -r -f -a "text\t'human-readable colored text'
+json\t'JSON document'
+csv\t'comma-separated values, one record per line'
+markdown\t'Markdown table'"
+complete -c forest -n "__fish_forest_needs_command" -l forest -d 'Name of the forest to use instead of the current one' -r
+complete -c forest -n "__fish_forest_needs_command" -s h -l help -d 'Print help (see more with \'--help\')'
 complete -c forest -n "__fish_forest_needs_command" -s V -l version -d 'Print version'
 complete -c forest -n "__fish_forest_needs_command" -f -a "task" -d 'Perform operations on tasks'
 complete -c forest -n "__fish_forest_needs_command" -f -a "tree" -d 'Perform operations on trees'
 complete -c forest -n "__fish_forest_needs_command" -f -a "note" -d 'Perform operations on notes'
+complete -c forest -n "__fish_forest_needs_command" -f -a "frame" -d 'Perform operations on time tracking frames'
+complete -c forest -n "__fish_forest_needs_command" -f -a "profile" -d 'Manage separate forests, each with its own trees, frames and notes'
+complete -c forest -n "__fish_forest_needs_command" -f -a "db" -d 'Manage the schema of the forest database'
 complete -c forest -n "__fish_forest_needs_command" -f -a "switch" -d 'Switch to another tree'
 complete -c forest -n "__fish_forest_needs_command" -f -a "start" -d 'Start recording time'
 complete -c forest -n "__fish_forest_needs_command" -f -a "stop" -d 'Stop current time recording'
+complete -c forest -n "__fish_forest_needs_command" -f -a "cancel" -d 'Discard current time recording'
+complete -c forest -n "__fish_forest_needs_command" -f -a "restart" -d 'Start recording time on the most recently tracked tree or task'
+complete -c forest -n "__fish_forest_needs_command" -f -a "import" -d 'Import time records from another time tracker'
+complete -c forest -n "__fish_forest_needs_command" -f -a "backup" -d 'Save the whole forest, including notes, to an archive'
+complete -c forest -n "__fish_forest_needs_command" -f -a "restore" -d 'Restore the forest from an archive created by `forest backup`'
+complete -c forest -n "__fish_forest_needs_command" -f -a "export" -d 'Export the forest to files readable by other applications'
+complete -c forest -n "__fish_forest_needs_command" -f -a "pause" -d 'Pause current time recording, without creating a note'
+complete -c forest -n "__fish_forest_needs_command" -f -a "resume" -d 'Resume paused time recording on the same tree or task'
 complete -c forest -n "__fish_forest_needs_command" -f -a "status" -d 'Show current time recording'
-complete -c forest -n "__fish_forest_needs_command" -f -a "report" -d 'Reports time spent on each tree'
+complete -c forest -n "__fish_forest_needs_command" -f -a "report" -d 'Reports time spent on each tree, or on each task of a tree'
+complete -c forest -n "__fish_forest_needs_command" -f -a "config" -d 'Get or set configuration options'
+complete -c forest -n "__fish_forest_needs_command" -f -a "agenda" -d 'Show overdue, today\'s and upcoming tasks across all trees'
 complete -c forest -n "__fish_forest_needs_command" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
-complete -c forest -n "__fish_forest_using_subcommand task; and not __fish_seen_subcommand_from list add remove rename show edit priority help" -s h -l help -d 'Print help'
-complete -c forest -n "__fish_forest_using_subcommand task; and not __fish_seen_subcommand_from list add remove rename show edit priority help" -f -a "list" -d 'List tasks in the current tree'
-complete -c forest -n "__fish_forest_using_subcommand task; and not __fish_seen_subcommand_from list add remove rename show edit priority help" -f -a "add" -d 'Add a new task to the current tree'
-complete -c forest -n "__fish_forest_using_subcommand task; and not __fish_seen_subcommand_from list add remove rename show edit priority help" -f -a "remove" -d 'Remove a task from the current tree'
-complete -c forest -n "__fish_forest_using_subcommand task; and not __fish_seen_subcommand_from list add remove rename show edit priority help" -f -a "rename" -d 'Rename a task in the current tree'
-complete -c forest -n "__fish_forest_using_subcommand task; and not __fish_seen_subcommand_from list add remove rename show edit priority help" -f -a "show" -d 'Show description of a task in the current tree'
-complete -c forest -n "__fish_forest_using_subcommand task; and not __fish_seen_subcommand_from list add remove rename show edit priority help" -f -a "edit" -d 'Edit description of a task in the current tree'
-complete -c forest -n "__fish_forest_using_subcommand task; and not __fish_seen_subcommand_from list add remove rename show edit priority help" -f -a "priority" -d 'Set priority of a task in the current tree'
-complete -c forest -n "__fish_forest_using_subcommand task; and not __fish_seen_subcommand_from list add remove rename show edit priority help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
-complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from list" -s h -l help -d 'Print help'
+complete -c forest -n "__fish_forest_using_subcommand task; and not __fish_seen_subcommand_from list add import remove rename show edit priority done start cancel reopen move copy depend undepend recur due tag untag help" -s o -l output -d 'Output format of listings, reports and status' -r -f -a "text\t'human-readable colored text'
+json\t'JSON document'
+csv\t'comma-separated values, one record per line'
+markdown\t'Markdown table'"
+complete -c forest -n "__fish_forest_using_subcommand task; and not __fish_seen_subcommand_from list add import remove rename show edit priority done start cancel reopen move copy depend undepend recur due tag untag help" -l forest -d 'Name of the forest to use instead of the current one' -r
+complete -c forest -n "__fish_forest_using_subcommand task; and not __fish_seen_subcommand_from list add import remove rename show edit priority done start cancel reopen move copy depend undepend recur due tag untag help" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c forest -n "__fish_forest_using_subcommand task; and not __fish_seen_subcommand_from list add import remove rename show edit priority done start cancel reopen move copy depend undepend recur due tag untag help" -f -a "list" -d 'List tasks in the current tree'
+complete -c forest -n "__fish_forest_using_subcommand task; and not __fish_seen_subcommand_from list add import remove rename show edit priority done start cancel reopen move copy depend undepend recur due tag untag help" -f -a "add" -d 'Add a new task to the current tree'
+complete -c forest -n "__fish_forest_using_subcommand task; and not __fish_seen_subcommand_from list add import remove rename show edit priority done start cancel reopen move copy depend undepend recur due tag untag help" -f -a "import" -d 'Import tasks from another task manager into the current tree'
+complete -c forest -n "__fish_forest_using_subcommand task; and not __fish_seen_subcommand_from list add import remove rename show edit priority done start cancel reopen move copy depend undepend recur due tag untag help" -f -a "remove" -d 'Remove a task from the current tree'
+complete -c forest -n "__fish_forest_using_subcommand task; and not __fish_seen_subcommand_from list add import remove rename show edit priority done start cancel reopen move copy depend undepend recur due tag untag help" -f -a "rename" -d 'Rename a task in the current tree'
+complete -c forest -n "__fish_forest_using_subcommand task; and not __fish_seen_subcommand_from list add import remove rename show edit priority done start cancel reopen move copy depend undepend recur due tag untag help" -f -a "show" -d 'Show description of a task in the current tree'
+complete -c forest -n "__fish_forest_using_subcommand task; and not __fish_seen_subcommand_from list add import remove rename show edit priority done start cancel reopen move copy depend undepend recur due tag untag help" -f -a "edit" -d 'Edit description of a task in the current tree'
+complete -c forest -n "__fish_forest_using_subcommand task; and not __fish_seen_subcommand_from list add import remove rename show edit priority done start cancel reopen move copy depend undepend recur due tag untag help" -f -a "priority" -d 'Set priority of a task in the current tree'
+complete -c forest -n "__fish_forest_using_subcommand task; and not __fish_seen_subcommand_from list add import remove rename show edit priority done start cancel reopen move copy depend undepend recur due tag untag help" -f -a "done" -d 'Mark a task of the current tree as done'
+complete -c forest -n "__fish_forest_using_subcommand task; and not __fish_seen_subcommand_from list add import remove rename show edit priority done start cancel reopen move copy depend undepend recur due tag untag help" -f -a "start" -d 'Mark a task of the current tree as in progress'
+complete -c forest -n "__fish_forest_using_subcommand task; and not __fish_seen_subcommand_from list add import remove rename show edit priority done start cancel reopen move copy depend undepend recur due tag untag help" -f -a "cancel" -d 'Mark a task of the current tree as cancelled'
+complete -c forest -n "__fish_forest_using_subcommand task; and not __fish_seen_subcommand_from list add import remove rename show edit priority done start cancel reopen move copy depend undepend recur due tag untag help" -f -a "reopen" -d 'Mark a done or cancelled task of the current tree as todo again'
+complete -c forest -n "__fish_forest_using_subcommand task; and not __fish_seen_subcommand_from list add import remove rename show edit priority done start cancel reopen move copy depend undepend recur due tag untag help" -f -a "move" -d 'Move a task of the current tree and its subtasks under another parent, possibly in another tree'
+complete -c forest -n "__fish_forest_using_subcommand task; and not __fish_seen_subcommand_from list add import remove rename show edit priority done start cancel reopen move copy depend undepend recur due tag untag help" -f -a "copy" -d 'Copy a task of the current tree and its subtasks under another parent, possibly in another tree'
+complete -c forest -n "__fish_forest_using_subcommand task; and not __fish_seen_subcommand_from list add import remove rename show edit priority done start cancel reopen move copy depend undepend recur due tag untag help" -f -a "depend" -d 'Make a task of the current tree depend on another task, possibly in another tree'
+complete -c forest -n "__fish_forest_using_subcommand task; and not __fish_seen_subcommand_from list add import remove rename show edit priority done start cancel reopen move copy depend undepend recur due tag untag help" -f -a "undepend" -d 'Remove a dependency of a task of the current tree'
+complete -c forest -n "__fish_forest_using_subcommand task; and not __fish_seen_subcommand_from list add import remove rename show edit priority done start cancel reopen move copy depend undepend recur due tag untag help" -f -a "recur" -d 'Set the recurrence rule of a task in the current tree'
+complete -c forest -n "__fish_forest_using_subcommand task; and not __fish_seen_subcommand_from list add import remove rename show edit priority done start cancel reopen move copy depend undepend recur due tag untag help" -f -a "due" -d 'Set due and scheduled dates of a task in the current tree'
+complete -c forest -n "__fish_forest_using_subcommand task; and not __fish_seen_subcommand_from list add import remove rename show edit priority done start cancel reopen move copy depend undepend recur due tag untag help" -f -a "tag" -d 'Add tags to a task in the current tree'
+complete -c forest -n "__fish_forest_using_subcommand task; and not __fish_seen_subcommand_from list add import remove rename show edit priority done start cancel reopen move copy depend undepend recur due tag untag help" -f -a "untag" -d 'Remove tags from a task in the current tree'
+complete -c forest -n "__fish_forest_using_subcommand task; and not __fish_seen_subcommand_from list add import remove rename show edit priority done start cancel reopen move copy depend undepend recur due tag untag help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from list" -s s -l status -d 'Only display tasks with the given status(es)' -r -f -a "todo\t'task not started yet'
+in-progress\t'task being worked on'
+done\t'task completed'
+cancelled\t'task abandoned'"
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from list" -l tag -d 'Only display tasks with one of the given tag(s)' -r
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from list" -s o -l output -d 'Output format of listings, reports and status' -r -f -a "text\t'human-readable colored text'
+json\t'JSON document'
+csv\t'comma-separated values, one record per line'
+markdown\t'Markdown table'"
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from list" -l forest -d 'Name of the forest to use instead of the current one' -r
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from list" -s h -l help -d 'Print help (see more with \'--help\')'
 complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from add" -s p -l parent -d 'Uid of parent task. By default, adds the task to the tree root' -r
 complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from add" -s d -l description -d 'Description of the new task' -r
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from add" -l due -d 'Date and time at which the new task is due' -r
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from add" -l scheduled -d 'Date and time at which the new task is planned to be worked on' -r
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from add" -l every -d 'Recurrence rule of the new task, eg. "weekly", "every 3 days" or "FREQ=MONTHLY;INTERVAL=2"' -r
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from add" -s o -l output -d 'Output format of listings, reports and status' -r -f -a "text\t'human-readable colored text'
+json\t'JSON document'
+csv\t'comma-separated values, one record per line'
+markdown\t'Markdown table'"
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from add" -l forest -d 'Name of the forest to use instead of the current one' -r
 complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from add" -s e -l edit -d 'Opens an editor to write the description of the new task'
-complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from add" -s h -l help -d 'Print help'
-complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from remove" -s h -l help -d 'Print help'
-complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from rename" -s h -l help -d 'Print help'
-complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from show" -s h -l help -d 'Print help'
-complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from edit" -s h -l help -d 'Print help'
-complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from priority" -s h -l help -d 'Print help'
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from add" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from import" -s f -l format -d 'Format of the file to import' -r -f -a "todotxt\t'todo.txt file, whose projects become parent tasks'
+taskwarrior\t'JSON output of `task export`, whose projects become parent tasks'
+markdown\t'Markdown checklist, nested by headings and indentation'"
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from import" -s p -l parent -d 'Uid of the task under which to import tasks. By default, imports them under the tree root' -r
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from import" -s o -l output -d 'Output format of listings, reports and status' -r -f -a "text\t'human-readable colored text'
+json\t'JSON document'
+csv\t'comma-separated values, one record per line'
+markdown\t'Markdown table'"
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from import" -l forest -d 'Name of the forest to use instead of the current one' -r
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from import" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from remove" -s o -l output -d 'Output format of listings, reports and status' -r -f -a "text\t'human-readable colored text'
+json\t'JSON document'
+csv\t'comma-separated values, one record per line'
+markdown\t'Markdown table'"
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from remove" -l forest -d 'Name of the forest to use instead of the current one' -r
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from remove" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from rename" -s o -l output -d 'Output format of listings, reports and status' -r -f -a "text\t'human-readable colored text'
+json\t'JSON document'
+csv\t'comma-separated values, one record per line'
+markdown\t'Markdown table'"
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from rename" -l forest -d 'Name of the forest to use instead of the current one' -r
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from rename" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from show" -s o -l output -d 'Output format of listings, reports and status' -r -f -a "text\t'human-readable colored text'
+json\t'JSON document'
+csv\t'comma-separated values, one record per line'
+markdown\t'Markdown table'"
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from show" -l forest -d 'Name of the forest to use instead of the current one' -r
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from show" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from edit" -s o -l output -d 'Output format of listings, reports and status' -r -f -a "text\t'human-readable colored text'
+json\t'JSON document'
+csv\t'comma-separated values, one record per line'
+markdown\t'Markdown table'"
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from edit" -l forest -d 'Name of the forest to use instead of the current one' -r
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from edit" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from priority" -s o -l output -d 'Output format of listings, reports and status' -r -f -a "text\t'human-readable colored text'
+json\t'JSON document'
+csv\t'comma-separated values, one record per line'
+markdown\t'Markdown table'"
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from priority" -l forest -d 'Name of the forest to use instead of the current one' -r
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from priority" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from done" -s o -l output -d 'Output format of listings, reports and status' -r -f -a "text\t'human-readable colored text'
+json\t'JSON document'
+csv\t'comma-separated values, one record per line'
+markdown\t'Markdown table'"
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from done" -l forest -d 'Name of the forest to use instead of the current one' -r
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from done" -s r -l recursive -d 'Also mark all open subtasks as done'
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from done" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from start" -s o -l output -d 'Output format of listings, reports and status' -r -f -a "text\t'human-readable colored text'
+json\t'JSON document'
+csv\t'comma-separated values, one record per line'
+markdown\t'Markdown table'"
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from start" -l forest -d 'Name of the forest to use instead of the current one' -r
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from start" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from cancel" -s o -l output -d 'Output format of listings, reports and status' -r -f -a "text\t'human-readable colored text'
+json\t'JSON document'
+csv\t'comma-separated values, one record per line'
+markdown\t'Markdown table'"
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from cancel" -l forest -d 'Name of the forest to use instead of the current one' -r
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from cancel" -s r -l recursive -d 'Also mark all open subtasks as cancelled'
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from cancel" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from reopen" -s o -l output -d 'Output format of listings, reports and status' -r -f -a "text\t'human-readable colored text'
+json\t'JSON document'
+csv\t'comma-separated values, one record per line'
+markdown\t'Markdown table'"
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from reopen" -l forest -d 'Name of the forest to use instead of the current one' -r
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from reopen" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from move" -s t -l tree -d 'Name of the destination tree. By default, the task is moved within the current tree' -r
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from move" -s p -l parent -d 'Uid of the new parent task in the destination tree. By default, the task is moved to the tree root' -r
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from move" -s n -l position -d 'Position of the task among the children of its new parent. By default, the task is moved after the last child' -r
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from move" -s o -l output -d 'Output format of listings, reports and status' -r -f -a "text\t'human-readable colored text'
+json\t'JSON document'
+csv\t'comma-separated values, one record per line'
+markdown\t'Markdown table'"
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from move" -l forest -d 'Name of the forest to use instead of the current one' -r
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from move" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from copy" -s t -l tree -d 'Name of the destination tree. By default, the task is copied within the current tree' -r
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from copy" -s p -l parent -d 'Uid of the new parent task in the destination tree. By default, the task is copied to the tree root' -r
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from copy" -s n -l position -d 'Position of the task among the children of its new parent. By default, the task is copied after the last child' -r
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from copy" -s o -l output -d 'Output format of listings, reports and status' -r -f -a "text\t'human-readable colored text'
+json\t'JSON document'
+csv\t'comma-separated values, one record per line'
+markdown\t'Markdown table'"
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from copy" -l forest -d 'Name of the forest to use instead of the current one' -r
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from copy" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from depend" -l on -d 'Uid of the task that must be done first' -r
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from depend" -s o -l output -d 'Output format of listings, reports and status' -r -f -a "text\t'human-readable colored text'
+json\t'JSON document'
+csv\t'comma-separated values, one record per line'
+markdown\t'Markdown table'"
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from depend" -l forest -d 'Name of the forest to use instead of the current one' -r
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from depend" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from undepend" -l on -d 'Uid of the task it should no longer depend on' -r
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from undepend" -s o -l output -d 'Output format of listings, reports and status' -r -f -a "text\t'human-readable colored text'
+json\t'JSON document'
+csv\t'comma-separated values, one record per line'
+markdown\t'Markdown table'"
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from undepend" -l forest -d 'Name of the forest to use instead of the current one' -r
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from undepend" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from recur" -s o -l output -d 'Output format of listings, reports and status' -r -f -a "text\t'human-readable colored text'
+json\t'JSON document'
+csv\t'comma-separated values, one record per line'
+markdown\t'Markdown table'"
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from recur" -l forest -d 'Name of the forest to use instead of the current one' -r
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from recur" -s c -l clear -d 'Stop repeating the task'
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from recur" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from due" -s s -l scheduled -d 'Date and time at which the task is planned to be worked on' -r
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from due" -s o -l output -d 'Output format of listings, reports and status' -r -f -a "text\t'human-readable colored text'
+json\t'JSON document'
+csv\t'comma-separated values, one record per line'
+markdown\t'Markdown table'"
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from due" -l forest -d 'Name of the forest to use instead of the current one' -r
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from due" -s c -l clear -d 'Remove the current due and scheduled dates of the task'
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from due" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from tag" -s o -l output -d 'Output format of listings, reports and status' -r -f -a "text\t'human-readable colored text'
+json\t'JSON document'
+csv\t'comma-separated values, one record per line'
+markdown\t'Markdown table'"
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from tag" -l forest -d 'Name of the forest to use instead of the current one' -r
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from tag" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from untag" -s o -l output -d 'Output format of listings, reports and status' -r -f -a "text\t'human-readable colored text'
+json\t'JSON document'
+csv\t'comma-separated values, one record per line'
+markdown\t'Markdown table'"
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from untag" -l forest -d 'Name of the forest to use instead of the current one' -r
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from untag" -s h -l help -d 'Print help (see more with \'--help\')'
 complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from help" -f -a "list" -d 'List tasks in the current tree'
 complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from help" -f -a "add" -d 'Add a new task to the current tree'
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from help" -f -a "import" -d 'Import tasks from another task manager into the current tree'
 complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from help" -f -a "remove" -d 'Remove a task from the current tree'
 complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from help" -f -a "rename" -d 'Rename a task in the current tree'
 complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from help" -f -a "show" -d 'Show description of a task in the current tree'
 complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from help" -f -a "edit" -d 'Edit description of a task in the current tree'
 complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from help" -f -a "priority" -d 'Set priority of a task in the current tree'
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from help" -f -a "done" -d 'Mark a task of the current tree as done'
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from help" -f -a "start" -d 'Mark a task of the current tree as in progress'
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from help" -f -a "cancel" -d 'Mark a task of the current tree as cancelled'
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from help" -f -a "reopen" -d 'Mark a done or cancelled task of the current tree as todo again'
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from help" -f -a "move" -d 'Move a task of the current tree and its subtasks under another parent, possibly in another tree'
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from help" -f -a "copy" -d 'Copy a task of the current tree and its subtasks under another parent, possibly in another tree'
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from help" -f -a "depend" -d 'Make a task of the current tree depend on another task, possibly in another tree'
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from help" -f -a "undepend" -d 'Remove a dependency of a task of the current tree'
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from help" -f -a "recur" -d 'Set the recurrence rule of a task in the current tree'
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from help" -f -a "due" -d 'Set due and scheduled dates of a task in the current tree'
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from help" -f -a "tag" -d 'Add tags to a task in the current tree'
+complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from help" -f -a "untag" -d 'Remove tags from a task in the current tree'
 complete -c forest -n "__fish_forest_using_subcommand task; and __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
-complete -c forest -n "__fish_forest_using_subcommand tree; and not __fish_seen_subcommand_from list add remove rename show edit help" -s h -l help -d 'Print help'
+complete -c forest -n "__fish_forest_using_subcommand tree; and not __fish_seen_subcommand_from list add remove rename show edit help" -s o -l output -d 'Output format of listings, reports and status' -r -f -a "text\t'human-readable colored text'
+json\t'JSON document'
+csv\t'comma-separated values, one record per line'
+markdown\t'Markdown table'"
+complete -c forest -n "__fish_forest_using_subcommand tree; and not __fish_seen_subcommand_from list add remove rename show edit help" -l forest -d 'Name of the forest to use instead of the current one' -r
+complete -c forest -n "__fish_forest_using_subcommand tree; and not __fish_seen_subcommand_from list add remove rename show edit help" -s h -l help -d 'Print help (see more with \'--help\')'
 complete -c forest -n "__fish_forest_using_subcommand tree; and not __fish_seen_subcommand_from list add remove rename show edit help" -f -a "list" -d 'List trees in the current forest'
 complete -c forest -n "__fish_forest_using_subcommand tree; and not __fish_seen_subcommand_from list add remove rename show edit help" -f -a "add" -d 'Add a new tree'
 complete -c forest -n "__fish_forest_using_subcommand tree; and not __fish_seen_subcommand_from list add remove rename show edit help" -f -a "remove" -d 'Remove a tree'
@@ -70,15 +262,46 @@ complete -c forest -n "__fish_forest_using_subcommand tree; and not __fish_seen_
 complete -c forest -n "__fish_forest_using_subcommand tree; and not __fish_seen_subcommand_from list add remove rename show edit help" -f -a "show" -d 'Show description of a tree'
 complete -c forest -n "__fish_forest_using_subcommand tree; and not __fish_seen_subcommand_from list add remove rename show edit help" -f -a "edit" -d 'Edit description of a tree'
 complete -c forest -n "__fish_forest_using_subcommand tree; and not __fish_seen_subcommand_from list add remove rename show edit help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
-complete -c forest -n "__fish_forest_using_subcommand tree; and __fish_seen_subcommand_from list" -s f -l format -d 'Formatting options' -r -f -a "{short\t'only display tree names',long\t''}"
+complete -c forest -n "__fish_forest_using_subcommand tree; and __fish_seen_subcommand_from list" -s f -l format -d 'Formatting options' -r -f -a "short\t'only display tree names'
+long\t''"
+complete -c forest -n "__fish_forest_using_subcommand tree; and __fish_seen_subcommand_from list" -s o -l output -d 'Output format of listings, reports and status' -r -f -a "text\t'human-readable colored text'
+json\t'JSON document'
+csv\t'comma-separated values, one record per line'
+markdown\t'Markdown table'"
+complete -c forest -n "__fish_forest_using_subcommand tree; and __fish_seen_subcommand_from list" -l forest -d 'Name of the forest to use instead of the current one' -r
 complete -c forest -n "__fish_forest_using_subcommand tree; and __fish_seen_subcommand_from list" -s h -l help -d 'Print help (see more with \'--help\')'
 complete -c forest -n "__fish_forest_using_subcommand tree; and __fish_seen_subcommand_from add" -s d -l description -d 'Description of the new tree' -r
+complete -c forest -n "__fish_forest_using_subcommand tree; and __fish_seen_subcommand_from add" -s o -l output -d 'Output format of listings, reports and status' -r -f -a "text\t'human-readable colored text'
+json\t'JSON document'
+csv\t'comma-separated values, one record per line'
+markdown\t'Markdown table'"
+complete -c forest -n "__fish_forest_using_subcommand tree; and __fish_seen_subcommand_from add" -l forest -d 'Name of the forest to use instead of the current one' -r
 complete -c forest -n "__fish_forest_using_subcommand tree; and __fish_seen_subcommand_from add" -s e -l edit -d 'Opens an editor to write the description of the new tree'
-complete -c forest -n "__fish_forest_using_subcommand tree; and __fish_seen_subcommand_from add" -s h -l help -d 'Print help'
-complete -c forest -n "__fish_forest_using_subcommand tree; and __fish_seen_subcommand_from remove" -s h -l help -d 'Print help'
-complete -c forest -n "__fish_forest_using_subcommand tree; and __fish_seen_subcommand_from rename" -s h -l help -d 'Print help'
-complete -c forest -n "__fish_forest_using_subcommand tree; and __fish_seen_subcommand_from show" -s h -l help -d 'Print help'
-complete -c forest -n "__fish_forest_using_subcommand tree; and __fish_seen_subcommand_from edit" -s h -l help -d 'Print help'
+complete -c forest -n "__fish_forest_using_subcommand tree; and __fish_seen_subcommand_from add" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c forest -n "__fish_forest_using_subcommand tree; and __fish_seen_subcommand_from remove" -s o -l output -d 'Output format of listings, reports and status' -r -f -a "text\t'human-readable colored text'
+json\t'JSON document'
+csv\t'comma-separated values, one record per line'
+markdown\t'Markdown table'"
+complete -c forest -n "__fish_forest_using_subcommand tree; and __fish_seen_subcommand_from remove" -l forest -d 'Name of the forest to use instead of the current one' -r
+complete -c forest -n "__fish_forest_using_subcommand tree; and __fish_seen_subcommand_from remove" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c forest -n "__fish_forest_using_subcommand tree; and __fish_seen_subcommand_from rename" -s o -l output -d 'Output format of listings, reports and status' -r -f -a "text\t'human-readable colored text'
+json\t'JSON document'
+csv\t'comma-separated values, one record per line'
+markdown\t'Markdown table'"
+complete -c forest -n "__fish_forest_using_subcommand tree; and __fish_seen_subcommand_from rename" -l forest -d 'Name of the forest to use instead of the current one' -r
+complete -c forest -n "__fish_forest_using_subcommand tree; and __fish_seen_subcommand_from rename" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c forest -n "__fish_forest_using_subcommand tree; and __fish_seen_subcommand_from show" -s o -l output -d 'Output format of listings, reports and status' -r -f -a "text\t'human-readable colored text'
+json\t'JSON document'
+csv\t'comma-separated values, one record per line'
+markdown\t'Markdown table'"
+complete -c forest -n "__fish_forest_using_subcommand tree; and __fish_seen_subcommand_from show" -l forest -d 'Name of the forest to use instead of the current one' -r
+complete -c forest -n "__fish_forest_using_subcommand tree; and __fish_seen_subcommand_from show" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c forest -n "__fish_forest_using_subcommand tree; and __fish_seen_subcommand_from edit" -s o -l output -d 'Output format of listings, reports and status' -r -f -a "text\t'human-readable colored text'
+json\t'JSON document'
+csv\t'comma-separated values, one record per line'
+markdown\t'Markdown table'"
+complete -c forest -n "__fish_forest_using_subcommand tree; and __fish_seen_subcommand_from edit" -l forest -d 'Name of the forest to use instead of the current one' -r
+complete -c forest -n "__fish_forest_using_subcommand tree; and __fish_seen_subcommand_from edit" -s h -l help -d 'Print help (see more with \'--help\')'
 complete -c forest -n "__fish_forest_using_subcommand tree; and __fish_seen_subcommand_from help" -f -a "list" -d 'List trees in the current forest'
 complete -c forest -n "__fish_forest_using_subcommand tree; and __fish_seen_subcommand_from help" -f -a "add" -d 'Add a new tree'
 complete -c forest -n "__fish_forest_using_subcommand tree; and __fish_seen_subcommand_from help" -f -a "remove" -d 'Remove a tree'
@@ -86,49 +309,384 @@ complete -c forest -n "__fish_forest_using_subcommand tree; and __fish_seen_subc
 complete -c forest -n "__fish_forest_using_subcommand tree; and __fish_seen_subcommand_from help" -f -a "show" -d 'Show description of a tree'
 complete -c forest -n "__fish_forest_using_subcommand tree; and __fish_seen_subcommand_from help" -f -a "edit" -d 'Edit description of a tree'
 complete -c forest -n "__fish_forest_using_subcommand tree; and __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
-complete -c forest -n "__fish_forest_using_subcommand note; and not __fish_seen_subcommand_from list add remove show edit help" -s h -l help -d 'Print help'
-complete -c forest -n "__fish_forest_using_subcommand note; and not __fish_seen_subcommand_from list add remove show edit help" -f -a "list" -d 'List all notes'
-complete -c forest -n "__fish_forest_using_subcommand note; and not __fish_seen_subcommand_from list add remove show edit help" -f -a "add" -d 'Create a new note associated to the current tree'
-complete -c forest -n "__fish_forest_using_subcommand note; and not __fish_seen_subcommand_from list add remove show edit help" -f -a "remove" -d 'Remove a note'
-complete -c forest -n "__fish_forest_using_subcommand note; and not __fish_seen_subcommand_from list add remove show edit help" -f -a "show" -d 'Show content of a note'
-complete -c forest -n "__fish_forest_using_subcommand note; and not __fish_seen_subcommand_from list add remove show edit help" -f -a "edit" -d 'Edit a note'
-complete -c forest -n "__fish_forest_using_subcommand note; and not __fish_seen_subcommand_from list add remove show edit help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c forest -n "__fish_forest_using_subcommand note; and not __fish_seen_subcommand_from list add remove show edit tag untag help" -s o -l output -d 'Output format of listings, reports and status' -r -f -a "text\t'human-readable colored text'
+json\t'JSON document'
+csv\t'comma-separated values, one record per line'
+markdown\t'Markdown table'"
+complete -c forest -n "__fish_forest_using_subcommand note; and not __fish_seen_subcommand_from list add remove show edit tag untag help" -l forest -d 'Name of the forest to use instead of the current one' -r
+complete -c forest -n "__fish_forest_using_subcommand note; and not __fish_seen_subcommand_from list add remove show edit tag untag help" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c forest -n "__fish_forest_using_subcommand note; and not __fish_seen_subcommand_from list add remove show edit tag untag help" -f -a "list" -d 'List all notes'
+complete -c forest -n "__fish_forest_using_subcommand note; and not __fish_seen_subcommand_from list add remove show edit tag untag help" -f -a "add" -d 'Create a new note associated to the current tree'
+complete -c forest -n "__fish_forest_using_subcommand note; and not __fish_seen_subcommand_from list add remove show edit tag untag help" -f -a "remove" -d 'Remove a note'
+complete -c forest -n "__fish_forest_using_subcommand note; and not __fish_seen_subcommand_from list add remove show edit tag untag help" -f -a "show" -d 'Show content of a note'
+complete -c forest -n "__fish_forest_using_subcommand note; and not __fish_seen_subcommand_from list add remove show edit tag untag help" -f -a "edit" -d 'Edit a note'
+complete -c forest -n "__fish_forest_using_subcommand note; and not __fish_seen_subcommand_from list add remove show edit tag untag help" -f -a "tag" -d 'Add tags to a note'
+complete -c forest -n "__fish_forest_using_subcommand note; and not __fish_seen_subcommand_from list add remove show edit tag untag help" -f -a "untag" -d 'Remove tags from a note'
+complete -c forest -n "__fish_forest_using_subcommand note; and not __fish_seen_subcommand_from list add remove show edit tag untag help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c forest -n "__fish_forest_using_subcommand note; and __fish_seen_subcommand_from list" -l tag -d 'Only display notes with one of the given tag(s)' -r
+complete -c forest -n "__fish_forest_using_subcommand note; and __fish_seen_subcommand_from list" -s o -l output -d 'Output format of listings, reports and status' -r -f -a "text\t'human-readable colored text'
+json\t'JSON document'
+csv\t'comma-separated values, one record per line'
+markdown\t'Markdown table'"
+complete -c forest -n "__fish_forest_using_subcommand note; and __fish_seen_subcommand_from list" -l forest -d 'Name of the forest to use instead of the current one' -r
 complete -c forest -n "__fish_forest_using_subcommand note; and __fish_seen_subcommand_from list" -s t -l show-tt -d 'Show time tracking notes (hidden by default)'
-complete -c forest -n "__fish_forest_using_subcommand note; and __fish_seen_subcommand_from list" -s h -l help -d 'Print help'
-complete -c forest -n "__fish_forest_using_subcommand note; and __fish_seen_subcommand_from add" -s h -l help -d 'Print help'
-complete -c forest -n "__fish_forest_using_subcommand note; and __fish_seen_subcommand_from remove" -s h -l help -d 'Print help'
-complete -c forest -n "__fish_forest_using_subcommand note; and __fish_seen_subcommand_from show" -s h -l help -d 'Print help'
-complete -c forest -n "__fish_forest_using_subcommand note; and __fish_seen_subcommand_from edit" -s h -l help -d 'Print help'
+complete -c forest -n "__fish_forest_using_subcommand note; and __fish_seen_subcommand_from list" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c forest -n "__fish_forest_using_subcommand note; and __fish_seen_subcommand_from add" -s o -l output -d 'Output format of listings, reports and status' -r -f -a "text\t'human-readable colored text'
+json\t'JSON document'
+csv\t'comma-separated values, one record per line'
+markdown\t'Markdown table'"
+complete -c forest -n "__fish_forest_using_subcommand note; and __fish_seen_subcommand_from add" -l forest -d 'Name of the forest to use instead of the current one' -r
+complete -c forest -n "__fish_forest_using_subcommand note; and __fish_seen_subcommand_from add" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c forest -n "__fish_forest_using_subcommand note; and __fish_seen_subcommand_from remove" -s o -l output -d 'Output format of listings, reports and status' -r -f -a "text\t'human-readable colored text'
+json\t'JSON document'
+csv\t'comma-separated values, one record per line'
+markdown\t'Markdown table'"
+complete -c forest -n "__fish_forest_using_subcommand note; and __fish_seen_subcommand_from remove" -l forest -d 'Name of the forest to use instead of the current one' -r
+complete -c forest -n "__fish_forest_using_subcommand note; and __fish_seen_subcommand_from remove" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c forest -n "__fish_forest_using_subcommand note; and __fish_seen_subcommand_from show" -s o -l output -d 'Output format of listings, reports and status' -r -f -a "text\t'human-readable colored text'
+json\t'JSON document'
+csv\t'comma-separated values, one record per line'
+markdown\t'Markdown table'"
+complete -c forest -n "__fish_forest_using_subcommand note; and __fish_seen_subcommand_from show" -l forest -d 'Name of the forest to use instead of the current one' -r
+complete -c forest -n "__fish_forest_using_subcommand note; and __fish_seen_subcommand_from show" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c forest -n "__fish_forest_using_subcommand note; and __fish_seen_subcommand_from edit" -s o -l output -d 'Output format of listings, reports and status' -r -f -a "text\t'human-readable colored text'
+json\t'JSON document'
+csv\t'comma-separated values, one record per line'
+markdown\t'Markdown table'"
+complete -c forest -n "__fish_forest_using_subcommand note; and __fish_seen_subcommand_from edit" -l forest -d 'Name of the forest to use instead of the current one' -r
+complete -c forest -n "__fish_forest_using_subcommand note; and __fish_seen_subcommand_from edit" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c forest -n "__fish_forest_using_subcommand note; and __fish_seen_subcommand_from tag" -s o -l output -d 'Output format of listings, reports and status' -r -f -a "text\t'human-readable colored text'
+json\t'JSON document'
+csv\t'comma-separated values, one record per line'
+markdown\t'Markdown table'"
+complete -c forest -n "__fish_forest_using_subcommand note; and __fish_seen_subcommand_from tag" -l forest -d 'Name of the forest to use instead of the current one' -r
+complete -c forest -n "__fish_forest_using_subcommand note; and __fish_seen_subcommand_from tag" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c forest -n "__fish_forest_using_subcommand note; and __fish_seen_subcommand_from untag" -s o -l output -d 'Output format of listings, reports and status' -r -f -a "text\t'human-readable colored text'
+json\t'JSON document'
+csv\t'comma-separated values, one record per line'
+markdown\t'Markdown table'"
+complete -c forest -n "__fish_forest_using_subcommand note; and __fish_seen_subcommand_from untag" -l forest -d 'Name of the forest to use instead of the current one' -r
+complete -c forest -n "__fish_forest_using_subcommand note; and __fish_seen_subcommand_from untag" -s h -l help -d 'Print help (see more with \'--help\')'
 complete -c forest -n "__fish_forest_using_subcommand note; and __fish_seen_subcommand_from help" -f -a "list" -d 'List all notes'
 complete -c forest -n "__fish_forest_using_subcommand note; and __fish_seen_subcommand_from help" -f -a "add" -d 'Create a new note associated to the current tree'
 complete -c forest -n "__fish_forest_using_subcommand note; and __fish_seen_subcommand_from help" -f -a "remove" -d 'Remove a note'
 complete -c forest -n "__fish_forest_using_subcommand note; and __fish_seen_subcommand_from help" -f -a "show" -d 'Show content of a note'
 complete -c forest -n "__fish_forest_using_subcommand note; and __fish_seen_subcommand_from help" -f -a "edit" -d 'Edit a note'
+complete -c forest -n "__fish_forest_using_subcommand note; and __fish_seen_subcommand_from help" -f -a "tag" -d 'Add tags to a note'
+complete -c forest -n "__fish_forest_using_subcommand note; and __fish_seen_subcommand_from help" -f -a "untag" -d 'Remove tags from a note'
 complete -c forest -n "__fish_forest_using_subcommand note; and __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
-complete -c forest -n "__fish_forest_using_subcommand switch" -s h -l help -d 'Print help'
+complete -c forest -n "__fish_forest_using_subcommand frame; and not __fish_seen_subcommand_from list add edit remove split merge help" -s o -l output -d 'Output format of listings, reports and status' -r -f -a "text\t'human-readable colored text'
+json\t'JSON document'
+csv\t'comma-separated values, one record per line'
+markdown\t'Markdown table'"
+complete -c forest -n "__fish_forest_using_subcommand frame; and not __fish_seen_subcommand_from list add edit remove split merge help" -l forest -d 'Name of the forest to use instead of the current one' -r
+complete -c forest -n "__fish_forest_using_subcommand frame; and not __fish_seen_subcommand_from list add edit remove split merge help" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c forest -n "__fish_forest_using_subcommand frame; and not __fish_seen_subcommand_from list add edit remove split merge help" -f -a "list" -d 'List time tracking frames, most recent first'
+complete -c forest -n "__fish_forest_using_subcommand frame; and not __fish_seen_subcommand_from list add edit remove split merge help" -f -a "add" -d 'Add a time tracking frame'
+complete -c forest -n "__fish_forest_using_subcommand frame; and not __fish_seen_subcommand_from list add edit remove split merge help" -f -a "edit" -d 'Edit a time tracking frame'
+complete -c forest -n "__fish_forest_using_subcommand frame; and not __fish_seen_subcommand_from list add edit remove split merge help" -f -a "remove" -d 'Remove a time tracking frame'
+complete -c forest -n "__fish_forest_using_subcommand frame; and not __fish_seen_subcommand_from list add edit remove split merge help" -f -a "split" -d 'Split a time tracking frame in two at the given date and time'
+complete -c forest -n "__fish_forest_using_subcommand frame; and not __fish_seen_subcommand_from list add edit remove split merge help" -f -a "merge" -d 'Merge two time tracking frames of the same tree or task'
+complete -c forest -n "__fish_forest_using_subcommand frame; and not __fish_seen_subcommand_from list add edit remove split merge help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c forest -n "__fish_forest_using_subcommand frame; and __fish_seen_subcommand_from list" -s t -l tree -d 'Only list frames of the given tree' -r
+complete -c forest -n "__fish_forest_using_subcommand frame; and __fish_seen_subcommand_from list" -l from -d 'Only list frames ending after the given date and time' -r
+complete -c forest -n "__fish_forest_using_subcommand frame; and __fish_seen_subcommand_from list" -l to -d 'Only list frames starting before the given date and time' -r
+complete -c forest -n "__fish_forest_using_subcommand frame; and __fish_seen_subcommand_from list" -s o -l output -d 'Output format of listings, reports and status' -r -f -a "text\t'human-readable colored text'
+json\t'JSON document'
+csv\t'comma-separated values, one record per line'
+markdown\t'Markdown table'"
+complete -c forest -n "__fish_forest_using_subcommand frame; and __fish_seen_subcommand_from list" -l forest -d 'Name of the forest to use instead of the current one' -r
+complete -c forest -n "__fish_forest_using_subcommand frame; and __fish_seen_subcommand_from list" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c forest -n "__fish_forest_using_subcommand frame; and __fish_seen_subcommand_from add" -l task -d 'Uid of task on which time was spent' -r
+complete -c forest -n "__fish_forest_using_subcommand frame; and __fish_seen_subcommand_from add" -l start -d 'Start date and time of the frame' -r
+complete -c forest -n "__fish_forest_using_subcommand frame; and __fish_seen_subcommand_from add" -l end -d 'End date and time of the frame' -r
+complete -c forest -n "__fish_forest_using_subcommand frame; and __fish_seen_subcommand_from add" -s o -l output -d 'Output format of listings, reports and status' -r -f -a "text\t'human-readable colored text'
+json\t'JSON document'
+csv\t'comma-separated values, one record per line'
+markdown\t'Markdown table'"
+complete -c forest -n "__fish_forest_using_subcommand frame; and __fish_seen_subcommand_from add" -l forest -d 'Name of the forest to use instead of the current one' -r
+complete -c forest -n "__fish_forest_using_subcommand frame; and __fish_seen_subcommand_from add" -l adjust -d 'Trim, split or remove overlapping frames instead of failing'
+complete -c forest -n "__fish_forest_using_subcommand frame; and __fish_seen_subcommand_from add" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c forest -n "__fish_forest_using_subcommand frame; and __fish_seen_subcommand_from edit" -l start -d 'New start date and time of the frame' -r
+complete -c forest -n "__fish_forest_using_subcommand frame; and __fish_seen_subcommand_from edit" -l end -d 'New end date and time of the frame' -r
+complete -c forest -n "__fish_forest_using_subcommand frame; and __fish_seen_subcommand_from edit" -l tree -d 'Attach the frame to the given tree' -r
+complete -c forest -n "__fish_forest_using_subcommand frame; and __fish_seen_subcommand_from edit" -l task -d 'Attach the frame to the given task' -r
+complete -c forest -n "__fish_forest_using_subcommand frame; and __fish_seen_subcommand_from edit" -s o -l output -d 'Output format of listings, reports and status' -r -f -a "text\t'human-readable colored text'
+json\t'JSON document'
+csv\t'comma-separated values, one record per line'
+markdown\t'Markdown table'"
+complete -c forest -n "__fish_forest_using_subcommand frame; and __fish_seen_subcommand_from edit" -l forest -d 'Name of the forest to use instead of the current one' -r
+complete -c forest -n "__fish_forest_using_subcommand frame; and __fish_seen_subcommand_from edit" -l adjust -d 'Trim, split or remove overlapping frames instead of failing'
+complete -c forest -n "__fish_forest_using_subcommand frame; and __fish_seen_subcommand_from edit" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c forest -n "__fish_forest_using_subcommand frame; and __fish_seen_subcommand_from remove" -s o -l output -d 'Output format of listings, reports and status' -r -f -a "text\t'human-readable colored text'
+json\t'JSON document'
+csv\t'comma-separated values, one record per line'
+markdown\t'Markdown table'"
+complete -c forest -n "__fish_forest_using_subcommand frame; and __fish_seen_subcommand_from remove" -l forest -d 'Name of the forest to use instead of the current one' -r
+complete -c forest -n "__fish_forest_using_subcommand frame; and __fish_seen_subcommand_from remove" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c forest -n "__fish_forest_using_subcommand frame; and __fish_seen_subcommand_from split" -l at -d 'Date and time at which to split the frame' -r
+complete -c forest -n "__fish_forest_using_subcommand frame; and __fish_seen_subcommand_from split" -s o -l output -d 'Output format of listings, reports and status' -r -f -a "text\t'human-readable colored text'
+json\t'JSON document'
+csv\t'comma-separated values, one record per line'
+markdown\t'Markdown table'"
+complete -c forest -n "__fish_forest_using_subcommand frame; and __fish_seen_subcommand_from split" -l forest -d 'Name of the forest to use instead of the current one' -r
+complete -c forest -n "__fish_forest_using_subcommand frame; and __fish_seen_subcommand_from split" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c forest -n "__fish_forest_using_subcommand frame; and __fish_seen_subcommand_from merge" -s o -l output -d 'Output format of listings, reports and status' -r -f -a "text\t'human-readable colored text'
+json\t'JSON document'
+csv\t'comma-separated values, one record per line'
+markdown\t'Markdown table'"
+complete -c forest -n "__fish_forest_using_subcommand frame; and __fish_seen_subcommand_from merge" -l forest -d 'Name of the forest to use instead of the current one' -r
+complete -c forest -n "__fish_forest_using_subcommand frame; and __fish_seen_subcommand_from merge" -l adjust -d 'Trim, split or remove overlapping frames instead of failing'
+complete -c forest -n "__fish_forest_using_subcommand frame; and __fish_seen_subcommand_from merge" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c forest -n "__fish_forest_using_subcommand frame; and __fish_seen_subcommand_from help" -f -a "list" -d 'List time tracking frames, most recent first'
+complete -c forest -n "__fish_forest_using_subcommand frame; and __fish_seen_subcommand_from help" -f -a "add" -d 'Add a time tracking frame'
+complete -c forest -n "__fish_forest_using_subcommand frame; and __fish_seen_subcommand_from help" -f -a "edit" -d 'Edit a time tracking frame'
+complete -c forest -n "__fish_forest_using_subcommand frame; and __fish_seen_subcommand_from help" -f -a "remove" -d 'Remove a time tracking frame'
+complete -c forest -n "__fish_forest_using_subcommand frame; and __fish_seen_subcommand_from help" -f -a "split" -d 'Split a time tracking frame in two at the given date and time'
+complete -c forest -n "__fish_forest_using_subcommand frame; and __fish_seen_subcommand_from help" -f -a "merge" -d 'Merge two time tracking frames of the same tree or task'
+complete -c forest -n "__fish_forest_using_subcommand frame; and __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c forest -n "__fish_forest_using_subcommand profile; and not __fish_seen_subcommand_from list create use help" -s o -l output -d 'Output format of listings, reports and status' -r -f -a "text\t'human-readable colored text'
+json\t'JSON document'
+csv\t'comma-separated values, one record per line'
+markdown\t'Markdown table'"
+complete -c forest -n "__fish_forest_using_subcommand profile; and not __fish_seen_subcommand_from list create use help" -l forest -d 'Name of the forest to use instead of the current one' -r
+complete -c forest -n "__fish_forest_using_subcommand profile; and not __fish_seen_subcommand_from list create use help" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c forest -n "__fish_forest_using_subcommand profile; and not __fish_seen_subcommand_from list create use help" -f -a "list" -d 'List all forests'
+complete -c forest -n "__fish_forest_using_subcommand profile; and not __fish_seen_subcommand_from list create use help" -f -a "create" -d 'Create a new empty forest'
+complete -c forest -n "__fish_forest_using_subcommand profile; and not __fish_seen_subcommand_from list create use help" -f -a "use" -d 'Use another forest by default'
+complete -c forest -n "__fish_forest_using_subcommand profile; and not __fish_seen_subcommand_from list create use help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c forest -n "__fish_forest_using_subcommand profile; and __fish_seen_subcommand_from list" -s o -l output -d 'Output format of listings, reports and status' -r -f -a "text\t'human-readable colored text'
+json\t'JSON document'
+csv\t'comma-separated values, one record per line'
+markdown\t'Markdown table'"
+complete -c forest -n "__fish_forest_using_subcommand profile; and __fish_seen_subcommand_from list" -l forest -d 'Name of the forest to use instead of the current one' -r
+complete -c forest -n "__fish_forest_using_subcommand profile; and __fish_seen_subcommand_from list" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c forest -n "__fish_forest_using_subcommand profile; and __fish_seen_subcommand_from create" -s o -l output -d 'Output format of listings, reports and status' -r -f -a "text\t'human-readable colored text'
+json\t'JSON document'
+csv\t'comma-separated values, one record per line'
+markdown\t'Markdown table'"
+complete -c forest -n "__fish_forest_using_subcommand profile; and __fish_seen_subcommand_from create" -l forest -d 'Name of the forest to use instead of the current one' -r
+complete -c forest -n "__fish_forest_using_subcommand profile; and __fish_seen_subcommand_from create" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c forest -n "__fish_forest_using_subcommand profile; and __fish_seen_subcommand_from use" -s o -l output -d 'Output format of listings, reports and status' -r -f -a "text\t'human-readable colored text'
+json\t'JSON document'
+csv\t'comma-separated values, one record per line'
+markdown\t'Markdown table'"
+complete -c forest -n "__fish_forest_using_subcommand profile; and __fish_seen_subcommand_from use" -l forest -d 'Name of the forest to use instead of the current one' -r
+complete -c forest -n "__fish_forest_using_subcommand profile; and __fish_seen_subcommand_from use" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c forest -n "__fish_forest_using_subcommand profile; and __fish_seen_subcommand_from help" -f -a "list" -d 'List all forests'
+complete -c forest -n "__fish_forest_using_subcommand profile; and __fish_seen_subcommand_from help" -f -a "create" -d 'Create a new empty forest'
+complete -c forest -n "__fish_forest_using_subcommand profile; and __fish_seen_subcommand_from help" -f -a "use" -d 'Use another forest by default'
+complete -c forest -n "__fish_forest_using_subcommand profile; and __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c forest -n "__fish_forest_using_subcommand db; and not __fish_seen_subcommand_from migrate status help" -s o -l output -d 'Output format of listings, reports and status' -r -f -a "text\t'human-readable colored text'
+json\t'JSON document'
+csv\t'comma-separated values, one record per line'
+markdown\t'Markdown table'"
+complete -c forest -n "__fish_forest_using_subcommand db; and not __fish_seen_subcommand_from migrate status help" -l forest -d 'Name of the forest to use instead of the current one' -r
+complete -c forest -n "__fish_forest_using_subcommand db; and not __fish_seen_subcommand_from migrate status help" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c forest -n "__fish_forest_using_subcommand db; and not __fish_seen_subcommand_from migrate status help" -f -a "migrate" -d 'Apply pending migrations to the database schema'
+complete -c forest -n "__fish_forest_using_subcommand db; and not __fish_seen_subcommand_from migrate status help" -f -a "status" -d 'Show the schema version of the database and pending migrations'
+complete -c forest -n "__fish_forest_using_subcommand db; and not __fish_seen_subcommand_from migrate status help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c forest -n "__fish_forest_using_subcommand db; and __fish_seen_subcommand_from migrate" -s o -l output -d 'Output format of listings, reports and status' -r -f -a "text\t'human-readable colored text'
+json\t'JSON document'
+csv\t'comma-separated values, one record per line'
+markdown\t'Markdown table'"
+complete -c forest -n "__fish_forest_using_subcommand db; and __fish_seen_subcommand_from migrate" -l forest -d 'Name of the forest to use instead of the current one' -r
+complete -c forest -n "__fish_forest_using_subcommand db; and __fish_seen_subcommand_from migrate" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c forest -n "__fish_forest_using_subcommand db; and __fish_seen_subcommand_from status" -s o -l output -d 'Output format of listings, reports and status' -r -f -a "text\t'human-readable colored text'
+json\t'JSON document'
+csv\t'comma-separated values, one record per line'
+markdown\t'Markdown table'"
+complete -c forest -n "__fish_forest_using_subcommand db; and __fish_seen_subcommand_from status" -l forest -d 'Name of the forest to use instead of the current one' -r
+complete -c forest -n "__fish_forest_using_subcommand db; and __fish_seen_subcommand_from status" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c forest -n "__fish_forest_using_subcommand db; and __fish_seen_subcommand_from help" -f -a "migrate" -d 'Apply pending migrations to the database schema'
+complete -c forest -n "__fish_forest_using_subcommand db; and __fish_seen_subcommand_from help" -f -a "status" -d 'Show the schema version of the database and pending migrations'
+complete -c forest -n "__fish_forest_using_subcommand db; and __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c forest -n "__fish_forest_using_subcommand switch" -s o -l output -d 'Output format of listings, reports and status' -r -f -a "text\t'human-readable colored text'
+json\t'JSON document'
+csv\t'comma-separated values, one record per line'
+markdown\t'Markdown table'"
+complete -c forest -n "__fish_forest_using_subcommand switch" -l forest -d 'Name of the forest to use instead of the current one' -r
+complete -c forest -n "__fish_forest_using_subcommand switch" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c forest -n "__fish_forest_using_subcommand start" -s t -l task -d 'Uid of task for which to record time' -r
 complete -c forest -n "__fish_forest_using_subcommand start" -l at -d 'Start date and time of recording' -r
-complete -c forest -n "__fish_forest_using_subcommand start" -s h -l help -d 'Print help'
+complete -c forest -n "__fish_forest_using_subcommand start" -s o -l output -d 'Output format of listings, reports and status' -r -f -a "text\t'human-readable colored text'
+json\t'JSON document'
+csv\t'comma-separated values, one record per line'
+markdown\t'Markdown table'"
+complete -c forest -n "__fish_forest_using_subcommand start" -l forest -d 'Name of the forest to use instead of the current one' -r
+complete -c forest -n "__fish_forest_using_subcommand start" -l adjust -d 'Trim, split or remove overlapping frames instead of failing'
+complete -c forest -n "__fish_forest_using_subcommand start" -s h -l help -d 'Print help (see more with \'--help\')'
 complete -c forest -n "__fish_forest_using_subcommand stop" -l at -d 'Stop date and time of recording' -r
+complete -c forest -n "__fish_forest_using_subcommand stop" -s o -l output -d 'Output format of listings, reports and status' -r -f -a "text\t'human-readable colored text'
+json\t'JSON document'
+csv\t'comma-separated values, one record per line'
+markdown\t'Markdown table'"
+complete -c forest -n "__fish_forest_using_subcommand stop" -l forest -d 'Name of the forest to use instead of the current one' -r
 complete -c forest -n "__fish_forest_using_subcommand stop" -s n -l no-note -d 'Do not create a new note'
-complete -c forest -n "__fish_forest_using_subcommand stop" -s h -l help -d 'Print help'
-complete -c forest -n "__fish_forest_using_subcommand status" -s h -l help -d 'Print help'
-complete -c forest -n "__fish_forest_using_subcommand report" -s h -l help -d 'Print help'
-complete -c forest -n "__fish_forest_using_subcommand help; and not __fish_seen_subcommand_from task tree note switch start stop status report help" -f -a "task" -d 'Perform operations on tasks'
-complete -c forest -n "__fish_forest_using_subcommand help; and not __fish_seen_subcommand_from task tree note switch start stop status report help" -f -a "tree" -d 'Perform operations on trees'
-complete -c forest -n "__fish_forest_using_subcommand help; and not __fish_seen_subcommand_from task tree note switch start stop status report help" -f -a "note" -d 'Perform operations on notes'
-complete -c forest -n "__fish_forest_using_subcommand help; and not __fish_seen_subcommand_from task tree note switch start stop status report help" -f -a "switch" -d 'Switch to another tree'
-complete -c forest -n "__fish_forest_using_subcommand help; and not __fish_seen_subcommand_from task tree note switch start stop status report help" -f -a "start" -d 'Start recording time'
-complete -c forest -n "__fish_forest_using_subcommand help; and not __fish_seen_subcommand_from task tree note switch start stop status report help" -f -a "stop" -d 'Stop current time recording'
-complete -c forest -n "__fish_forest_using_subcommand help; and not __fish_seen_subcommand_from task tree note switch start stop status report help" -f -a "status" -d 'Show current time recording'
-complete -c forest -n "__fish_forest_using_subcommand help; and not __fish_seen_subcommand_from task tree note switch start stop status report help" -f -a "report" -d 'Reports time spent on each tree'
-complete -c forest -n "__fish_forest_using_subcommand help; and not __fish_seen_subcommand_from task tree note switch start stop status report help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c forest -n "__fish_forest_using_subcommand stop" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c forest -n "__fish_forest_using_subcommand cancel" -s o -l output -d 'Output format of listings, reports and status' -r -f -a "text\t'human-readable colored text'
+json\t'JSON document'
+csv\t'comma-separated values, one record per line'
+markdown\t'Markdown table'"
+complete -c forest -n "__fish_forest_using_subcommand cancel" -l forest -d 'Name of the forest to use instead of the current one' -r
+complete -c forest -n "__fish_forest_using_subcommand cancel" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c forest -n "__fish_forest_using_subcommand restart" -l at -d 'Start date and time of recording' -r
+complete -c forest -n "__fish_forest_using_subcommand restart" -s o -l output -d 'Output format of listings, reports and status' -r -f -a "text\t'human-readable colored text'
+json\t'JSON document'
+csv\t'comma-separated values, one record per line'
+markdown\t'Markdown table'"
+complete -c forest -n "__fish_forest_using_subcommand restart" -l forest -d 'Name of the forest to use instead of the current one' -r
+complete -c forest -n "__fish_forest_using_subcommand restart" -l adjust -d 'Trim, split or remove overlapping frames instead of failing'
+complete -c forest -n "__fish_forest_using_subcommand restart" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c forest -n "__fish_forest_using_subcommand import" -s f -l from -d 'Time tracker the records come from' -r -f -a "watson\t'Watson frames file'
+timewarrior\t'JSON output of `timew export`'
+toggl-csv\t'CSV export of detailed time entries from Toggl Track or Clockify'"
+complete -c forest -n "__fish_forest_using_subcommand import" -s o -l output -d 'Output format of listings, reports and status' -r -f -a "text\t'human-readable colored text'
+json\t'JSON document'
+csv\t'comma-separated values, one record per line'
+markdown\t'Markdown table'"
+complete -c forest -n "__fish_forest_using_subcommand import" -l forest -d 'Name of the forest to use instead of the current one' -r
+complete -c forest -n "__fish_forest_using_subcommand import" -s n -l dry-run -d 'Show the records that would be imported without importing them'
+complete -c forest -n "__fish_forest_using_subcommand import" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c forest -n "__fish_forest_using_subcommand backup" -s o -l output -d 'Output format of listings, reports and status' -r -f -a "text\t'human-readable colored text'
+json\t'JSON document'
+csv\t'comma-separated values, one record per line'
+markdown\t'Markdown table'"
+complete -c forest -n "__fish_forest_using_subcommand backup" -l forest -d 'Name of the forest to use instead of the current one' -r
+complete -c forest -n "__fish_forest_using_subcommand backup" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c forest -n "__fish_forest_using_subcommand restore" -s m -l mode -d 'Whether to replace the forest with the archive, or to add what is missing from it' -r -f -a "replace\t'Replace the whole forest with the backup'
+merge\t'Add the trees, tasks, frames and notes of the backup that are missing from the forest'"
+complete -c forest -n "__fish_forest_using_subcommand restore" -s o -l output -d 'Output format of listings, reports and status' -r -f -a "text\t'human-readable colored text'
+json\t'JSON document'
+csv\t'comma-separated values, one record per line'
+markdown\t'Markdown table'"
+complete -c forest -n "__fish_forest_using_subcommand restore" -l forest -d 'Name of the forest to use instead of the current one' -r
+complete -c forest -n "__fish_forest_using_subcommand restore" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c forest -n "__fish_forest_using_subcommand export; and not __fish_seen_subcommand_from ics help" -s o -l output -d 'Output format of listings, reports and status' -r -f -a "text\t'human-readable colored text'
+json\t'JSON document'
+csv\t'comma-separated values, one record per line'
+markdown\t'Markdown table'"
+complete -c forest -n "__fish_forest_using_subcommand export; and not __fish_seen_subcommand_from ics help" -l forest -d 'Name of the forest to use instead of the current one' -r
+complete -c forest -n "__fish_forest_using_subcommand export; and not __fish_seen_subcommand_from ics help" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c forest -n "__fish_forest_using_subcommand export; and not __fish_seen_subcommand_from ics help" -f -a "ics" -d 'Write time tracking frames as events and due dates of tasks as to-dos, in iCalendar format'
+complete -c forest -n "__fish_forest_using_subcommand export; and not __fish_seen_subcommand_from ics help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c forest -n "__fish_forest_using_subcommand export; and __fish_seen_subcommand_from ics" -l from -d 'Only export frames ending and tasks due after the given date and time' -r
+complete -c forest -n "__fish_forest_using_subcommand export; and __fish_seen_subcommand_from ics" -l to -d 'Only export frames starting and tasks due before the given date and time' -r
+complete -c forest -n "__fish_forest_using_subcommand export; and __fish_seen_subcommand_from ics" -s o -l output -d 'Output format of listings, reports and status' -r -f -a "text\t'human-readable colored text'
+json\t'JSON document'
+csv\t'comma-separated values, one record per line'
+markdown\t'Markdown table'"
+complete -c forest -n "__fish_forest_using_subcommand export; and __fish_seen_subcommand_from ics" -l forest -d 'Name of the forest to use instead of the current one' -r
+complete -c forest -n "__fish_forest_using_subcommand export; and __fish_seen_subcommand_from ics" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c forest -n "__fish_forest_using_subcommand export; and __fish_seen_subcommand_from help" -f -a "ics" -d 'Write time tracking frames as events and due dates of tasks as to-dos, in iCalendar format'
+complete -c forest -n "__fish_forest_using_subcommand export; and __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c forest -n "__fish_forest_using_subcommand pause" -l at -d 'Pause date and time of recording' -r
+complete -c forest -n "__fish_forest_using_subcommand pause" -s o -l output -d 'Output format of listings, reports and status' -r -f -a "text\t'human-readable colored text'
+json\t'JSON document'
+csv\t'comma-separated values, one record per line'
+markdown\t'Markdown table'"
+complete -c forest -n "__fish_forest_using_subcommand pause" -l forest -d 'Name of the forest to use instead of the current one' -r
+complete -c forest -n "__fish_forest_using_subcommand pause" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c forest -n "__fish_forest_using_subcommand resume" -l at -d 'Resume date and time of recording' -r
+complete -c forest -n "__fish_forest_using_subcommand resume" -s o -l output -d 'Output format of listings, reports and status' -r -f -a "text\t'human-readable colored text'
+json\t'JSON document'
+csv\t'comma-separated values, one record per line'
+markdown\t'Markdown table'"
+complete -c forest -n "__fish_forest_using_subcommand resume" -l forest -d 'Name of the forest to use instead of the current one' -r
+complete -c forest -n "__fish_forest_using_subcommand resume" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c forest -n "__fish_forest_using_subcommand status" -s o -l output -d 'Output format of listings, reports and status' -r -f -a "text\t'human-readable colored text'
+json\t'JSON document'
+csv\t'comma-separated values, one record per line'
+markdown\t'Markdown table'"
+complete -c forest -n "__fish_forest_using_subcommand status" -l forest -d 'Name of the forest to use instead of the current one' -r
+complete -c forest -n "__fish_forest_using_subcommand status" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c forest -n "__fish_forest_using_subcommand report" -l tag -d 'Only count time spent on tasks with one of the given tag(s)' -r
+complete -c forest -n "__fish_forest_using_subcommand report" -s t -l tree -d 'Only report time spent on the given tree (defaults to the current tree with `--by task`)' -r
+complete -c forest -n "__fish_forest_using_subcommand report" -s b -l by -d 'Report time spent on each tree or on each task' -r -f -a "tree\t'report time spent on each tree'
+task\t'report time spent on each task of a tree'"
+complete -c forest -n "__fish_forest_using_subcommand report" -s d -l depth -d 'With `--by task`, collapse tasks deeper than the given depth into their ancestors' -r
+complete -c forest -n "__fish_forest_using_subcommand report" -l from -d 'Only count time spent from the given date and time' -r
+complete -c forest -n "__fish_forest_using_subcommand report" -l to -d 'Only count time spent until the given date and time' -r
+complete -c forest -n "__fish_forest_using_subcommand report" -s g -l grid -d 'Show a timesheet grid with one column per day, for the week or month containing `--from` (defaults to now)' -r -f -a "week\t'one column for each day of a week'
+month\t'one column for each day of a month'"
+complete -c forest -n "__fish_forest_using_subcommand report" -l round -d 'Round the time of each cell of the grid to a multiple of the given duration, eg. "15m"' -r
+complete -c forest -n "__fish_forest_using_subcommand report" -l round-mode -d 'How to round the time of each cell of the grid' -r -f -a "nearest\t'round to the nearest multiple'
+up\t'round up to the next multiple'
+down\t'round down to the previous multiple'"
+complete -c forest -n "__fish_forest_using_subcommand report" -s o -l output -d 'Output format of listings, reports and status' -r -f -a "text\t'human-readable colored text'
+json\t'JSON document'
+csv\t'comma-separated values, one record per line'
+markdown\t'Markdown table'"
+complete -c forest -n "__fish_forest_using_subcommand report" -l forest -d 'Name of the forest to use instead of the current one' -r
+complete -c forest -n "__fish_forest_using_subcommand report" -l today -d 'Only count time spent today'
+complete -c forest -n "__fish_forest_using_subcommand report" -l week -d 'Only count time spent this week'
+complete -c forest -n "__fish_forest_using_subcommand report" -l month -d 'Only count time spent this month'
+complete -c forest -n "__fish_forest_using_subcommand report" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c forest -n "__fish_forest_using_subcommand config; and not __fish_seen_subcommand_from week-start help" -s o -l output -d 'Output format of listings, reports and status' -r -f -a "text\t'human-readable colored text'
+json\t'JSON document'
+csv\t'comma-separated values, one record per line'
+markdown\t'Markdown table'"
+complete -c forest -n "__fish_forest_using_subcommand config; and not __fish_seen_subcommand_from week-start help" -l forest -d 'Name of the forest to use instead of the current one' -r
+complete -c forest -n "__fish_forest_using_subcommand config; and not __fish_seen_subcommand_from week-start help" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c forest -n "__fish_forest_using_subcommand config; and not __fish_seen_subcommand_from week-start help" -f -a "week-start" -d 'Get or set the first day of the week, used by `report --week`'
+complete -c forest -n "__fish_forest_using_subcommand config; and not __fish_seen_subcommand_from week-start help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c forest -n "__fish_forest_using_subcommand config; and __fish_seen_subcommand_from week-start" -s o -l output -d 'Output format of listings, reports and status' -r -f -a "text\t'human-readable colored text'
+json\t'JSON document'
+csv\t'comma-separated values, one record per line'
+markdown\t'Markdown table'"
+complete -c forest -n "__fish_forest_using_subcommand config; and __fish_seen_subcommand_from week-start" -l forest -d 'Name of the forest to use instead of the current one' -r
+complete -c forest -n "__fish_forest_using_subcommand config; and __fish_seen_subcommand_from week-start" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c forest -n "__fish_forest_using_subcommand config; and __fish_seen_subcommand_from help" -f -a "week-start" -d 'Get or set the first day of the week, used by `report --week`'
+complete -c forest -n "__fish_forest_using_subcommand config; and __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c forest -n "__fish_forest_using_subcommand agenda" -s d -l days -d 'Number of days after today to include in upcoming tasks' -r
+complete -c forest -n "__fish_forest_using_subcommand agenda" -s o -l output -d 'Output format of listings, reports and status' -r -f -a "text\t'human-readable colored text'
+json\t'JSON document'
+csv\t'comma-separated values, one record per line'
+markdown\t'Markdown table'"
+complete -c forest -n "__fish_forest_using_subcommand agenda" -l forest -d 'Name of the forest to use instead of the current one' -r
+complete -c forest -n "__fish_forest_using_subcommand agenda" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c forest -n "__fish_forest_using_subcommand help; and not __fish_seen_subcommand_from task tree note frame profile db switch start stop cancel restart import backup restore export pause resume status report config agenda help" -f -a "task" -d 'Perform operations on tasks'
+complete -c forest -n "__fish_forest_using_subcommand help; and not __fish_seen_subcommand_from task tree note frame profile db switch start stop cancel restart import backup restore export pause resume status report config agenda help" -f -a "tree" -d 'Perform operations on trees'
+complete -c forest -n "__fish_forest_using_subcommand help; and not __fish_seen_subcommand_from task tree note frame profile db switch start stop cancel restart import backup restore export pause resume status report config agenda help" -f -a "note" -d 'Perform operations on notes'
+complete -c forest -n "__fish_forest_using_subcommand help; and not __fish_seen_subcommand_from task tree note frame profile db switch start stop cancel restart import backup restore export pause resume status report config agenda help" -f -a "frame" -d 'Perform operations on time tracking frames'
+complete -c forest -n "__fish_forest_using_subcommand help; and not __fish_seen_subcommand_from task tree note frame profile db switch start stop cancel restart import backup restore export pause resume status report config agenda help" -f -a "profile" -d 'Manage separate forests, each with its own trees, frames and notes'
+complete -c forest -n "__fish_forest_using_subcommand help; and not __fish_seen_subcommand_from task tree note frame profile db switch start stop cancel restart import backup restore export pause resume status report config agenda help" -f -a "db" -d 'Manage the schema of the forest database'
+complete -c forest -n "__fish_forest_using_subcommand help; and not __fish_seen_subcommand_from task tree note frame profile db switch start stop cancel restart import backup restore export pause resume status report config agenda help" -f -a "switch" -d 'Switch to another tree'
+complete -c forest -n "__fish_forest_using_subcommand help; and not __fish_seen_subcommand_from task tree note frame profile db switch start stop cancel restart import backup restore export pause resume status report config agenda help" -f -a "start" -d 'Start recording time'
+complete -c forest -n "__fish_forest_using_subcommand help; and not __fish_seen_subcommand_from task tree note frame profile db switch start stop cancel restart import backup restore export pause resume status report config agenda help" -f -a "stop" -d 'Stop current time recording'
+complete -c forest -n "__fish_forest_using_subcommand help; and not __fish_seen_subcommand_from task tree note frame profile db switch start stop cancel restart import backup restore export pause resume status report config agenda help" -f -a "cancel" -d 'Discard current time recording'
+complete -c forest -n "__fish_forest_using_subcommand help; and not __fish_seen_subcommand_from task tree note frame profile db switch start stop cancel restart import backup restore export pause resume status report config agenda help" -f -a "restart" -d 'Start recording time on the most recently tracked tree or task'
+complete -c forest -n "__fish_forest_using_subcommand help; and not __fish_seen_subcommand_from task tree note frame profile db switch start stop cancel restart import backup restore export pause resume status report config agenda help" -f -a "import" -d 'Import time records from another time tracker'
+complete -c forest -n "__fish_forest_using_subcommand help; and not __fish_seen_subcommand_from task tree note frame profile db switch start stop cancel restart import backup restore export pause resume status report config agenda help" -f -a "backup" -d 'Save the whole forest, including notes, to an archive'
+complete -c forest -n "__fish_forest_using_subcommand help; and not __fish_seen_subcommand_from task tree note frame profile db switch start stop cancel restart import backup restore export pause resume status report config agenda help" -f -a "restore" -d 'Restore the forest from an archive created by `forest backup`'
+complete -c forest -n "__fish_forest_using_subcommand help; and not __fish_seen_subcommand_from task tree note frame profile db switch start stop cancel restart import backup restore export pause resume status report config agenda help" -f -a "export" -d 'Export the forest to files readable by other applications'
+complete -c forest -n "__fish_forest_using_subcommand help; and not __fish_seen_subcommand_from task tree note frame profile db switch start stop cancel restart import backup restore export pause resume status report config agenda help" -f -a "pause" -d 'Pause current time recording, without creating a note'
+complete -c forest -n "__fish_forest_using_subcommand help; and not __fish_seen_subcommand_from task tree note frame profile db switch start stop cancel restart import backup restore export pause resume status report config agenda help" -f -a "resume" -d 'Resume paused time recording on the same tree or task'
+complete -c forest -n "__fish_forest_using_subcommand help; and not __fish_seen_subcommand_from task tree note frame profile db switch start stop cancel restart import backup restore export pause resume status report config agenda help" -f -a "status" -d 'Show current time recording'
+complete -c forest -n "__fish_forest_using_subcommand help; and not __fish_seen_subcommand_from task tree note frame profile db switch start stop cancel restart import backup restore export pause resume status report config agenda help" -f -a "report" -d 'Reports time spent on each tree, or on each task of a tree'
+complete -c forest -n "__fish_forest_using_subcommand help; and not __fish_seen_subcommand_from task tree note frame profile db switch start stop cancel restart import backup restore export pause resume status report config agenda help" -f -a "config" -d 'Get or set configuration options'
+complete -c forest -n "__fish_forest_using_subcommand help; and not __fish_seen_subcommand_from task tree note frame profile db switch start stop cancel restart import backup restore export pause resume status report config agenda help" -f -a "agenda" -d 'Show overdue, today\'s and upcoming tasks across all trees'
+complete -c forest -n "__fish_forest_using_subcommand help; and not __fish_seen_subcommand_from task tree note frame profile db switch start stop cancel restart import backup restore export pause resume status report config agenda help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
 complete -c forest -n "__fish_forest_using_subcommand help; and __fish_seen_subcommand_from task" -f -a "list" -d 'List tasks in the current tree'
 complete -c forest -n "__fish_forest_using_subcommand help; and __fish_seen_subcommand_from task" -f -a "add" -d 'Add a new task to the current tree'
+complete -c forest -n "__fish_forest_using_subcommand help; and __fish_seen_subcommand_from task" -f -a "import" -d 'Import tasks from another task manager into the current tree'
 complete -c forest -n "__fish_forest_using_subcommand help; and __fish_seen_subcommand_from task" -f -a "remove" -d 'Remove a task from the current tree'
 complete -c forest -n "__fish_forest_using_subcommand help; and __fish_seen_subcommand_from task" -f -a "rename" -d 'Rename a task in the current tree'
 complete -c forest -n "__fish_forest_using_subcommand help; and __fish_seen_subcommand_from task" -f -a "show" -d 'Show description of a task in the current tree'
 complete -c forest -n "__fish_forest_using_subcommand help; and __fish_seen_subcommand_from task" -f -a "edit" -d 'Edit description of a task in the current tree'
 complete -c forest -n "__fish_forest_using_subcommand help; and __fish_seen_subcommand_from task" -f -a "priority" -d 'Set priority of a task in the current tree'
+complete -c forest -n "__fish_forest_using_subcommand help; and __fish_seen_subcommand_from task" -f -a "done" -d 'Mark a task of the current tree as done'
+complete -c forest -n "__fish_forest_using_subcommand help; and __fish_seen_subcommand_from task" -f -a "start" -d 'Mark a task of the current tree as in progress'
+complete -c forest -n "__fish_forest_using_subcommand help; and __fish_seen_subcommand_from task" -f -a "cancel" -d 'Mark a task of the current tree as cancelled'
+complete -c forest -n "__fish_forest_using_subcommand help; and __fish_seen_subcommand_from task" -f -a "reopen" -d 'Mark a done or cancelled task of the current tree as todo again'
+complete -c forest -n "__fish_forest_using_subcommand help; and __fish_seen_subcommand_from task" -f -a "move" -d 'Move a task of the current tree and its subtasks under another parent, possibly in another tree'
+complete -c forest -n "__fish_forest_using_subcommand help; and __fish_seen_subcommand_from task" -f -a "copy" -d 'Copy a task of the current tree and its subtasks under another parent, possibly in another tree'
+complete -c forest -n "__fish_forest_using_subcommand help; and __fish_seen_subcommand_from task" -f -a "depend" -d 'Make a task of the current tree depend on another task, possibly in another tree'
+complete -c forest -n "__fish_forest_using_subcommand help; and __fish_seen_subcommand_from task" -f -a "undepend" -d 'Remove a dependency of a task of the current tree'
+complete -c forest -n "__fish_forest_using_subcommand help; and __fish_seen_subcommand_from task" -f -a "recur" -d 'Set the recurrence rule of a task in the current tree'
+complete -c forest -n "__fish_forest_using_subcommand help; and __fish_seen_subcommand_from task" -f -a "due" -d 'Set due and scheduled dates of a task in the current tree'
+complete -c forest -n "__fish_forest_using_subcommand help; and __fish_seen_subcommand_from task" -f -a "tag" -d 'Add tags to a task in the current tree'
+complete -c forest -n "__fish_forest_using_subcommand help; and __fish_seen_subcommand_from task" -f -a "untag" -d 'Remove tags from a task in the current tree'
 complete -c forest -n "__fish_forest_using_subcommand help; and __fish_seen_subcommand_from tree" -f -a "list" -d 'List trees in the current forest'
 complete -c forest -n "__fish_forest_using_subcommand help; and __fish_seen_subcommand_from tree" -f -a "add" -d 'Add a new tree'
 complete -c forest -n "__fish_forest_using_subcommand help; and __fish_seen_subcommand_from tree" -f -a "remove" -d 'Remove a tree'
@@ -140,3 +698,18 @@ complete -c forest -n "__fish_forest_using_subcommand help; and __fish_seen_subc
 complete -c forest -n "__fish_forest_using_subcommand help; and __fish_seen_subcommand_from note" -f -a "remove" -d 'Remove a note'
 complete -c forest -n "__fish_forest_using_subcommand help; and __fish_seen_subcommand_from note" -f -a "show" -d 'Show content of a note'
 complete -c forest -n "__fish_forest_using_subcommand help; and __fish_seen_subcommand_from note" -f -a "edit" -d 'Edit a note'
+complete -c forest -n "__fish_forest_using_subcommand help; and __fish_seen_subcommand_from note" -f -a "tag" -d 'Add tags to a note'
+complete -c forest -n "__fish_forest_using_subcommand help; and __fish_seen_subcommand_from note" -f -a "untag" -d 'Remove tags from a note'
+complete -c forest -n "__fish_forest_using_subcommand help; and __fish_seen_subcommand_from frame" -f -a "list" -d 'List time tracking frames, most recent first'
+complete -c forest -n "__fish_forest_using_subcommand help; and __fish_seen_subcommand_from frame" -f -a "add" -d 'Add a time tracking frame'
+complete -c forest -n "__fish_forest_using_subcommand help; and __fish_seen_subcommand_from frame" -f -a "edit" -d 'Edit a time tracking frame'
+complete -c forest -n "__fish_forest_using_subcommand help; and __fish_seen_subcommand_from frame" -f -a "remove" -d 'Remove a time tracking frame'
+complete -c forest -n "__fish_forest_using_subcommand help; and __fish_seen_subcommand_from frame" -f -a "split" -d 'Split a time tracking frame in two at the given date and time'
+complete -c forest -n "__fish_forest_using_subcommand help; and __fish_seen_subcommand_from frame" -f -a "merge" -d 'Merge two time tracking frames of the same tree or task'
+complete -c forest -n "__fish_forest_using_subcommand help; and __fish_seen_subcommand_from profile" -f -a "list" -d 'List all forests'
+complete -c forest -n "__fish_forest_using_subcommand help; and __fish_seen_subcommand_from profile" -f -a "create" -d 'Create a new empty forest'
+complete -c forest -n "__fish_forest_using_subcommand help; and __fish_seen_subcommand_from profile" -f -a "use" -d 'Use another forest by default'
+complete -c forest -n "__fish_forest_using_subcommand help; and __fish_seen_subcommand_from db" -f -a "migrate" -d 'Apply pending migrations to the database schema'
+complete -c forest -n "__fish_forest_using_subcommand help; and __fish_seen_subcommand_from db" -f -a "status" -d 'Show the schema version of the database and pending migrations'
+complete -c forest -n "__fish_forest_using_subcommand help; and __fish_seen_subcommand_from export" -f -a "ics" -d 'Write time tracking frames as events and due dates of tasks as to-dos, in iCalendar format'
+complete -c forest -n "__fish_forest_using_subcommand help; and __fish_seen_subcommand_from config" -f -a "week-start" -d 'Get or set the first day of the week, used by `report --week`'
@@ -1,3 +1,7 @@
+use chrono::{
+    DateTime, Datelike, Days, LocalResult, Months, NaiveDate, NaiveDateTime, NaiveTime, TimeZone,
+};
+pub use chrono::{TimeDelta, Weekday};
 use nanoid::nanoid;
 use std::char;
 use std::fmt;
@@ -13,6 +17,106 @@ pub enum ListFormat {
     Long,
 }
 
+/// Possible output formats for listings and reports
+#[derive(clap::ValueEnum, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutputFormat {
+    /// human-readable colored text
+    #[default]
+    Text,
+
+    /// JSON document
+    Json,
+
+    /// comma-separated values, one record per line
+    Csv,
+
+    /// Markdown table
+    Markdown,
+}
+
+/// Possible groupings for time reports
+#[derive(clap::ValueEnum, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ReportGrouping {
+    /// report time spent on each tree
+    #[default]
+    Tree,
+
+    /// report time spent on each task of a tree
+    Task,
+}
+
+/// Possible periods covered by a timesheet grid
+#[derive(clap::ValueEnum, Clone, Copy, PartialEq, Eq)]
+pub enum GridPeriod {
+    /// one column for each day of a week
+    Week,
+
+    /// one column for each day of a month
+    Month,
+}
+
+/// Time trackers from which time records can be imported
+#[derive(clap::ValueEnum, Clone, Copy, PartialEq, Eq)]
+pub enum ImportSource {
+    /// Watson frames file
+    Watson,
+
+    /// JSON output of `timew export`
+    Timewarrior,
+
+    /// CSV export of detailed time entries from Toggl Track or Clockify
+    #[value(alias = "clockify-csv")]
+    TogglCsv,
+}
+
+/// Ways of restoring a backup of the forest
+#[derive(clap::ValueEnum, Clone, Copy, PartialEq, Eq)]
+pub enum RestoreMode {
+    /// Replace the whole forest with the backup
+    Replace,
+    /// Add the trees, tasks, frames and notes of the backup that are missing from the forest
+    Merge,
+}
+
+/// Task managers from which tasks can be imported
+#[derive(clap::ValueEnum, Clone, Copy, PartialEq, Eq)]
+pub enum TaskImportFormat {
+    /// todo.txt file, whose projects become parent tasks
+    Todotxt,
+    /// JSON output of `task export`, whose projects become parent tasks
+    Taskwarrior,
+    /// Markdown checklist, nested by headings and indentation
+    Markdown,
+}
+
+/// Possible ways to round durations
+#[derive(clap::ValueEnum, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RoundingMode {
+    /// round to the nearest multiple
+    #[default]
+    Nearest,
+
+    /// round up to the next multiple
+    Up,
+
+    /// round down to the previous multiple
+    Down,
+}
+
+impl RoundingMode {
+    /// Rounds a number of seconds to a multiple of `unit` seconds
+    pub fn round(&self, seconds: i64, unit: i64) -> i64 {
+        if unit <= 0 {
+            return seconds;
+        }
+        match self {
+            RoundingMode::Nearest => (seconds + unit / 2).div_euclid(unit) * unit,
+            RoundingMode::Up => (seconds + unit - 1).div_euclid(unit) * unit,
+            RoundingMode::Down => seconds.div_euclid(unit) * unit,
+        }
+    }
+}
+
 /// Unique Identifier
 #[derive(sqlx::Type, Clone, Default)]
 #[sqlx(transparent)]
@@ -63,6 +167,138 @@ impl TryFrom<String> for Uid {
     }
 }
 
+/// Completion state of a task
+#[derive(clap::ValueEnum, serde::Serialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum TaskStatus {
+    /// task not started yet
+    #[default]
+    Todo,
+
+    /// task being worked on
+    InProgress,
+
+    /// task completed
+    Done,
+
+    /// task abandoned
+    Cancelled,
+}
+
+impl TaskStatus {
+    /// Returns the string stored in database for this status
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TaskStatus::Todo => "todo",
+            TaskStatus::InProgress => "in-progress",
+            TaskStatus::Done => "done",
+            TaskStatus::Cancelled => "cancelled",
+        }
+    }
+
+    /// Returns true if the task still needs some work, ie. it is neither done nor cancelled
+    pub fn is_open(&self) -> bool {
+        matches!(self, TaskStatus::Todo | TaskStatus::InProgress)
+    }
+}
+
+impl fmt::Display for TaskStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl TryFrom<String> for TaskStatus {
+    type Error = &'static str;
+
+    /// Tries to construct a TaskStatus from its database representation
+    ///
+    /// # Errors
+    /// Returns an error if the provided String is not a known status
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "todo" => Ok(TaskStatus::Todo),
+            "in-progress" => Ok(TaskStatus::InProgress),
+            "done" => Ok(TaskStatus::Done),
+            "cancelled" => Ok(TaskStatus::Cancelled),
+            _ => Err("Provided string is not a valid task status"),
+        }
+    }
+}
+
+/// Frequency at which a recurring task repeats
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+/// Recurrence rule of a task.
+///
+/// Rules are stored as a subset of iCalendar RRULEs, eg. `FREQ=WEEKLY;INTERVAL=2`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Recurrence {
+    pub frequency: Frequency,
+
+    /// number of frequency units between two occurrences
+    pub interval: u32,
+}
+
+impl Recurrence {
+    /// Returns the date and time of the occurrence following the given one
+    ///
+    /// Monthly and yearly occurrences are clamped to the last day of shorter months.
+    pub fn next(&self, datetime: NaiveDateTime) -> Option<NaiveDateTime> {
+        match self.frequency {
+            Frequency::Daily => datetime.checked_add_days(Days::new(self.interval.into())),
+            Frequency::Weekly => datetime.checked_add_days(Days::new(7 * u64::from(self.interval))),
+            Frequency::Monthly => datetime.checked_add_months(Months::new(self.interval)),
+            Frequency::Yearly => datetime.checked_add_months(Months::new(12 * self.interval)),
+        }
+    }
+
+    /// Returns a human-friendly description of the rule, eg. "every 2 weeks"
+    pub fn describe(&self) -> String {
+        let unit = match self.frequency {
+            Frequency::Daily => "day",
+            Frequency::Weekly => "week",
+            Frequency::Monthly => "month",
+            Frequency::Yearly => "year",
+        };
+        if self.interval == 1 {
+            format!("every {unit}")
+        } else {
+            format!("every {} {unit}s", self.interval)
+        }
+    }
+}
+
+impl fmt::Display for Recurrence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let frequency = match self.frequency {
+            Frequency::Daily => "DAILY",
+            Frequency::Weekly => "WEEKLY",
+            Frequency::Monthly => "MONTHLY",
+            Frequency::Yearly => "YEARLY",
+        };
+        write!(f, "FREQ={frequency};INTERVAL={}", self.interval)
+    }
+}
+
+impl TryFrom<String> for Recurrence {
+    type Error = String;
+
+    /// Tries to construct a Recurrence from its database representation
+    ///
+    /// # Errors
+    /// Returns an error if the provided String is not a valid rule
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        recurrence_parser(&value)
+    }
+}
+
 /// Priority of tasks.
 /// High priority is expressed with low integers:
 /// * `10` is a higher priority than `11`
@@ -90,3 +326,516 @@ pub fn uid_parser(uid: &str) -> Result<String, String> {
         ))
     }
 }
+
+/// Parses a day of the week, eg. "monday" or "mon"
+pub fn weekday_parser(day: &str) -> Result<Weekday, String> {
+    day.parse()
+        .map_err(|_| format!("'{day}' is not a valid day of the week"))
+}
+
+/// Parses a duration made of hours, minutes and seconds, eg. "15m", "1h30m" or "45s"
+pub fn duration_parser(duration: &str) -> Result<TimeDelta, String> {
+    let error =
+        || format!("'{duration}' is not a valid duration, eg. \"15m\", \"1h30m\" or \"45s\"");
+
+    let mut seconds: i64 = 0;
+    let mut number = String::new();
+    for c in duration.trim().chars() {
+        if c.is_ascii_digit() {
+            number.push(c);
+            continue;
+        }
+        let unit = match c {
+            'h' => 3600,
+            'm' => 60,
+            's' => 1,
+            _ => return Err(error()),
+        };
+        let value: i64 = number.parse().map_err(|_| error())?;
+        seconds = value
+            .checked_mul(unit)
+            .and_then(|value| seconds.checked_add(value))
+            .ok_or_else(error)?;
+        number.clear();
+    }
+
+    // a unit is required after every number
+    if !number.is_empty() || seconds == 0 {
+        return Err(error());
+    }
+
+    Ok(TimeDelta::seconds(seconds))
+}
+
+/// Parses a tag name
+///
+/// Tags can only contain alphanumeric characters and `-`, `_`, `.`, `:` or `/`
+pub fn tag_parser(tag: &str) -> Result<String, String> {
+    if tag.is_empty() {
+        Err(String::from("Tags cannot be empty"))
+    } else if tag
+        .chars()
+        .all(|c| c.is_alphanumeric() || "-_.:/".contains(c))
+    {
+        Ok(tag.to_string())
+    } else {
+        Err(format!(
+            "'{tag}' is not a valid tag. Tags can only contain alphanumeric characters and '-', '_', '.', ':' or '/'"
+        ))
+    }
+}
+
+/// Parses the name of a forest, which is also the name of the directory storing it
+pub fn profile_name_parser(name: &str) -> Result<String, String> {
+    if name.is_empty() {
+        Err(String::from("Forest names cannot be empty"))
+    } else if !name.starts_with('.')
+        && name
+            .chars()
+            .all(|c| c.is_alphanumeric() || "-_.".contains(c))
+    {
+        Ok(name.to_string())
+    } else {
+        Err(format!(
+            "'{name}' is not a valid forest name. Forest names can only contain alphanumeric characters and '-', '_' or '.', and cannot start with '.'"
+        ))
+    }
+}
+
+/// Parses a recurrence rule
+///
+/// Accepted rules are:
+/// * `daily`, `weekly`, `monthly` and `yearly`
+/// * `every N days`, `every N weeks`, `every N months` and `every N years`
+/// * RRULEs with a `FREQ` of `DAILY`, `WEEKLY`, `MONTHLY` or `YEARLY` and an optional `INTERVAL`,
+///   eg. `FREQ=MONTHLY;INTERVAL=3`
+pub fn recurrence_parser(rule: &str) -> Result<Recurrence, String> {
+    let error = || {
+        format!(
+            "'{rule}' is not a valid recurrence. Use 'daily', 'weekly', 'monthly', 'yearly', 'every N days|weeks|months|years' or an RRULE such as 'FREQ=WEEKLY;INTERVAL=2'"
+        )
+    };
+    let parse_frequency = |frequency: &str| match frequency {
+        "daily" | "day" | "days" => Some(Frequency::Daily),
+        "weekly" | "week" | "weeks" => Some(Frequency::Weekly),
+        "monthly" | "month" | "months" => Some(Frequency::Monthly),
+        "yearly" | "year" | "years" => Some(Frequency::Yearly),
+        _ => None,
+    };
+
+    let normalized = rule.trim().to_lowercase();
+    let normalized = normalized.strip_prefix("rrule:").unwrap_or(&normalized);
+
+    let recurrence = if normalized.contains('=') {
+        // RRULE subset: FREQ and INTERVAL parts, in any order
+        let mut frequency = None;
+        let mut interval = 1;
+        for part in normalized.split(';').filter(|part| !part.is_empty()) {
+            match part.split_once('=') {
+                Some(("freq", value)) => frequency = parse_frequency(value),
+                Some(("interval", value)) => interval = value.parse().map_err(|_| error())?,
+                _ => return Err(error()),
+            }
+        }
+        Recurrence {
+            frequency: frequency.ok_or_else(error)?,
+            interval,
+        }
+    } else {
+        let words: Vec<&str> = normalized.split_whitespace().collect();
+        match words.as_slice() {
+            [frequency] => Recurrence {
+                frequency: parse_frequency(frequency).ok_or_else(error)?,
+                interval: 1,
+            },
+            ["every", frequency] => Recurrence {
+                frequency: parse_frequency(frequency).ok_or_else(error)?,
+                interval: 1,
+            },
+            ["every", interval, frequency] => Recurrence {
+                frequency: parse_frequency(frequency).ok_or_else(error)?,
+                interval: interval.parse().map_err(|_| error())?,
+            },
+            _ => return Err(error()),
+        }
+    };
+
+    if recurrence.interval == 0 {
+        return Err(error());
+    }
+
+    Ok(recurrence)
+}
+
+/// Parses a date and time given by the user, relative to `now` and in the timezone of `now`
+///
+/// Accepted forms are:
+/// * `now`
+/// * durations relative to now, eg. `-15m`, `2h ago`, `+1h` or `in 30m`
+/// * a time of the current day, eg. `9:00` or `17:30:15`
+/// * a day followed by an optional time, midnight by default. Days are `today`, `yesterday`,
+///   `tomorrow`, dates such as `2024-06-01`, or days of the week such as `monday` or `mon`, which
+///   refer to the latest such day (today included) unless prefixed with `next` or `last`
+/// * ISO 8601 dates and times, eg. `2024-06-01T09:30`, with an optional UTC offset, eg.
+///   `2024-06-01T09:30:00+02:00`
+pub fn parse_datetime<Tz: TimeZone>(
+    datetime: &str,
+    now: &DateTime<Tz>,
+) -> Result<DateTime<Tz>, String> {
+    let error = || {
+        format!(
+            "'{datetime}' is not a valid date and time, eg. \"2024-06-01 9:30\", \"17:30\", \"yesterday 17:30\", \"monday 9:00\", \"-15m\", \"2h ago\" or \"now\""
+        )
+    };
+
+    let trimmed = datetime.trim();
+    let normalized = trimmed.to_lowercase();
+
+    if normalized == "now" {
+        return Ok(now.clone());
+    }
+
+    // durations relative to now
+    if let Some(duration) = normalized
+        .strip_prefix('-')
+        .or_else(|| normalized.strip_suffix("ago"))
+    {
+        let delta = duration_parser(duration.trim()).map_err(|_| error())?;
+        return now.clone().checked_sub_signed(delta).ok_or_else(error);
+    }
+    if let Some(duration) = normalized
+        .strip_prefix('+')
+        .or_else(|| normalized.strip_prefix("in "))
+    {
+        let delta = duration_parser(duration.trim()).map_err(|_| error())?;
+        return now.clone().checked_add_signed(delta).ok_or_else(error);
+    }
+
+    // ISO 8601 with an offset
+    if let Ok(datetime) = DateTime::parse_from_rfc3339(trimmed) {
+        return Ok(datetime.with_timezone(&now.timezone()));
+    }
+    for format in [
+        "%Y-%m-%dT%H:%M%:z",
+        "%Y-%m-%d %H:%M%:z",
+        "%Y-%m-%d %H:%M:%S%:z",
+    ] {
+        if let Ok(datetime) = DateTime::parse_from_str(trimmed, format) {
+            return Ok(datetime.with_timezone(&now.timezone()));
+        }
+    }
+
+    // local date and time
+    let naive_datetime = ["%Y-%m-%dT%H:%M", "%Y-%m-%dT%H:%M:%S"]
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(trimmed, format).ok());
+    let naive_datetime = match naive_datetime {
+        Some(naive_datetime) => naive_datetime,
+        None => {
+            let parse_time = |time: &str| {
+                ["%H:%M", "%H:%M:%S"]
+                    .iter()
+                    .find_map(|format| NaiveTime::parse_from_str(time, format).ok())
+            };
+
+            // the time, if any, is the last word
+            let mut words: Vec<&str> = normalized.split_whitespace().collect();
+            let time = match words.last().and_then(|word| parse_time(word)) {
+                Some(time) => {
+                    words.pop();
+                    Some(time)
+                }
+                None => None,
+            };
+
+            let today = now.date_naive();
+            let days_since = |weekday: &str| -> Option<u32> {
+                let weekday: Weekday = weekday.parse().ok()?;
+                Some(
+                    (today.weekday().num_days_from_monday() + 7 - weekday.num_days_from_monday())
+                        % 7,
+                )
+            };
+            let date = match words.as_slice() {
+                [] if time.is_some() => Some(today),
+                ["today"] => Some(today),
+                ["yesterday"] => today.checked_sub_days(Days::new(1)),
+                ["tomorrow"] => today.checked_add_days(Days::new(1)),
+                ["last", weekday] => days_since(weekday).and_then(|days| {
+                    let days = if days == 0 { 7 } else { days };
+                    today.checked_sub_days(Days::new(days.into()))
+                }),
+                ["next", weekday] => days_since(weekday)
+                    .and_then(|days| today.checked_add_days(Days::new((7 - days).into()))),
+                [day] => match NaiveDate::parse_from_str(day, "%Y-%m-%d") {
+                    Ok(date) => Some(date),
+                    Err(_) => days_since(day)
+                        .and_then(|days| today.checked_sub_days(Days::new(days.into()))),
+                },
+                _ => None,
+            };
+
+            date.ok_or_else(error)?
+                .and_time(time.unwrap_or(NaiveTime::MIN))
+        }
+    };
+
+    resolve_local_datetime(
+        datetime,
+        now.timezone().from_local_datetime(&naive_datetime),
+    )
+}
+
+/// Returns the single date and time matching a local date and time given by the user
+///
+/// # Errors
+/// Returns an error if the local date and time is ambiguous, eg. when clocks are turned back at the
+/// end of daylight saving time, or if it does not exist, eg. when clocks are turned forward. The
+/// error suggests to use an explicit UTC offset in the first case.
+pub fn resolve_local_datetime<Tz: TimeZone>(
+    datetime: &str,
+    local_datetime: LocalResult<DateTime<Tz>>,
+) -> Result<DateTime<Tz>, String> {
+    match local_datetime {
+        LocalResult::Single(local_datetime) => Ok(local_datetime),
+        LocalResult::Ambiguous(earliest, latest) => Err(format!(
+            "'{datetime}' is ambiguous in the local timezone: it could be {} or {}. Please add the UTC offset, eg. \"{}\"",
+            earliest.fixed_offset().format("%Y-%m-%d %H:%M%:z"),
+            latest.fixed_offset().format("%Y-%m-%d %H:%M%:z"),
+            earliest.fixed_offset().format("%Y-%m-%d %H:%M%:z"),
+        )),
+        LocalResult::None => Err(format!(
+            "'{datetime}' does not exist in the local timezone, clocks were turned forward at that time"
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::FixedOffset;
+
+    /// Wednesday 2024-06-12 at 14:30 in UTC+02:00
+    fn now() -> DateTime<FixedOffset> {
+        DateTime::parse_from_rfc3339("2024-06-12T14:30:00+02:00").unwrap()
+    }
+
+    fn parse(datetime: &str) -> String {
+        parse_datetime(datetime, &now()).unwrap().to_rfc3339()
+    }
+
+    #[test]
+    fn parses_now() {
+        assert_eq!(parse("now"), "2024-06-12T14:30:00+02:00");
+        assert_eq!(parse(" NOW "), "2024-06-12T14:30:00+02:00");
+    }
+
+    #[test]
+    fn parses_relative_durations() {
+        assert_eq!(parse("-15m"), "2024-06-12T14:15:00+02:00");
+        assert_eq!(parse("2h ago"), "2024-06-12T12:30:00+02:00");
+        assert_eq!(parse("1h30m ago"), "2024-06-12T13:00:00+02:00");
+        assert_eq!(parse("+45s"), "2024-06-12T14:30:45+02:00");
+        assert_eq!(parse("in 30m"), "2024-06-12T15:00:00+02:00");
+    }
+
+    #[test]
+    fn parses_times_of_today() {
+        assert_eq!(parse("9:00"), "2024-06-12T09:00:00+02:00");
+        assert_eq!(parse("17:30"), "2024-06-12T17:30:00+02:00");
+        assert_eq!(parse("08:05:30"), "2024-06-12T08:05:30+02:00");
+
+        // the day is the local day, even when it differs from the UTC day
+        let after_midnight = DateTime::parse_from_rfc3339("2024-06-12T00:30:00+02:00").unwrap();
+        assert_eq!(
+            parse_datetime("0:15", &after_midnight)
+                .unwrap()
+                .to_rfc3339(),
+            "2024-06-12T00:15:00+02:00"
+        );
+    }
+
+    #[test]
+    fn parses_named_days() {
+        assert_eq!(parse("today"), "2024-06-12T00:00:00+02:00");
+        assert_eq!(parse("yesterday 17:30"), "2024-06-11T17:30:00+02:00");
+        assert_eq!(parse("Tomorrow 8:00"), "2024-06-13T08:00:00+02:00");
+    }
+
+    #[test]
+    fn parses_days_of_the_week() {
+        assert_eq!(parse("monday 9:00"), "2024-06-10T09:00:00+02:00");
+        assert_eq!(parse("wed 9:00"), "2024-06-12T09:00:00+02:00");
+        assert_eq!(parse("thursday"), "2024-06-06T00:00:00+02:00");
+        assert_eq!(parse("last wednesday 10:00"), "2024-06-05T10:00:00+02:00");
+        assert_eq!(parse("next monday"), "2024-06-17T00:00:00+02:00");
+        assert_eq!(parse("next wed"), "2024-06-19T00:00:00+02:00");
+    }
+
+    #[test]
+    fn parses_dates() {
+        assert_eq!(parse("2024-06-01"), "2024-06-01T00:00:00+02:00");
+        assert_eq!(parse("2024-06-01 9:30"), "2024-06-01T09:30:00+02:00");
+        assert_eq!(parse("2024-06-01T09:30"), "2024-06-01T09:30:00+02:00");
+    }
+
+    #[test]
+    fn parses_iso_8601_with_offset() {
+        assert_eq!(
+            parse("2024-06-01T09:30:00+00:00"),
+            "2024-06-01T11:30:00+02:00"
+        );
+        assert_eq!(parse("2024-06-01T09:30:00Z"), "2024-06-01T11:30:00+02:00");
+        assert_eq!(parse("2024-06-01T09:30-05:00"), "2024-06-01T16:30:00+02:00");
+    }
+
+    #[test]
+    fn rejects_ambiguous_and_nonexistent_local_times() {
+        let local = NaiveDate::from_ymd_opt(2024, 10, 27)
+            .unwrap()
+            .and_hms_opt(2, 30, 0)
+            .unwrap();
+        let at_offset = |hours| {
+            FixedOffset::east_opt(hours * 3600)
+                .unwrap()
+                .from_local_datetime(&local)
+                .unwrap()
+        };
+        let (earliest, latest) = (at_offset(2), at_offset(1));
+
+        let error =
+            resolve_local_datetime("2024-10-27 2:30", LocalResult::Ambiguous(earliest, latest))
+                .unwrap_err();
+        assert!(error.contains("2024-10-27 02:30+02:00"), "{error}");
+        assert!(error.contains("2024-10-27 02:30+01:00"), "{error}");
+
+        assert!(
+            resolve_local_datetime::<FixedOffset>("2024-03-31 2:30", LocalResult::None).is_err()
+        );
+        assert_eq!(
+            resolve_local_datetime("2024-10-27 2:30", LocalResult::Single(earliest)),
+            Ok(earliest)
+        );
+
+        // an explicit offset resolves the ambiguity
+        assert_eq!(parse("2024-10-27 02:30+01:00"), "2024-10-27T03:30:00+02:00");
+    }
+
+    #[test]
+    fn rejects_invalid_input() {
+        for input in [
+            "",
+            "soon",
+            "-15",
+            "2h",
+            "25:00",
+            "monday 9",
+            "last 9:00",
+            "2024-13-01",
+        ] {
+            assert!(parse_datetime(input, &now()).is_err(), "{input}");
+        }
+    }
+
+    #[test]
+    fn rounds_to_multiples_of_the_unit() {
+        // 7m 29s, 7m 30s and 7m 31s to multiples of 15 minutes
+        let samples = [449, 450, 451];
+        let round = |mode: RoundingMode| samples.map(|seconds| mode.round(seconds, 900));
+
+        assert_eq!(round(RoundingMode::Nearest), [0, 900, 900]);
+        assert_eq!(round(RoundingMode::Up), [900, 900, 900]);
+        assert_eq!(round(RoundingMode::Down), [0, 0, 0]);
+
+        // exact multiples are kept whatever the mode
+        for mode in [RoundingMode::Nearest, RoundingMode::Up, RoundingMode::Down] {
+            assert_eq!(mode.round(0, 900), 0);
+            assert_eq!(mode.round(1800, 900), 1800);
+        }
+    }
+
+    #[test]
+    fn keeps_seconds_without_a_rounding_unit() {
+        for mode in [RoundingMode::Nearest, RoundingMode::Up, RoundingMode::Down] {
+            assert_eq!(mode.round(451, 0), 451);
+            assert_eq!(mode.round(451, -900), 451);
+        }
+    }
+
+    /// Returns the given date at 9:00
+    fn at_nine(year: i32, month: u32, day: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(year, month, day)
+            .unwrap()
+            .and_hms_opt(9, 0, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn computes_next_occurrences() {
+        let every = |frequency, interval| Recurrence {
+            frequency,
+            interval,
+        };
+
+        assert_eq!(
+            every(Frequency::Daily, 3).next(at_nine(2024, 2, 27)),
+            Some(at_nine(2024, 3, 1))
+        );
+        assert_eq!(
+            every(Frequency::Weekly, 2).next(at_nine(2024, 6, 12)),
+            Some(at_nine(2024, 6, 26))
+        );
+        assert_eq!(
+            every(Frequency::Yearly, 1).next(at_nine(2024, 6, 12)),
+            Some(at_nine(2025, 6, 12))
+        );
+
+        // occurrences are clamped to the last day of shorter months
+        assert_eq!(
+            every(Frequency::Monthly, 1).next(at_nine(2024, 1, 31)),
+            Some(at_nine(2024, 2, 29))
+        );
+        assert_eq!(
+            every(Frequency::Yearly, 1).next(at_nine(2024, 2, 29)),
+            Some(at_nine(2025, 2, 28))
+        );
+    }
+
+    #[test]
+    fn parses_recurrences() {
+        let parse = |rule| recurrence_parser(rule).map(|recurrence| recurrence.to_string());
+
+        assert_eq!(parse("weekly"), Ok("FREQ=WEEKLY;INTERVAL=1".into()));
+        assert_eq!(parse("every month"), Ok("FREQ=MONTHLY;INTERVAL=1".into()));
+        assert_eq!(parse("every 2 weeks"), Ok("FREQ=WEEKLY;INTERVAL=2".into()));
+        assert_eq!(parse("Every 3 Days"), Ok("FREQ=DAILY;INTERVAL=3".into()));
+        assert_eq!(
+            parse("FREQ=WEEKLY;INTERVAL=2"),
+            Ok("FREQ=WEEKLY;INTERVAL=2".into())
+        );
+
+        // RRULE parts may come in any order, and the interval defaults to 1
+        assert_eq!(
+            parse("RRULE:INTERVAL=3;FREQ=MONTHLY;"),
+            Ok("FREQ=MONTHLY;INTERVAL=3".into())
+        );
+        assert_eq!(parse("FREQ=YEARLY"), Ok("FREQ=YEARLY;INTERVAL=1".into()));
+    }
+
+    #[test]
+    fn rejects_invalid_recurrences() {
+        for rule in [
+            "",
+            "every 0 days",
+            "FREQ=DAILY;INTERVAL=0",
+            "every -1 weeks",
+            "every two weeks",
+            "fortnightly",
+            "FREQ=HOURLY",
+            "INTERVAL=2",
+            "FREQ=WEEKLY;BYDAY=MO",
+        ] {
+            assert!(recurrence_parser(rule).is_err(), "{rule}");
+        }
+    }
+}
@@ -1,3 +1,5 @@
+-- initial schema, which databases created before migrations were introduced already have
+
 -- tree table stores trees
 CREATE TABLE IF NOT EXISTS tree (
     name TEXT NOT NULL PRIMARY KEY,
@@ -0,0 +1,2 @@
+-- status of tasks: 'todo', 'in-progress', 'done' or 'cancelled'
+ALTER TABLE task ADD COLUMN status TEXT NOT NULL DEFAULT 'todo';
@@ -0,0 +1,3 @@
+-- date and time at which tasks are due and planned to be worked on
+ALTER TABLE task ADD COLUMN due INTEGER DEFAULT NULL;
+ALTER TABLE task ADD COLUMN scheduled INTEGER DEFAULT NULL;
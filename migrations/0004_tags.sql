@@ -0,0 +1,22 @@
+-- tag table stores labels that can be attached to tasks and notes
+CREATE TABLE tag (
+    name TEXT NOT NULL PRIMARY KEY
+);
+
+-- task_tag table links tasks to their tags
+CREATE TABLE task_tag (
+    task_id TEXT NOT NULL,
+    tag_name TEXT NOT NULL,
+    PRIMARY KEY (task_id, tag_name),
+    FOREIGN KEY (task_id) REFERENCES task(id) ON DELETE CASCADE,
+    FOREIGN KEY (tag_name) REFERENCES tag(name) ON UPDATE CASCADE ON DELETE CASCADE
+);
+
+-- note_tag table links notes to their tags
+CREATE TABLE note_tag (
+    note_id TEXT NOT NULL,
+    tag_name TEXT NOT NULL,
+    PRIMARY KEY (note_id, tag_name),
+    FOREIGN KEY (note_id) REFERENCES note(id) ON DELETE CASCADE,
+    FOREIGN KEY (tag_name) REFERENCES tag(name) ON UPDATE CASCADE ON DELETE CASCADE
+);
@@ -0,0 +1,8 @@
+-- dependency table stores which tasks must be done before a task can start
+CREATE TABLE dependency (
+    task_id TEXT NOT NULL,
+    blocker_id TEXT NOT NULL,
+    PRIMARY KEY (task_id, blocker_id),
+    FOREIGN KEY (task_id) REFERENCES task(id) ON DELETE CASCADE,
+    FOREIGN KEY (blocker_id) REFERENCES task(id) ON DELETE CASCADE
+);
@@ -0,0 +1,2 @@
+-- recurrence rule of tasks, eg. "FREQ=WEEKLY;INTERVAL=1"
+ALTER TABLE task ADD COLUMN recurrence TEXT DEFAULT NULL;
@@ -0,0 +1,2 @@
+-- first day of weeks in reports
+ALTER TABLE state ADD COLUMN week_start TEXT NOT NULL DEFAULT 'Mon';
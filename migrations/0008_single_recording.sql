@@ -0,0 +1,11 @@
+-- stop extra recordings of databases created before a single recording was enforced
+UPDATE frame
+SET "end" = (SELECT MAX("start") FROM frame WHERE "end" IS NULL)
+WHERE
+    "end" IS NULL AND
+    "start" < (SELECT MAX("start") FROM frame WHERE "end" IS NULL);
+
+-- at most one frame can be recording at any time
+CREATE UNIQUE INDEX frame_single_recording
+ON frame(("end" IS NULL))
+WHERE "end" IS NULL;
@@ -0,0 +1,5 @@
+-- frame which started the session this frame was resumed from, if any
+ALTER TABLE frame ADD COLUMN session_id TEXT;
+
+-- whether the frame was ended by a pause that was not resumed yet
+ALTER TABLE frame ADD COLUMN paused INTEGER NOT NULL DEFAULT 0;
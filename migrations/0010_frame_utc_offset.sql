@@ -0,0 +1,2 @@
+-- offset from UTC in seconds of the local time at which the frame started
+ALTER TABLE frame ADD COLUMN utc_offset INTEGER;
@@ -0,0 +1,4 @@
+-- identifier of the record the frame was imported from, eg. "watson:<id>"
+ALTER TABLE frame ADD COLUMN import_id TEXT;
+
+CREATE UNIQUE INDEX frame_import_id ON frame(import_id);
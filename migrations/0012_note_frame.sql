@@ -0,0 +1,2 @@
+-- frame whose work is described by a time tracking note
+ALTER TABLE note ADD COLUMN frame_id TEXT REFERENCES frame(id) ON DELETE SET NULL;
@@ -0,0 +1,18 @@
+-- frames in the local time at which they were recorded, as if it was UTC, so that they are split
+-- into the days of the timezone they were recorded in: shift is the offset from UTC of this local
+-- time in milliseconds, frames recorded before offsets were stored being in the current timezone
+CREATE VIEW zoned_frame AS
+SELECT
+    z.id, z.task_id, z.session_id, z.shift,
+    z."start" + z.shift AS local_start,
+    z."end" + z.shift AS local_end
+FROM (
+    SELECT
+        f.*,
+        1000 * COALESCE(
+            f.utc_offset,
+            CAST(strftime('%s', f."start" / 1000, 'unixepoch', 'localtime') AS INTEGER)
+                - f."start" / 1000
+        ) AS shift
+    FROM frame f
+) z;
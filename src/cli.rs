@@ -7,6 +7,16 @@ use clap::{value_parser, Parser, Subcommand};
 pub struct Cli {
     #[command(subcommand)]
     pub command: Commands,
+
+    /// Output format of listings, reports and status
+    #[arg(short = 'o', long = "output", value_name = "FORMAT", global = true)]
+    #[arg(default_value = "text")]
+    pub output: forest_types::OutputFormat,
+
+    /// Name of the forest to use instead of the current one
+    #[arg(long = "forest", value_name = "NAME", global = true)]
+    #[arg(value_parser = forest_types::profile_name_parser)]
+    pub forest: Option<String>,
 }
 
 #[derive(Subcommand)]
@@ -29,6 +39,24 @@ pub enum Commands {
         command: NoteCommands,
     },
 
+    /// Perform operations on time tracking frames
+    Frame {
+        #[command(subcommand)]
+        command: FrameCommands,
+    },
+
+    /// Manage separate forests, each with its own trees, frames and notes
+    Profile {
+        #[command(subcommand)]
+        command: ProfileCommands,
+    },
+
+    /// Manage the schema of the forest database
+    Db {
+        #[command(subcommand)]
+        command: DbCommands,
+    },
+
     /// Switch to another tree
     Switch {
         /// Name of the tree to switch to
@@ -39,21 +67,34 @@ pub enum Commands {
 
     /// Start recording time
     Start {
-        /// Name of tree for which to record time
-        #[arg(value_name = "TREE")]
-        #[arg(value_parser = forest_types::tree_name_parser)]
-        tree_name: Option<String>,
+        /// Name of tree or uid of task for which to record time
+        #[arg(value_name = "TREE|UID")]
+        target: Option<String>,
+
+        /// Uid of task for which to record time
+        #[arg(
+            short = 't',
+            long = "task",
+            value_name = "UID",
+            conflicts_with = "target"
+        )]
+        #[arg(value_parser = forest_types::uid_parser)]
+        task: Option<String>,
 
         /// Start date and time of recording
-        #[arg(value_name = "DATETIME")]
+        #[arg(value_name = "DATETIME", allow_hyphen_values = true)]
         #[arg(long = "at", value_name = "FORMAT")]
         at: Option<String>,
+
+        /// Trim, split or remove overlapping frames instead of failing
+        #[arg(long = "adjust")]
+        adjust: bool,
     },
 
     /// Stop current time recording
     Stop {
         /// Stop date and time of recording
-        #[arg(value_name = "DATETIME")]
+        #[arg(value_name = "DATETIME", allow_hyphen_values = true)]
         #[arg(long = "at", value_name = "FORMAT")]
         at: Option<String>,
 
@@ -62,18 +103,172 @@ pub enum Commands {
         no_note: bool,
     },
 
+    /// Discard current time recording
+    Cancel,
+
+    /// Start recording time on the most recently tracked tree or task
+    Restart {
+        /// Start date and time of recording
+        #[arg(long = "at", value_name = "DATETIME", allow_hyphen_values = true)]
+        at: Option<String>,
+
+        /// Trim, split or remove overlapping frames instead of failing
+        #[arg(long = "adjust")]
+        adjust: bool,
+    },
+
+    /// Import time records from another time tracker
+    Import {
+        /// Time tracker the records come from
+        #[arg(short = 'f', long = "from", value_name = "SOURCE")]
+        from: forest_types::ImportSource,
+
+        /// Watson frames file, output of `timew export`, or CSV export of Toggl or Clockify
+        #[arg(value_name = "FILE")]
+        file: std::path::PathBuf,
+
+        /// Show the records that would be imported without importing them
+        #[arg(short = 'n', long = "dry-run")]
+        dry_run: bool,
+    },
+
+    /// Save the whole forest, including notes, to an archive
+    Backup {
+        /// Archive to create
+        #[arg(value_name = "FILE")]
+        file: std::path::PathBuf,
+    },
+
+    /// Restore the forest from an archive created by `forest backup`
+    Restore {
+        /// Archive to restore
+        #[arg(value_name = "FILE")]
+        file: std::path::PathBuf,
+
+        /// Whether to replace the forest with the archive, or to add what is missing from it
+        #[arg(
+            short = 'm',
+            long = "mode",
+            value_name = "MODE",
+            default_value = "merge"
+        )]
+        mode: forest_types::RestoreMode,
+    },
+
+    /// Export the forest to files readable by other applications
+    Export {
+        #[command(subcommand)]
+        command: ExportCommands,
+    },
+
+    /// Pause current time recording, without creating a note
+    Pause {
+        /// Pause date and time of recording
+        #[arg(long = "at", value_name = "DATETIME", allow_hyphen_values = true)]
+        at: Option<String>,
+    },
+
+    /// Resume paused time recording on the same tree or task
+    Resume {
+        /// Resume date and time of recording
+        #[arg(long = "at", value_name = "DATETIME", allow_hyphen_values = true)]
+        at: Option<String>,
+    },
+
     /// Show current time recording
     Status,
 
-    /// Reports time spent on each tree
-    Report,
+    /// Reports time spent on each tree, or on each task of a tree
+    Report {
+        /// Only count time spent on tasks with one of the given tag(s)
+        #[arg(long = "tag", value_name = "TAG")]
+        #[arg(value_parser = forest_types::tag_parser)]
+        tags: Vec<String>,
+
+        /// Only report time spent on the given tree (defaults to the current tree with `--by task`)
+        #[arg(short = 't', long = "tree", value_name = "TREE")]
+        #[arg(value_parser = forest_types::tree_name_parser)]
+        tree_name: Option<String>,
+
+        /// Report time spent on each tree or on each task
+        #[arg(
+            short = 'b',
+            long = "by",
+            value_name = "GROUPING",
+            default_value = "tree"
+        )]
+        by: forest_types::ReportGrouping,
+
+        /// With `--by task`, collapse tasks deeper than the given depth into their ancestors
+        #[arg(short = 'd', long = "depth", value_name = "N", requires = "by")]
+        #[arg(value_parser = value_parser!(u32).range(1..))]
+        depth: Option<u32>,
+
+        /// Only count time spent from the given date and time
+        #[arg(long = "from", value_name = "DATETIME", allow_hyphen_values = true)]
+        from: Option<String>,
+
+        /// Only count time spent until the given date and time
+        #[arg(long = "to", value_name = "DATETIME", allow_hyphen_values = true)]
+        to: Option<String>,
+
+        /// Only count time spent today
+        #[arg(long = "today", conflicts_with_all = ["from", "to", "week", "month"])]
+        today: bool,
+
+        /// Only count time spent this week
+        #[arg(long = "week", conflicts_with_all = ["from", "to", "month"])]
+        week: bool,
+
+        /// Only count time spent this month
+        #[arg(long = "month", conflicts_with_all = ["from", "to"])]
+        month: bool,
+
+        /// Show a timesheet grid with one column per day, for the week or month containing
+        /// `--from` (defaults to now)
+        #[arg(short = 'g', long = "grid", value_name = "PERIOD")]
+        #[arg(conflicts_with_all = ["to", "today", "week", "month"])]
+        grid: Option<forest_types::GridPeriod>,
+
+        /// Round the time of each cell of the grid to a multiple of the given duration, eg. "15m"
+        #[arg(long = "round", value_name = "DURATION", requires = "grid")]
+        #[arg(value_parser = forest_types::duration_parser)]
+        round: Option<forest_types::TimeDelta>,
+
+        /// How to round the time of each cell of the grid
+        #[arg(long = "round-mode", value_name = "MODE", requires = "round")]
+        #[arg(default_value = "nearest")]
+        round_mode: forest_types::RoundingMode,
+    },
+
+    /// Get or set configuration options
+    Config {
+        #[command(subcommand)]
+        command: ConfigCommands,
+    },
+
+    /// Show overdue, today's and upcoming tasks across all trees
+    Agenda {
+        /// Number of days after today to include in upcoming tasks
+        #[arg(short = 'd', long = "days", value_name = "DAYS", default_value_t = 7)]
+        days: u32,
+    },
 }
 
 #[derive(Subcommand)]
 pub enum TaskCommands {
     /// List tasks in the current tree
     #[clap(alias = "ls")]
-    List,
+    List {
+        /// Only display tasks with the given status(es)
+        #[arg(short = 's', long = "status", value_name = "STATUS")]
+        status: Vec<forest_types::TaskStatus>,
+
+        /// Only display tasks with one of the given tag(s)
+        #[arg(long = "tag", value_name = "TAG")]
+        #[arg(value_parser = forest_types::tag_parser)]
+        tags: Vec<String>,
+    },
     /// Add a new task to the current tree
     Add {
         /// Name of the new task
@@ -93,6 +288,39 @@ pub enum TaskCommands {
         /// Opens an editor to write the description of the new task
         #[arg(short = 'e', long = "edit")]
         edit: bool,
+
+        /// Date and time at which the new task is due
+        #[arg(long = "due", value_name = "DATETIME", allow_hyphen_values = true)]
+        due: Option<String>,
+
+        /// Date and time at which the new task is planned to be worked on
+        #[arg(
+            long = "scheduled",
+            value_name = "DATETIME",
+            allow_hyphen_values = true
+        )]
+        scheduled: Option<String>,
+
+        /// Recurrence rule of the new task, eg. "weekly", "every 3 days" or "FREQ=MONTHLY;INTERVAL=2"
+        #[arg(long = "every", value_name = "RULE")]
+        #[arg(value_parser = forest_types::recurrence_parser)]
+        recurrence: Option<forest_types::Recurrence>,
+    },
+
+    /// Import tasks from another task manager into the current tree
+    Import {
+        /// Format of the file to import
+        #[arg(short = 'f', long = "format", value_name = "FORMAT")]
+        format: forest_types::TaskImportFormat,
+
+        /// todo.txt file, output of `task export`, or Markdown checklist
+        #[arg(value_name = "FILE")]
+        file: std::path::PathBuf,
+
+        /// Uid of the task under which to import tasks. By default, imports them under the tree root.
+        #[arg(short = 'p', long = "parent", value_name = "UID")]
+        #[arg(value_parser = forest_types::uid_parser)]
+        parent_uid: Option<String>,
     },
 
     /// Remove a task from the current tree
@@ -145,6 +373,193 @@ pub enum TaskCommands {
         #[arg(value_parser = value_parser!(forest_types::Priority))]
         priority: forest_types::Priority,
     },
+
+    /// Mark a task of the current tree as done
+    Done {
+        /// Uid of the task
+        #[arg(value_name = "UID")]
+        #[arg(value_parser = forest_types::uid_parser)]
+        uid: String,
+
+        /// Also mark all open subtasks as done
+        #[arg(short = 'r', long = "recursive")]
+        recursive: bool,
+    },
+
+    /// Mark a task of the current tree as in progress
+    Start {
+        /// Uid of the task
+        #[arg(value_name = "UID")]
+        #[arg(value_parser = forest_types::uid_parser)]
+        uid: String,
+    },
+
+    /// Mark a task of the current tree as cancelled
+    Cancel {
+        /// Uid of the task
+        #[arg(value_name = "UID")]
+        #[arg(value_parser = forest_types::uid_parser)]
+        uid: String,
+
+        /// Also mark all open subtasks as cancelled
+        #[arg(short = 'r', long = "recursive")]
+        recursive: bool,
+    },
+
+    /// Mark a done or cancelled task of the current tree as todo again
+    Reopen {
+        /// Uid of the task
+        #[arg(value_name = "UID")]
+        #[arg(value_parser = forest_types::uid_parser)]
+        uid: String,
+    },
+
+    /// Move a task of the current tree and its subtasks under another parent, possibly in another tree
+    #[clap(alias = "mv")]
+    Move {
+        /// Uid of the task
+        #[arg(value_name = "UID")]
+        #[arg(value_parser = forest_types::uid_parser)]
+        uid: String,
+
+        /// Name of the destination tree. By default, the task is moved within the current tree.
+        #[arg(short = 't', long = "tree", value_name = "TREE")]
+        #[arg(value_parser = forest_types::tree_name_parser)]
+        tree_name: Option<String>,
+
+        /// Uid of the new parent task in the destination tree. By default, the task is moved to
+        /// the tree root.
+        #[arg(short = 'p', long = "parent", value_name = "PARENT_UID")]
+        #[arg(value_parser = forest_types::uid_parser)]
+        parent_uid: Option<String>,
+
+        /// Position of the task among the children of its new parent. By default, the task is
+        /// moved after the last child.
+        #[arg(short = 'n', long = "position", value_name = "POSITION")]
+        #[arg(value_parser = value_parser!(forest_types::Priority).range(1..))]
+        position: Option<forest_types::Priority>,
+    },
+
+    /// Copy a task of the current tree and its subtasks under another parent, possibly in another tree
+    #[clap(alias = "cp")]
+    Copy {
+        /// Uid of the task
+        #[arg(value_name = "UID")]
+        #[arg(value_parser = forest_types::uid_parser)]
+        uid: String,
+
+        /// Name of the destination tree. By default, the task is copied within the current tree.
+        #[arg(short = 't', long = "tree", value_name = "TREE")]
+        #[arg(value_parser = forest_types::tree_name_parser)]
+        tree_name: Option<String>,
+
+        /// Uid of the new parent task in the destination tree. By default, the task is copied to
+        /// the tree root.
+        #[arg(short = 'p', long = "parent", value_name = "PARENT_UID")]
+        #[arg(value_parser = forest_types::uid_parser)]
+        parent_uid: Option<String>,
+
+        /// Position of the task among the children of its new parent. By default, the task is
+        /// copied after the last child.
+        #[arg(short = 'n', long = "position", value_name = "POSITION")]
+        #[arg(value_parser = value_parser!(forest_types::Priority).range(1..))]
+        position: Option<forest_types::Priority>,
+    },
+
+    /// Make a task of the current tree depend on another task, possibly in another tree
+    Depend {
+        /// Uid of the task
+        #[arg(value_name = "UID")]
+        #[arg(value_parser = forest_types::uid_parser)]
+        uid: String,
+
+        /// Uid of the task that must be done first
+        #[arg(long = "on", value_name = "OTHER_UID")]
+        #[arg(value_parser = forest_types::uid_parser)]
+        blocker_uid: String,
+    },
+
+    /// Remove a dependency of a task of the current tree
+    Undepend {
+        /// Uid of the task
+        #[arg(value_name = "UID")]
+        #[arg(value_parser = forest_types::uid_parser)]
+        uid: String,
+
+        /// Uid of the task it should no longer depend on
+        #[arg(long = "on", value_name = "OTHER_UID")]
+        #[arg(value_parser = forest_types::uid_parser)]
+        blocker_uid: String,
+    },
+
+    /// Set the recurrence rule of a task in the current tree
+    Recur {
+        /// Uid of the task
+        #[arg(value_name = "UID")]
+        #[arg(value_parser = forest_types::uid_parser)]
+        uid: String,
+
+        /// Recurrence rule, eg. "weekly", "every 3 days" or "FREQ=MONTHLY;INTERVAL=2"
+        #[arg(value_name = "RULE")]
+        #[arg(value_parser = forest_types::recurrence_parser)]
+        #[arg(required_unless_present = "clear")]
+        recurrence: Option<forest_types::Recurrence>,
+
+        /// Stop repeating the task
+        #[arg(short = 'c', long = "clear", conflicts_with = "recurrence")]
+        clear: bool,
+    },
+
+    /// Set due and scheduled dates of a task in the current tree
+    Due {
+        /// Uid of the task
+        #[arg(value_name = "UID")]
+        #[arg(value_parser = forest_types::uid_parser)]
+        uid: String,
+
+        /// Date and time at which the task is due
+        #[arg(value_name = "DATETIME", allow_hyphen_values = true)]
+        #[arg(required_unless_present_any = ["scheduled", "clear"])]
+        due: Option<String>,
+
+        /// Date and time at which the task is planned to be worked on
+        #[arg(
+            short = 's',
+            long = "scheduled",
+            value_name = "DATETIME",
+            allow_hyphen_values = true
+        )]
+        scheduled: Option<String>,
+
+        /// Remove the current due and scheduled dates of the task
+        #[arg(short = 'c', long = "clear")]
+        clear: bool,
+    },
+    /// Add tags to a task in the current tree
+    Tag {
+        /// Uid of the task
+        #[arg(value_name = "UID")]
+        #[arg(value_parser = forest_types::uid_parser)]
+        uid: String,
+
+        /// Tags to add
+        #[arg(value_name = "TAG", required = true)]
+        #[arg(value_parser = forest_types::tag_parser)]
+        tags: Vec<String>,
+    },
+
+    /// Remove tags from a task in the current tree
+    Untag {
+        /// Uid of the task
+        #[arg(value_name = "UID")]
+        #[arg(value_parser = forest_types::uid_parser)]
+        uid: String,
+
+        /// Tags to remove
+        #[arg(value_name = "TAG", required = true)]
+        #[arg(value_parser = forest_types::tag_parser)]
+        tags: Vec<String>,
+    },
 }
 
 #[derive(Subcommand)]
@@ -220,6 +635,11 @@ pub enum NoteCommands {
         /// Show time tracking notes (hidden by default)
         #[arg(short = 't', long = "show-tt")]
         show_time_tracking: bool,
+
+        /// Only display notes with one of the given tag(s)
+        #[arg(long = "tag", value_name = "TAG")]
+        #[arg(value_parser = forest_types::tag_parser)]
+        tags: Vec<String>,
     },
 
     /// Create a new note associated to the current tree
@@ -254,6 +674,200 @@ pub enum NoteCommands {
         #[arg(value_parser = forest_types::uid_parser)]
         uid: String,
     },
+    /// Add tags to a note
+    Tag {
+        /// Uid of the note
+        #[arg(value_name = "UID")]
+        #[arg(value_parser = forest_types::uid_parser)]
+        uid: String,
+
+        /// Tags to add
+        #[arg(value_name = "TAG", required = true)]
+        #[arg(value_parser = forest_types::tag_parser)]
+        tags: Vec<String>,
+    },
+
+    /// Remove tags from a note
+    Untag {
+        /// Uid of the note
+        #[arg(value_name = "UID")]
+        #[arg(value_parser = forest_types::uid_parser)]
+        uid: String,
+
+        /// Tags to remove
+        #[arg(value_name = "TAG", required = true)]
+        #[arg(value_parser = forest_types::tag_parser)]
+        tags: Vec<String>,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum ProfileCommands {
+    /// List all forests
+    #[clap(alias = "ls")]
+    List,
+
+    /// Create a new empty forest
+    Create {
+        /// Name of the new forest
+        #[arg(value_name = "NAME")]
+        #[arg(value_parser = forest_types::profile_name_parser)]
+        name: String,
+    },
+
+    /// Use another forest by default
+    Use {
+        /// Name of the forest to use, "default" being the original forest
+        #[arg(value_name = "NAME")]
+        #[arg(value_parser = forest_types::profile_name_parser)]
+        name: String,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum DbCommands {
+    /// Apply pending migrations to the database schema
+    Migrate,
+
+    /// Show the schema version of the database and pending migrations
+    Status,
+}
+
+#[derive(Subcommand)]
+pub enum ExportCommands {
+    /// Write time tracking frames as events and due dates of tasks as to-dos, in iCalendar format
+    Ics {
+        /// Only export frames ending and tasks due after the given date and time
+        #[arg(long = "from", value_name = "DATETIME", allow_hyphen_values = true)]
+        from: Option<String>,
+
+        /// Only export frames starting and tasks due before the given date and time
+        #[arg(long = "to", value_name = "DATETIME", allow_hyphen_values = true)]
+        to: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum FrameCommands {
+    /// List time tracking frames, most recent first
+    #[clap(alias = "ls")]
+    List {
+        /// Only list frames of the given tree
+        #[arg(short = 't', long = "tree", value_name = "TREE")]
+        #[arg(value_parser = forest_types::tree_name_parser)]
+        tree_name: Option<String>,
+
+        /// Only list frames ending after the given date and time
+        #[arg(long = "from", value_name = "DATETIME", allow_hyphen_values = true)]
+        from: Option<String>,
+
+        /// Only list frames starting before the given date and time
+        #[arg(long = "to", value_name = "DATETIME", allow_hyphen_values = true)]
+        to: Option<String>,
+    },
+
+    /// Add a time tracking frame
+    Add {
+        /// Name of tree or uid of task on which time was spent
+        #[arg(value_name = "TREE|UID")]
+        target: Option<String>,
+
+        /// Uid of task on which time was spent
+        #[arg(long = "task", value_name = "UID", conflicts_with = "target")]
+        #[arg(value_parser = forest_types::uid_parser)]
+        task: Option<String>,
+
+        /// Start date and time of the frame
+        #[arg(long = "start", value_name = "DATETIME", allow_hyphen_values = true)]
+        start: String,
+
+        /// End date and time of the frame
+        #[arg(long = "end", value_name = "DATETIME", allow_hyphen_values = true)]
+        end: String,
+
+        /// Trim, split or remove overlapping frames instead of failing
+        #[arg(long = "adjust")]
+        adjust: bool,
+    },
+
+    /// Edit a time tracking frame
+    Edit {
+        /// Uid of the frame
+        #[arg(value_name = "UID")]
+        #[arg(value_parser = forest_types::uid_parser)]
+        uid: String,
+
+        /// New start date and time of the frame
+        #[arg(long = "start", value_name = "DATETIME", allow_hyphen_values = true)]
+        start: Option<String>,
+
+        /// New end date and time of the frame
+        #[arg(long = "end", value_name = "DATETIME", allow_hyphen_values = true)]
+        end: Option<String>,
+
+        /// Attach the frame to the given tree
+        #[arg(long = "tree", value_name = "TREE", conflicts_with = "task")]
+        #[arg(value_parser = forest_types::tree_name_parser)]
+        tree_name: Option<String>,
+
+        /// Attach the frame to the given task
+        #[arg(long = "task", value_name = "UID")]
+        #[arg(value_parser = forest_types::uid_parser)]
+        task: Option<String>,
+
+        /// Trim, split or remove overlapping frames instead of failing
+        #[arg(long = "adjust")]
+        adjust: bool,
+    },
+
+    /// Remove a time tracking frame
+    #[clap(alias = "rm")]
+    Remove {
+        /// Uid of the frame
+        #[arg(value_name = "UID")]
+        #[arg(value_parser = forest_types::uid_parser)]
+        uid: String,
+    },
+
+    /// Split a time tracking frame in two at the given date and time
+    Split {
+        /// Uid of the frame
+        #[arg(value_name = "UID")]
+        #[arg(value_parser = forest_types::uid_parser)]
+        uid: String,
+
+        /// Date and time at which to split the frame
+        #[arg(long = "at", value_name = "DATETIME", allow_hyphen_values = true)]
+        at: String,
+    },
+
+    /// Merge two time tracking frames of the same tree or task
+    Merge {
+        /// Uid of the first frame
+        #[arg(value_name = "UID")]
+        #[arg(value_parser = forest_types::uid_parser)]
+        uid: String,
+
+        /// Uid of the second frame
+        #[arg(value_name = "OTHER_UID")]
+        #[arg(value_parser = forest_types::uid_parser)]
+        other_uid: String,
+
+        /// Trim, split or remove overlapping frames instead of failing
+        #[arg(long = "adjust")]
+        adjust: bool,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum ConfigCommands {
+    /// Get or set the first day of the week, used by `report --week`
+    WeekStart {
+        /// First day of the week
+        #[arg(value_name = "DAY")]
+        #[arg(value_parser = forest_types::weekday_parser)]
+        day: Option<forest_types::Weekday>,
+    },
 }
 
 #[test]
@@ -0,0 +1,35 @@
+use clap::Command;
+use clap_complete::engine::{ArgValueCompleter, CompletionCandidate};
+use std::ffi::OsStr;
+use tokio::runtime::Handle;
+
+use crate::forest;
+
+/// Attaches dynamic completers to the arguments of the command and of all its subcommands.
+///
+/// Completers query the forest database, so they cannot be declared in `cli.rs` which is also
+/// compiled by the build script to generate static completion files.
+pub fn add_completers(command: Command) -> Command {
+    command
+        .mut_args(|arg| {
+            if arg.get_id() == "tags" {
+                arg.add(ArgValueCompleter::new(complete_tags))
+            } else {
+                arg
+            }
+        })
+        .mut_subcommands(add_completers)
+}
+
+/// Completes tag names with the tags existing in the forest
+fn complete_tags(current: &OsStr) -> Vec<CompletionCandidate> {
+    let current = current.to_string_lossy();
+
+    // completers are called synchronously from within the async runtime
+    let tags = tokio::task::block_in_place(|| Handle::current().block_on(forest::tag::names()));
+
+    tags.into_iter()
+        .filter(|tag| tag.starts_with(current.as_ref()))
+        .map(CompletionCandidate::new)
+        .collect()
+}
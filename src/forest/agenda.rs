@@ -0,0 +1,141 @@
+use chrono::{Days, Local};
+use std::error::Error;
+
+use super::ansi;
+use super::dbutils;
+use super::task;
+use super::timetracking;
+use forest_types::Uid;
+
+/// Section of the agenda a task belongs to
+#[derive(PartialEq)]
+enum Section {
+    Overdue,
+    Today,
+    Upcoming,
+}
+
+/// Shows open tasks that are overdue, due or scheduled today, or due or scheduled in the next
+/// `days` days, across all trees
+///
+/// # Errors
+/// Returns an error if the local time of midnight cannot be determined
+///
+/// # Panics
+/// This function may panic if database operations fail
+pub async fn show(days: u32) -> Result<(), Box<dyn Error>> {
+    let pool = dbutils::load_db().await;
+
+    let mut conn = pool
+        .acquire()
+        .await
+        .expect("Acquiring connection to database should succeed");
+
+    // boundaries of the agenda sections
+    let now = Local::now();
+    let today = now.date_naive();
+    let tomorrow = today
+        .checked_add_days(Days::new(1))
+        .ok_or("Cannot compute the date of tomorrow")?;
+    let horizon = tomorrow
+        .checked_add_days(Days::new(days.into()))
+        .ok_or("Too many days to show")?;
+
+    let now = now.timestamp_millis();
+    let today_start = timetracking::local_midnight(today)?;
+    let tomorrow_start = timetracking::local_midnight(tomorrow)?;
+    let horizon_start = timetracking::local_midnight(horizon)?;
+
+    // get all open tasks of every tree that have a date before the end of the agenda
+    let query_result = sqlx::query!(
+        r#"
+        SELECT id, tree_name, name, due, scheduled
+        FROM task
+        WHERE
+            "left" != 1 AND
+            status IN ('todo', 'in-progress') AND
+            (due < ? OR scheduled < ?)
+        ORDER BY COALESCE(MIN(due, scheduled), due, scheduled) ASC;
+        "#,
+        horizon_start,
+        horizon_start,
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    // error handling
+    let tasks = match query_result {
+        Ok(records) => records,
+        Err(query_error) => panic!("Database query failed: {query_error}"),
+    };
+
+    if tasks.is_empty() {
+        println!("Nothing on the agenda for the next {days} days.");
+        return Ok(());
+    }
+
+    // sort each task in a section
+    let sections: Vec<Section> = tasks
+        .iter()
+        .map(|task| {
+            if task.due.is_some_and(|due| due < now) {
+                Section::Overdue
+            } else if task
+                .due
+                .is_some_and(|due| today_start <= due && due < tomorrow_start)
+                // tasks scheduled in the past are carried over to today until they are closed
+                || task.scheduled.is_some_and(|scheduled| scheduled < tomorrow_start)
+            {
+                Section::Today
+            } else {
+                Section::Upcoming
+            }
+        })
+        .collect();
+
+    for (section, title) in [
+        (Section::Overdue, "Overdue"),
+        (Section::Today, "Today"),
+        (Section::Upcoming, "Upcoming"),
+    ] {
+        if !sections.contains(&section) {
+            continue;
+        }
+
+        println!("{title}");
+        for (task, _) in tasks
+            .iter()
+            .zip(sections.iter())
+            .filter(|(_, task_section)| **task_section == section)
+        {
+            let date_format = if section == Section::Overdue {
+                ansi::ForestFormat::Overdue
+            } else {
+                ansi::ForestFormat::Date
+            };
+
+            print!(
+                "  {} {} {}",
+                ansi::format(
+                    Uid::try_from(task.id.clone()).unwrap().short(),
+                    ansi::ForestFormat::Uid
+                ),
+                ansi::format(&task.tree_name, ansi::ForestFormat::TreeName),
+                ansi::format(&task.name, ansi::ForestFormat::TaskName),
+            );
+            if let Some(due) = task.due {
+                print!(" (due {})", task::format_timestamp(due, date_format));
+            }
+            if let Some(scheduled) = task.scheduled {
+                print!(
+                    " (scheduled {})",
+                    task::format_timestamp(scheduled, ansi::ForestFormat::Date)
+                );
+            }
+            println!();
+        }
+        println!();
+    }
+
+    Ok(())
+}
@@ -8,6 +8,15 @@ const TIME: Style = Style::new().fg_color(Some(Color::Ansi(AnsiColor::Green)));
 const DATE: Style = Style::new().fg_color(Some(Color::Ansi(AnsiColor::Blue)));
 const UID: Style = Style::new().fg_color(Some(Color::Ansi(AnsiColor::Yellow)));
 const BOX: Style = Style::new().fg_color(Some(Color::Ansi(AnsiColor::BrightBlack)));
+const OVERDUE: Style = Style::new()
+    .fg_color(Some(Color::Ansi(AnsiColor::Red)))
+    .bold();
+const TAG: Style = Style::new().fg_color(Some(Color::Ansi(AnsiColor::BrightCyan)));
+const BLOCKED: Style = Style::new().fg_color(Some(Color::Ansi(AnsiColor::BrightRed)));
+const STATUS: Style = Style::new().bold();
+const CLOSED: Style = Style::new()
+    .fg_color(Some(Color::Ansi(AnsiColor::BrightBlack)))
+    .strikethrough();
 
 /// Format used to display forest data
 pub enum ForestFormat {
@@ -17,6 +26,11 @@ pub enum ForestFormat {
     Time,
     Uid,
     Box,
+    Status,
+    Closed,
+    Overdue,
+    Tag,
+    Blocked,
 }
 
 /// Formats the input string with the given format
@@ -40,5 +54,20 @@ pub fn format(s: &str, fmt: ForestFormat) -> String {
         ForestFormat::Box => {
             format!("{BOX}{s}{RESET}")
         }
+        ForestFormat::Status => {
+            format!("{STATUS}{s}{RESET}")
+        }
+        ForestFormat::Closed => {
+            format!("{CLOSED}{s}{RESET}")
+        }
+        ForestFormat::Overdue => {
+            format!("{OVERDUE}{s}{RESET}")
+        }
+        ForestFormat::Tag => {
+            format!("{TAG}{s}{RESET}")
+        }
+        ForestFormat::Blocked => {
+            format!("{BLOCKED}{s}{RESET}")
+        }
     }
 }
@@ -0,0 +1,1076 @@
+use chrono::Local;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sqlx::SqliteConnection;
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
+use std::fs;
+use std::path::PathBuf;
+
+use super::dbutils;
+use super::frame;
+use super::output;
+use super::task;
+use forest_types::{RestoreMode, TaskStatus, Uid};
+
+/// Identifier of forest archives
+const ARCHIVE_FORMAT: &str = "forest-backup";
+
+/// Version of the archive format written by this version of forest
+///
+/// It should be increased whenever the archive layout changes, and restoring should keep
+/// accepting archives of previous versions.
+const ARCHIVE_VERSION: u64 = 1;
+
+/// Whole content of a forest, along with the contents of its notes
+#[derive(Serialize, Deserialize)]
+struct Archive {
+    format: String,
+    version: u64,
+    created: String,
+    current_tree: Option<String>,
+    week_start: String,
+    trees: Vec<ArchivedTree>,
+    /// tasks of each tree, sorted by their position in the tree
+    tasks: Vec<ArchivedTask>,
+    tags: Vec<String>,
+    task_tags: Vec<ArchivedTaskTag>,
+    dependencies: Vec<ArchivedDependency>,
+    frames: Vec<ArchivedFrame>,
+    notes: Vec<ArchivedNote>,
+    note_tags: Vec<ArchivedNoteTag>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct ArchivedTree {
+    name: String,
+    description: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct ArchivedTask {
+    id: String,
+    tree_name: String,
+    left: i64,
+    right: i64,
+    name: String,
+    description: String,
+    status: String,
+    due: Option<i64>,
+    scheduled: Option<i64>,
+    recurrence: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct ArchivedTaskTag {
+    task_id: String,
+    tag_name: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct ArchivedDependency {
+    task_id: String,
+    blocker_id: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct ArchivedFrame {
+    id: String,
+    start: i64,
+    end: Option<i64>,
+    task_id: String,
+    session_id: Option<String>,
+    paused: bool,
+    utc_offset: Option<i64>,
+    import_id: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct ArchivedNote {
+    id: String,
+    date: i64,
+    task_id: String,
+    time_tracking: bool,
+    frame_id: Option<String>,
+    /// content of the note file
+    content: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct ArchivedNoteTag {
+    note_id: String,
+    tag_name: String,
+}
+
+/// Returns the path of the file of a note
+///
+/// # Errors
+/// Returns an error if the id is not a valid uid or if the note file cannot be located
+fn note_path(note_id: &str) -> Result<PathBuf, Box<dyn Error>> {
+    let uid = Uid::try_from(note_id.to_string())?;
+    dbutils::get_note_path(&uid)
+        .ok_or_else(|| format!("Cannot locate the file of note {uid}").into())
+}
+
+/// Saves the whole forest, including the contents of notes, to an archive
+///
+/// # Errors
+/// Returns an error if a note or the archive cannot be read or written
+///
+/// # Panics
+/// This function may panic if database operations fail
+pub async fn backup(path: &PathBuf) -> Result<(), Box<dyn Error>> {
+    let pool = dbutils::load_db().await;
+
+    // read everything in a single transaction, so that the archive is consistent
+    let mut transaction = pool
+        .begin()
+        .await
+        .expect("Beginning a transaction should succeed");
+
+    let state = sqlx::query!(
+        r#"
+        SELECT current_tree, week_start
+        FROM state;
+        "#,
+    )
+    .fetch_one(&mut *transaction)
+    .await
+    .unwrap_or_else(|e| panic!("Database query failed: {e}"));
+
+    let trees = sqlx::query_as!(
+        ArchivedTree,
+        r#"
+        SELECT name, description
+        FROM tree
+        ORDER BY name;
+        "#,
+    )
+    .fetch_all(&mut *transaction)
+    .await
+    .unwrap_or_else(|e| panic!("Database query failed: {e}"));
+
+    let tasks = sqlx::query_as!(
+        ArchivedTask,
+        r#"
+        SELECT
+            id, tree_name, "left", "right", name, description, status, due, scheduled,
+            recurrence
+        FROM task
+        ORDER BY tree_name, "left";
+        "#,
+    )
+    .fetch_all(&mut *transaction)
+    .await
+    .unwrap_or_else(|e| panic!("Database query failed: {e}"));
+
+    let tags = sqlx::query!(
+        r#"
+        SELECT name
+        FROM tag
+        ORDER BY name;
+        "#,
+    )
+    .fetch_all(&mut *transaction)
+    .await
+    .unwrap_or_else(|e| panic!("Database query failed: {e}"))
+    .into_iter()
+    .map(|record| record.name)
+    .collect();
+
+    let task_tags = sqlx::query_as!(
+        ArchivedTaskTag,
+        r#"
+        SELECT task_id, tag_name
+        FROM task_tag
+        ORDER BY task_id, tag_name;
+        "#,
+    )
+    .fetch_all(&mut *transaction)
+    .await
+    .unwrap_or_else(|e| panic!("Database query failed: {e}"));
+
+    let dependencies = sqlx::query_as!(
+        ArchivedDependency,
+        r#"
+        SELECT task_id, blocker_id
+        FROM dependency
+        ORDER BY task_id, blocker_id;
+        "#,
+    )
+    .fetch_all(&mut *transaction)
+    .await
+    .unwrap_or_else(|e| panic!("Database query failed: {e}"));
+
+    let frames = sqlx::query_as!(
+        ArchivedFrame,
+        r#"
+        SELECT
+            id, "start", "end", task_id, session_id, paused AS "paused: bool", utc_offset,
+            import_id
+        FROM frame
+        ORDER BY "start";
+        "#,
+    )
+    .fetch_all(&mut *transaction)
+    .await
+    .unwrap_or_else(|e| panic!("Database query failed: {e}"));
+
+    let records = sqlx::query!(
+        r#"
+        SELECT id, date, task_id, time_tracking AS "time_tracking: bool", frame_id
+        FROM note
+        ORDER BY date;
+        "#,
+    )
+    .fetch_all(&mut *transaction)
+    .await
+    .unwrap_or_else(|e| panic!("Database query failed: {e}"));
+
+    let mut notes = Vec::with_capacity(records.len());
+    for note in records {
+        let note_path = note_path(&note.id)?;
+        // notes whose file was never written are empty
+        let content = match fs::read_to_string(&note_path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(format!("Cannot read '{}': {e}", note_path.display()).into()),
+        };
+        notes.push(ArchivedNote {
+            id: note.id,
+            date: note.date,
+            task_id: note.task_id,
+            time_tracking: note.time_tracking,
+            frame_id: note.frame_id,
+            content,
+        });
+    }
+
+    let note_tags = sqlx::query_as!(
+        ArchivedNoteTag,
+        r#"
+        SELECT note_id, tag_name
+        FROM note_tag
+        ORDER BY note_id, tag_name;
+        "#,
+    )
+    .fetch_all(&mut *transaction)
+    .await
+    .unwrap_or_else(|e| panic!("Database query failed: {e}"));
+
+    let archive = Archive {
+        format: String::from(ARCHIVE_FORMAT),
+        version: ARCHIVE_VERSION,
+        created: output::format_rfc3339(Local::now().timestamp_millis()),
+        current_tree: state.current_tree,
+        week_start: state.week_start,
+        trees,
+        tasks,
+        tags,
+        task_tags,
+        dependencies,
+        frames,
+        notes,
+        note_tags,
+    };
+
+    let file =
+        fs::File::create(path).map_err(|e| format!("Cannot create '{}': {e}", path.display()))?;
+    serde_json::to_writer_pretty(std::io::BufWriter::new(file), &archive)
+        .map_err(|e| format!("Cannot write '{}': {e}", path.display()))?;
+
+    println!(
+        "Saved {} trees, {} tasks, {} frames and {} notes to '{}'",
+        archive.trees.len(),
+        archive.tasks.len() - archive.trees.len(),
+        archive.frames.len(),
+        archive.notes.len(),
+        path.display(),
+    );
+
+    Ok(())
+}
+
+/// Reads an archive and checks that it is consistent
+///
+/// # Errors
+/// Returns an error if the file is not a forest archive, if it was created by a newer version of
+/// forest, or if its content is inconsistent
+fn read_archive(path: &PathBuf) -> Result<Archive, Box<dyn Error>> {
+    let content =
+        fs::read_to_string(path).map_err(|e| format!("Cannot read '{}': {e}", path.display()))?;
+    let not_an_archive = || format!("'{}' is not a forest archive", path.display());
+
+    // check the version before reading the rest, whose layout depends on it
+    let header: Value = serde_json::from_str(&content).map_err(|_| not_an_archive())?;
+    if header["format"].as_str() != Some(ARCHIVE_FORMAT) {
+        return Err(not_an_archive().into());
+    }
+    let version = header["version"].as_u64().ok_or_else(not_an_archive)?;
+    if version > ARCHIVE_VERSION {
+        return Err(format!(
+            "'{}' was created by a newer version of forest (archive version {version}, supported up to {ARCHIVE_VERSION})",
+            path.display()
+        )
+        .into());
+    }
+
+    let mut archive: Archive = serde_json::from_value(header)
+        .map_err(|e| format!("'{}' is an ill-formed forest archive: {e}", path.display()))?;
+
+    // tasks are restored from the root of each tree, whatever their order in the file
+    archive
+        .tasks
+        .sort_by(|a, b| (&a.tree_name, a.left).cmp(&(&b.tree_name, b.left)));
+
+    let inconsistent =
+        |reason: String| format!("'{}' is an inconsistent archive: {reason}", path.display());
+
+    let tree_names: HashSet<&String> = archive.trees.iter().map(|tree| &tree.name).collect();
+    let task_ids: HashSet<&String> = archive.tasks.iter().map(|task| &task.id).collect();
+    let tag_names: HashSet<&String> = archive.tags.iter().collect();
+    let frame_ids: HashSet<&String> = archive.frames.iter().map(|frame| &frame.id).collect();
+    let note_ids: HashSet<&String> = archive.notes.iter().map(|note| &note.id).collect();
+
+    if let Some(current_tree) = &archive.current_tree {
+        if !tree_names.contains(current_tree) {
+            return Err(inconsistent(format!("unknown current tree '{current_tree}'")).into());
+        }
+    }
+    forest_types::weekday_parser(&archive.week_start).map_err(inconsistent)?;
+
+    // each tree should be a valid nested set, listed from its root
+    let mut tasks_of_tree: HashMap<&String, Vec<&ArchivedTask>> = HashMap::new();
+    for task in &archive.tasks {
+        if !tree_names.contains(&task.tree_name) {
+            let reason = format!("task {} is in unknown tree '{}'", task.id, task.tree_name);
+            return Err(inconsistent(reason).into());
+        }
+        Uid::try_from(task.id.clone()).map_err(|e| inconsistent(e.to_string()))?;
+        TaskStatus::try_from(task.status.clone()).map_err(|e| inconsistent(e.to_string()))?;
+        if let Some(recurrence) = &task.recurrence {
+            forest_types::recurrence_parser(recurrence).map_err(inconsistent)?;
+        }
+        tasks_of_tree.entry(&task.tree_name).or_default().push(task);
+    }
+    if task_ids.len() != archive.tasks.len() {
+        return Err(inconsistent(String::from("task uids are not unique")).into());
+    }
+    for tree in &archive.trees {
+        let tasks = tasks_of_tree.remove(&tree.name).unwrap_or_default();
+
+        let width = 2 * tasks.len() as i64;
+        if tasks
+            .first()
+            .is_none_or(|root| root.left != 1 || root.right != width)
+        {
+            let reason = format!("tree '{}' has no root", tree.name);
+            return Err(inconsistent(reason).into());
+        }
+
+        // boundaries should be unique and tasks should be nested inside their parent
+        let mut boundaries = HashSet::new();
+        let mut ancestors: Vec<i64> = Vec::new();
+        for task in tasks {
+            while ancestors.last().is_some_and(|&right| right < task.left) {
+                ancestors.pop();
+            }
+            if task.left >= task.right
+                || ancestors.last().is_some_and(|&right| right < task.right)
+                || !boundaries.insert(task.left)
+                || !boundaries.insert(task.right)
+                || task.right > width
+            {
+                let reason = format!("task {} is misplaced in tree '{}'", task.id, tree.name);
+                return Err(inconsistent(reason).into());
+            }
+            ancestors.push(task.right);
+        }
+    }
+
+    for task_tag in &archive.task_tags {
+        if !task_ids.contains(&task_tag.task_id) || !tag_names.contains(&task_tag.tag_name) {
+            let reason = format!("tag of unknown task {}", task_tag.task_id);
+            return Err(inconsistent(reason).into());
+        }
+    }
+    for dependency in &archive.dependencies {
+        if !task_ids.contains(&dependency.task_id) || !task_ids.contains(&dependency.blocker_id) {
+            let reason = format!("dependency of unknown task {}", dependency.task_id);
+            return Err(inconsistent(reason).into());
+        }
+    }
+    let mut running_frames = 0;
+    for frame in &archive.frames {
+        if !task_ids.contains(&frame.task_id) {
+            let reason = format!("frame {} is on unknown task {}", frame.id, frame.task_id);
+            return Err(inconsistent(reason).into());
+        }
+        if frame.end.is_some_and(|end| end <= frame.start) {
+            let reason = format!("frame {} ends before it starts", frame.id);
+            return Err(inconsistent(reason).into());
+        }
+        running_frames += i32::from(frame.end.is_none());
+    }
+    if frame_ids.len() != archive.frames.len() || running_frames > 1 {
+        return Err(inconsistent(String::from("frames are not unique")).into());
+    }
+    for note in &archive.notes {
+        Uid::try_from(note.id.clone()).map_err(|e| inconsistent(e.to_string()))?;
+        if !task_ids.contains(&note.task_id) {
+            let reason = format!("note {} is on unknown task {}", note.id, note.task_id);
+            return Err(inconsistent(reason).into());
+        }
+    }
+    if note_ids.len() != archive.notes.len() {
+        return Err(inconsistent(String::from("note uids are not unique")).into());
+    }
+    for note_tag in &archive.note_tags {
+        if !note_ids.contains(&note_tag.note_id) || !tag_names.contains(&note_tag.tag_name) {
+            let reason = format!("tag of unknown note {}", note_tag.note_id);
+            return Err(inconsistent(reason).into());
+        }
+    }
+
+    Ok(archive)
+}
+
+/// Removes all trees, tasks, frames and notes from the forest
+///
+/// Returns the ids of the removed notes, whose files are left untouched.
+///
+/// # Panics
+/// This function may panic if database operations fail
+async fn clear(conn: &mut SqliteConnection) -> Vec<String> {
+    let note_ids = sqlx::query!(
+        r#"
+        SELECT id
+        FROM note;
+        "#,
+    )
+    .fetch_all(&mut *conn)
+    .await
+    .unwrap_or_else(|e| panic!("Database query failed: {e}"))
+    .into_iter()
+    .map(|record| record.id)
+    .collect();
+
+    // removing trees removes their tasks, and everything attached to these tasks
+    let query_result = sqlx::query!(
+        r#"
+        DELETE FROM frame;
+        DELETE FROM note;
+        DELETE FROM tree;
+        DELETE FROM task;
+        DELETE FROM tag;
+        UPDATE state SET current_tree = NULL;
+        "#,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    // error handling
+    if let Err(query_error) = query_result {
+        panic!("Database query failed: {query_error}");
+    }
+
+    note_ids
+}
+
+/// Number of records of an archive that were restored or skipped
+#[derive(Default)]
+struct RestoreSummary {
+    trees: usize,
+    tasks: usize,
+    frames: usize,
+    notes: usize,
+    /// records that already were in the forest
+    existing: usize,
+    /// frames overlapping frames of the forest
+    overlapping: usize,
+    /// dependencies that would close a dependency cycle with the ones of the forest
+    cyclic: usize,
+    /// files of the restored notes, to be written once the restored forest is saved
+    note_files: Vec<(PathBuf, String)>,
+}
+
+/// Adds the trees, tasks, frames and notes of an archive that are missing from the forest
+///
+/// Records are matched on their uids, and trees on their names. Missing tasks are added as last
+/// children of their parent. Dependencies that would close a dependency cycle are not added, nor
+/// are frames overlapping frames of the forest with `skip_overlaps`.
+///
+/// # Errors
+/// Returns an error if the file of a note cannot be located
+///
+/// # Panics
+/// This function may panic if database operations fail
+async fn merge(
+    conn: &mut SqliteConnection,
+    archive: &Archive,
+    skip_overlaps: bool,
+) -> Result<RestoreSummary, Box<dyn Error>> {
+    let mut summary = RestoreSummary::default();
+
+    for tree in &archive.trees {
+        let query_result = sqlx::query!(
+            r#"
+            INSERT OR IGNORE INTO tree("name", "description")
+            VALUES (?, ?);
+            "#,
+            tree.name,
+            tree.description,
+        )
+        .execute(&mut *conn)
+        .await;
+
+        // error handling
+        match query_result {
+            Ok(result) => summary.trees += result.rows_affected() as usize,
+            Err(query_error) => panic!("Database query failed: {query_error}"),
+        }
+    }
+
+    // tasks of the forest standing for tasks of the archive, ie. roots of existing trees
+    let mut local_ids: HashMap<&String, String> = HashMap::new();
+    // uids of the parents of archived tasks, found along the way as tasks are sorted
+    let mut ancestors: Vec<(&String, i64, &String)> = Vec::new();
+    for archived_task in &archive.tasks {
+        while ancestors.last().is_some_and(|&(tree_name, right, _)| {
+            tree_name != &archived_task.tree_name || right < archived_task.left
+        }) {
+            ancestors.pop();
+        }
+        let parent_id = ancestors.last().map(|&(_, _, id)| id);
+        ancestors.push((
+            &archived_task.tree_name,
+            archived_task.right,
+            &archived_task.id,
+        ));
+
+        // the position of the new task is the end of its parent, or the root of a new tree
+        let (tree_name, left) = match parent_id {
+            Some(parent_id) => {
+                let parent_id = local_ids.get(parent_id).unwrap_or(parent_id);
+                let query_result = sqlx::query!(
+                    r#"
+                    SELECT tree_name, "right"
+                    FROM task
+                    WHERE id = ?;
+                    "#,
+                    parent_id,
+                )
+                .fetch_one(&mut *conn)
+                .await;
+
+                match query_result {
+                    Ok(record) => (record.tree_name, record.right),
+                    Err(query_error) => panic!("Database query failed: {query_error}"),
+                }
+            }
+            None => (archived_task.tree_name.clone(), 1),
+        };
+
+        let is_root = parent_id.is_none();
+        let query_result = sqlx::query!(
+            r#"
+            SELECT id
+            FROM task
+            WHERE id = ? OR (? AND tree_name = ? AND "left" = 1);
+            "#,
+            archived_task.id,
+            is_root,
+            tree_name,
+        )
+        .fetch_optional(&mut *conn)
+        .await;
+
+        // error handling
+        match query_result {
+            Ok(Some(record)) => {
+                // roots of existing trees are not counted as they were not in the forest
+                if parent_id.is_some() {
+                    summary.existing += 1;
+                }
+                local_ids.insert(&archived_task.id, record.id);
+                continue;
+            }
+            Ok(None) => (),
+            Err(query_error) => panic!("Database query failed: {query_error}"),
+        }
+
+        if parent_id.is_some() {
+            task::shift_tasks(conn, &tree_name, left, 2).await;
+            summary.tasks += 1;
+        }
+
+        let right = left + 1;
+        let query_result = sqlx::query!(
+            r#"
+            INSERT INTO task(
+                "id", "tree_name", "left", "right", "name", "description", "status", "due",
+                "scheduled", "recurrence"
+            )
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?);
+            "#,
+            archived_task.id,
+            tree_name,
+            left,
+            right,
+            archived_task.name,
+            archived_task.description,
+            archived_task.status,
+            archived_task.due,
+            archived_task.scheduled,
+            archived_task.recurrence,
+        )
+        .execute(&mut *conn)
+        .await;
+
+        // error handling
+        if let Err(query_error) = query_result {
+            panic!("Database query failed: {query_error}");
+        }
+    }
+    let local_id = |id: &String| local_ids.get(id).unwrap_or(id).clone();
+
+    for tag in &archive.tags {
+        let query_result = sqlx::query!(
+            r#"
+            INSERT OR IGNORE INTO tag("name")
+            VALUES (?);
+            "#,
+            tag,
+        )
+        .execute(&mut *conn)
+        .await;
+
+        // error handling
+        if let Err(query_error) = query_result {
+            panic!("Database query failed: {query_error}");
+        }
+    }
+
+    for task_tag in &archive.task_tags {
+        let task_id = local_id(&task_tag.task_id);
+        let query_result = sqlx::query!(
+            r#"
+            INSERT OR IGNORE INTO task_tag("task_id", "tag_name")
+            VALUES (?, ?);
+            "#,
+            task_id,
+            task_tag.tag_name,
+        )
+        .execute(&mut *conn)
+        .await;
+
+        // error handling
+        if let Err(query_error) = query_result {
+            panic!("Database query failed: {query_error}");
+        }
+    }
+
+    for dependency in &archive.dependencies {
+        let task_id = local_id(&dependency.task_id);
+        let blocker_id = local_id(&dependency.blocker_id);
+
+        // the forest may already have the opposite dependency, directly or not
+        if task::would_create_cycle(conn, &task_id, &blocker_id).await {
+            summary.cyclic += 1;
+            continue;
+        }
+
+        let query_result = sqlx::query!(
+            r#"
+            INSERT OR IGNORE INTO dependency("task_id", "blocker_id")
+            VALUES (?, ?);
+            "#,
+            task_id,
+            blocker_id,
+        )
+        .execute(&mut *conn)
+        .await;
+
+        // error handling
+        if let Err(query_error) = query_result {
+            panic!("Database query failed: {query_error}");
+        }
+    }
+
+    for archived_frame in &archive.frames {
+        let query_result = sqlx::query!(
+            r#"
+            SELECT COUNT(*) AS "count!: i64"
+            FROM frame
+            WHERE id = ?;
+            "#,
+            archived_frame.id,
+        )
+        .fetch_one(&mut *conn)
+        .await;
+
+        // error handling
+        match query_result {
+            Ok(record) => {
+                if record.count > 0 {
+                    summary.existing += 1;
+                    continue;
+                }
+            }
+            Err(query_error) => panic!("Database query failed: {query_error}"),
+        }
+
+        if skip_overlaps
+            && frame::resolve_overlaps(conn, archived_frame.start, archived_frame.end, &[], false)
+                .await
+                .is_err()
+        {
+            summary.overlapping += 1;
+            continue;
+        }
+
+        let task_id = local_id(&archived_frame.task_id);
+        let query_result = sqlx::query!(
+            r#"
+            INSERT INTO frame(
+                "id", "start", "end", "task_id", "session_id", "paused", "utc_offset", "import_id"
+            )
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?);
+            "#,
+            archived_frame.id,
+            archived_frame.start,
+            archived_frame.end,
+            task_id,
+            archived_frame.session_id,
+            archived_frame.paused,
+            archived_frame.utc_offset,
+            archived_frame.import_id,
+        )
+        .execute(&mut *conn)
+        .await;
+
+        // error handling
+        match query_result {
+            Ok(_) => summary.frames += 1,
+            Err(sqlx::Error::Database(db_error))
+                if db_error.kind() == sqlx::error::ErrorKind::UniqueViolation =>
+            {
+                // the frame was already imported from another time tracker, or is running
+                summary.existing += 1;
+            }
+            Err(query_error) => panic!("Database query failed: {query_error}"),
+        }
+    }
+
+    for note in &archive.notes {
+        // notes about frames that were not restored are not linked to any frame
+        let task_id = local_id(&note.task_id);
+        let query_result = sqlx::query!(
+            r#"
+            INSERT OR IGNORE INTO note("id", "date", "task_id", "time_tracking", "frame_id")
+            VALUES (?, ?, ?, ?, (SELECT id FROM frame WHERE id = ?));
+            "#,
+            note.id,
+            note.date,
+            task_id,
+            note.time_tracking,
+            note.frame_id,
+        )
+        .execute(&mut *conn)
+        .await;
+
+        // error handling
+        match query_result {
+            Ok(result) => {
+                if result.rows_affected() == 0 {
+                    summary.existing += 1;
+                    continue;
+                }
+            }
+            Err(query_error) => panic!("Database query failed: {query_error}"),
+        }
+
+        summary
+            .note_files
+            .push((note_path(&note.id)?, note.content.clone()));
+        summary.notes += 1;
+    }
+
+    for note_tag in &archive.note_tags {
+        let query_result = sqlx::query!(
+            r#"
+            INSERT OR IGNORE INTO note_tag("note_id", "tag_name")
+            VALUES (?, ?);
+            "#,
+            note_tag.note_id,
+            note_tag.tag_name,
+        )
+        .execute(&mut *conn)
+        .await;
+
+        // error handling
+        if let Err(query_error) = query_result {
+            panic!("Database query failed: {query_error}");
+        }
+    }
+
+    Ok(summary)
+}
+
+/// Restores the forest from an archive created by `backup`
+///
+/// With `RestoreMode::Replace`, the forest is replaced by the content of the archive. With
+/// `RestoreMode::Merge`, only trees, tasks, frames and notes whose uids are missing from the forest
+/// are added, and frames overlapping frames of the forest are skipped. In both modes, dependencies
+/// that would create a dependency cycle are skipped.
+///
+/// # Errors
+/// Returns an error if the archive cannot be read, is not a valid archive, or if notes cannot be
+/// written
+///
+/// # Panics
+/// This function may panic if database operations fail
+pub async fn restore(path: &PathBuf, mode: RestoreMode) -> Result<(), Box<dyn Error>> {
+    let archive = read_archive(path)?;
+
+    let pool = dbutils::load_db().await;
+
+    let mut transaction = pool
+        .begin()
+        .await
+        .expect("Beginning a transaction should succeed");
+
+    let removed_note_ids = match mode {
+        RestoreMode::Replace => clear(&mut transaction).await,
+        RestoreMode::Merge => Vec::new(),
+    };
+
+    let summary = merge(&mut transaction, &archive, mode == RestoreMode::Merge).await?;
+
+    // restore the state of the archive, unless it would change the current tree
+    let replace = mode == RestoreMode::Replace;
+    let query_result = sqlx::query!(
+        r#"
+        UPDATE state
+        SET
+            current_tree = COALESCE(current_tree, ?),
+            week_start = CASE WHEN ? THEN ? ELSE week_start END;
+        "#,
+        archive.current_tree,
+        replace,
+        archive.week_start,
+    )
+    .execute(&mut *transaction)
+    .await;
+
+    // error handling
+    if let Err(query_error) = query_result {
+        panic!("Database query failed: {query_error}");
+    }
+
+    transaction
+        .commit()
+        .await
+        .expect("Committing a transaction should succeed");
+
+    // files of notes are only written and removed once the new forest is saved
+    for (note_path, content) in &summary.note_files {
+        fs::write(note_path, content)
+            .map_err(|e| format!("Cannot write '{}': {e}", note_path.display()))?;
+    }
+    let restored_note_ids: HashSet<&String> = archive.notes.iter().map(|note| &note.id).collect();
+    for note_id in removed_note_ids {
+        if !restored_note_ids.contains(&note_id) {
+            // a missing file is not an issue since the note is gone anyway
+            let _ = fs::remove_file(note_path(&note_id)?);
+        }
+    }
+
+    println!(
+        "Restored {} trees, {} tasks, {} frames and {} notes from '{}'",
+        summary.trees,
+        summary.tasks,
+        summary.frames,
+        summary.notes,
+        path.display(),
+    );
+    if summary.existing + summary.overlapping + summary.cyclic > 0 {
+        println!(
+            "Skipped {} records already in the forest, {} overlapping frames and {} dependencies that would create a cycle",
+            summary.existing, summary.overlapping, summary.cyclic,
+        );
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Archive whose tasks are valid but listed child first, with trees interleaved
+    const OUT_OF_ORDER_ARCHIVE: &str = r#"{
+        "format": "forest-backup",
+        "version": 1,
+        "created": "2024-06-12T14:30:00+02:00",
+        "current_tree": "work",
+        "week_start": "Mon",
+        "trees": [
+            {"name": "home", "description": ""},
+            {"name": "work", "description": ""}
+        ],
+        "tasks": [
+            {"id": "00000000000000000000000000000003", "tree_name": "work", "left": 2, "right": 3,
+             "name": "child", "description": "", "status": "todo",
+             "due": null, "scheduled": null, "recurrence": null},
+            {"id": "00000000000000000000000000000001", "tree_name": "home", "left": 1, "right": 2,
+             "name": "home", "description": "", "status": "todo",
+             "due": null, "scheduled": null, "recurrence": null},
+            {"id": "00000000000000000000000000000002", "tree_name": "work", "left": 1, "right": 4,
+             "name": "work", "description": "", "status": "todo",
+             "due": null, "scheduled": null, "recurrence": null}
+        ],
+        "tags": [],
+        "task_tags": [],
+        "dependencies": [],
+        "frames": [],
+        "notes": [],
+        "note_tags": []
+    }"#;
+
+    /// Reads an archive from a temporary file named after the test
+    fn read(test_name: &str, archive: &str) -> Result<Archive, Box<dyn Error>> {
+        let file_name = format!("forest-archive-{}-{test_name}.json", std::process::id());
+        let path = std::env::temp_dir().join(file_name);
+        fs::write(&path, archive).unwrap();
+        let archive = read_archive(&path);
+        fs::remove_file(&path).unwrap();
+        archive
+    }
+
+    /// Returns why the archive, changed by `change`, is rejected as inconsistent
+    fn rejection(test_name: &str, change: impl FnOnce(&mut Value)) -> String {
+        let mut archive: Value = serde_json::from_str(OUT_OF_ORDER_ARCHIVE).unwrap();
+        change(&mut archive);
+        let error = match read(test_name, &archive.to_string()) {
+            Ok(_) => panic!("archive should be rejected"),
+            Err(e) => e.to_string(),
+        };
+        let (_, reason) = error
+            .split_once("is an inconsistent archive: ")
+            .unwrap_or_else(|| panic!("unexpected error: {error}"));
+        reason.to_string()
+    }
+
+    /// Returns an archived task of the "work" tree
+    fn work_task(id: &str, left: i64, right: i64) -> Value {
+        serde_json::json!({
+            "id": id, "tree_name": "work", "left": left, "right": right,
+            "name": "task", "description": "", "status": "todo",
+            "due": null, "scheduled": null, "recurrence": null
+        })
+    }
+
+    /// Returns an archived frame of the "work" task
+    fn work_frame(id: &str, start: i64, end: Option<i64>) -> Value {
+        serde_json::json!({
+            "id": id, "start": start, "end": end, "task_id": "00000000000000000000000000000002",
+            "session_id": null, "paused": false, "utc_offset": null, "import_id": null
+        })
+    }
+
+    #[test]
+    fn sorts_out_of_order_tasks() {
+        let archive = read("sorted", OUT_OF_ORDER_ARCHIVE);
+
+        // each tree is listed from its root, so that parents come before their children
+        let tasks: Vec<(&str, i64)> = archive
+            .as_ref()
+            .unwrap()
+            .tasks
+            .iter()
+            .map(|task| (task.tree_name.as_str(), task.left))
+            .collect();
+        assert_eq!(tasks, vec![("home", 1), ("work", 1), ("work", 2)]);
+    }
+
+    #[test]
+    fn rejects_misplaced_tasks() {
+        // the second child starts inside the first one but ends outside of it
+        let reason = rejection("misplaced", |archive| {
+            archive["tasks"] = serde_json::json!([
+                work_task("00000000000000000000000000000002", 1, 6),
+                work_task("00000000000000000000000000000003", 2, 4),
+                work_task("00000000000000000000000000000004", 3, 5),
+            ]);
+            archive["trees"] = serde_json::json!([{"name": "work", "description": ""}]);
+        });
+        assert_eq!(
+            reason,
+            "task 00000000000000000000000000000004 is misplaced in tree 'work'"
+        );
+    }
+
+    #[test]
+    fn rejects_duplicate_boundaries() {
+        let reason = rejection("boundaries", |archive| {
+            archive["tasks"] = serde_json::json!([
+                work_task("00000000000000000000000000000002", 1, 6),
+                work_task("00000000000000000000000000000003", 2, 5),
+                work_task("00000000000000000000000000000004", 2, 3),
+            ]);
+            archive["trees"] = serde_json::json!([{"name": "work", "description": ""}]);
+        });
+        assert_eq!(
+            reason,
+            "task 00000000000000000000000000000004 is misplaced in tree 'work'"
+        );
+    }
+
+    #[test]
+    fn rejects_two_running_frames() {
+        let reason = rejection("running", |archive| {
+            archive["frames"] = serde_json::json!([
+                work_frame("00000000000000000000000000000010", 1_000, None),
+                work_frame("00000000000000000000000000000011", 2_000, None),
+            ]);
+        });
+        assert_eq!(reason, "frames are not unique");
+
+        // a stopped frame can be archived with the running one
+        let mut archive: Value = serde_json::from_str(OUT_OF_ORDER_ARCHIVE).unwrap();
+        archive["frames"] = serde_json::json!([
+            work_frame("00000000000000000000000000000010", 1_000, Some(1_500)),
+            work_frame("00000000000000000000000000000011", 2_000, None),
+        ]);
+        assert!(read("stopped", &archive.to_string()).is_ok());
+    }
+
+    #[test]
+    fn rejects_dangling_tags() {
+        let reason = rejection("tag", |archive| {
+            archive["tags"] = serde_json::json!(["urgent"]);
+            archive["task_tags"] = serde_json::json!([
+                {"task_id": "00000000000000000000000000000009", "tag_name": "urgent"}
+            ]);
+        });
+        assert_eq!(
+            reason,
+            "tag of unknown task 00000000000000000000000000000009"
+        );
+    }
+
+    #[test]
+    fn rejects_dangling_dependencies() {
+        let reason = rejection("dependency", |archive| {
+            archive["dependencies"] = serde_json::json!([
+                {"task_id": "00000000000000000000000000000002",
+                 "blocker_id": "00000000000000000000000000000009"}
+            ]);
+        });
+        assert_eq!(
+            reason,
+            "dependency of unknown task 00000000000000000000000000000002"
+        );
+    }
+}
@@ -0,0 +1,50 @@
+use std::error::Error;
+
+use super::dbutils;
+use forest_types::Weekday;
+
+/// Prints the first day of the week, or sets it if a day is provided
+///
+/// # Errors
+/// Returns an error if the state of the forest cannot be updated
+///
+/// # Panics
+/// This function may panic if database operations fail
+pub async fn week_start(day: Option<Weekday>) -> Result<(), Box<dyn Error>> {
+    let pool = dbutils::load_db().await;
+
+    let Some(day) = day else {
+        println!("Weeks start on {}", dbutils::get_week_start(&pool).await);
+        return Ok(());
+    };
+
+    let mut conn = pool
+        .acquire()
+        .await
+        .expect("Acquiring connection to database should succeed");
+
+    let week_start = day.to_string();
+    let query_result = sqlx::query!(
+        r#"
+        UPDATE state
+        SET week_start = ?;
+        "#,
+        week_start,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    // error handling
+    match query_result {
+        Ok(result) => {
+            if result.rows_affected() != 1 {
+                return Err("Cannot update the state of the forest".into());
+            }
+        }
+        Err(query_error) => panic!("Database query failed: {query_error}"),
+    }
+
+    println!("Weeks now start on {day}");
+
+    Ok(())
+}
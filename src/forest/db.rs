@@ -0,0 +1,170 @@
+use serde::Serialize;
+use sqlx::migrate::Migrate;
+use std::collections::HashSet;
+use std::error::Error;
+
+use super::ansi;
+use super::dbutils;
+use super::output::{self, Render};
+use forest_types::OutputFormat;
+
+/// Migration of the database schema, and whether it was applied to the database
+#[derive(Serialize, Clone)]
+struct MigrationStatus {
+    version: i64,
+    description: String,
+    applied: bool,
+}
+
+/// Schema version of the database and migrations known to this version of forest
+#[derive(Serialize)]
+struct DbStatus {
+    database: String,
+    /// version of the last applied migration, 0 for an empty database
+    version: i64,
+    latest_version: i64,
+    migrations: Vec<MigrationStatus>,
+}
+
+impl Render for DbStatus {
+    type Row = MigrationStatus;
+
+    fn print_text(&self) {
+        println!("Database: {}", self.database);
+
+        let pending = self
+            .migrations
+            .iter()
+            .filter(|migration| !migration.applied)
+            .count();
+        if pending == 0 {
+            println!("Schema version {}, up to date", self.version);
+        } else {
+            println!(
+                "Schema version {}, {pending} pending migrations up to version {} (run `forest db migrate`)",
+                self.version, self.latest_version,
+            );
+        }
+
+        for migration in &self.migrations {
+            let status = if migration.applied {
+                ansi::format("[x]", ansi::ForestFormat::Closed)
+            } else {
+                ansi::format("[ ]", ansi::ForestFormat::Box)
+            };
+            println!(
+                "  {status} {:>4} {}",
+                migration.version, migration.description
+            );
+        }
+    }
+
+    fn rows(&self) -> Vec<Self::Row> {
+        self.migrations.clone()
+    }
+}
+
+/// Returns the versions of the migrations applied to the database
+///
+/// # Errors
+/// Returns an error if a migration failed halfway
+///
+/// # Panics
+/// This function may panic if database operations fail
+async fn applied_versions(pool: &sqlx::SqlitePool) -> Result<HashSet<i64>, Box<dyn Error>> {
+    let mut conn = pool
+        .acquire()
+        .await
+        .expect("Acquiring connection to database should succeed");
+
+    conn.ensure_migrations_table()
+        .await
+        .unwrap_or_else(|e| panic!("Database query failed: {e}"));
+
+    if let Some(version) = conn
+        .dirty_version()
+        .await
+        .unwrap_or_else(|e| panic!("Database query failed: {e}"))
+    {
+        return Err(format!("Migration {version} of the database failed halfway").into());
+    }
+
+    Ok(conn
+        .list_applied_migrations()
+        .await
+        .unwrap_or_else(|e| panic!("Database query failed: {e}"))
+        .into_iter()
+        .map(|migration| migration.version)
+        .collect())
+}
+
+/// Prints the schema version of the database and which migrations are pending
+///
+/// # Errors
+/// Returns an error if a migration failed halfway, or if the status cannot be printed
+///
+/// # Panics
+/// This function may panic if database operations fail
+pub async fn status(output: OutputFormat) -> Result<(), Box<dyn Error>> {
+    // the database is not migrated, so that pending migrations can be listed
+    let pool = dbutils::connect().await;
+    dbutils::record_baseline_migration(&pool).await;
+
+    let applied = applied_versions(&pool).await?;
+
+    let migrations: Vec<MigrationStatus> = dbutils::MIGRATOR
+        .iter()
+        .map(|migration| MigrationStatus {
+            version: migration.version,
+            description: migration.description.to_string(),
+            applied: applied.contains(&migration.version),
+        })
+        .collect();
+
+    let status = DbStatus {
+        database: dbutils::get_db_path().display().to_string(),
+        version: applied.iter().copied().max().unwrap_or_default(),
+        latest_version: migrations
+            .iter()
+            .map(|migration| migration.version)
+            .max()
+            .unwrap_or_default(),
+        migrations,
+    };
+
+    output::print(&status, output)
+}
+
+/// Applies pending migrations to the database
+///
+/// # Errors
+/// Returns an error if a migration failed halfway, or if the database cannot be migrated, eg.
+/// because it was migrated by a newer version of forest
+///
+/// # Panics
+/// This function may panic if database operations fail
+pub async fn migrate() -> Result<(), Box<dyn Error>> {
+    let pool = dbutils::connect().await;
+    dbutils::record_baseline_migration(&pool).await;
+
+    let applied = applied_versions(&pool).await?;
+
+    dbutils::MIGRATOR
+        .run(&pool)
+        .await
+        .map_err(dbutils::format_migrate_error)?;
+
+    let mut version = applied.iter().copied().max().unwrap_or_default();
+    for migration in dbutils::MIGRATOR.iter() {
+        if !applied.contains(&migration.version) {
+            println!(
+                "Applied migration {} ({})",
+                migration.version, migration.description
+            );
+            version = version.max(migration.version);
+        }
+    }
+    println!("Database is up to date, with schema version {version}");
+
+    Ok(())
+}
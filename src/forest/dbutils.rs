@@ -1,6 +1,10 @@
-use forest_types::Uid;
+use forest_types::{Uid, Weekday};
+use sqlx::migrate::{Migrate, MigrateError, Migrator};
+use std::env;
 use std::error::Error;
+use std::fs;
 use std::path::PathBuf;
+use std::sync::OnceLock;
 
 /// Name of config directory for the application
 const FOREST_CONFIG_DIR: &str = "forest";
@@ -11,58 +15,357 @@ const FOREST_NOTE_SUBDIR: &str = "notes";
 /// Name of db file storing user data
 const SQLITE_DB_FILE_NAME: &str = "forest.db";
 
-/// Creates the necessary SQLite tables if they do not exist
+/// Name of sub directory storing named forests, besides the default one
+const FOREST_PROFILE_SUBDIR: &str = "forests";
+
+/// Name of the file storing the name of the forest used by default
+const FOREST_PROFILE_FILE_NAME: &str = "profile";
+
+/// Name of the forest stored directly in the config directory
+pub(super) const DEFAULT_PROFILE: &str = "default";
+
+/// Environment variable giving the path to the database file, notes being stored next to it
+pub(super) const FOREST_DB_VAR: &str = "FOREST_DB";
+
+/// Environment variable giving the directory storing the database and the notes
+pub(super) const FOREST_HOME_VAR: &str = "FOREST_HOME";
+
+/// Name of the file pinning the tree, and optionally the task, of a directory and its subdirectories
+pub(super) const DIRECTORY_CONTEXT_FILE_NAME: &str = ".forest";
+
+/// Name of the forest selected for this run, overriding all other settings
+static SELECTED_PROFILE: OnceLock<String> = OnceLock::new();
+
+/// Returns the directories of the application in the XDG config directory
+///
 /// # Panics
-/// This function may panic if the sql table creation fails
-async fn create_tables_if_not_exist(pool: &sqlx::sqlite::SqlitePool) {
-    let mut conn = pool
-        .acquire()
-        .await
-        .expect("Acquiring connection to database should succeed");
+/// This function may panic if the home directory cannot be located
+fn get_xdg_dirs() -> xdg::BaseDirectories {
+    xdg::BaseDirectories::with_prefix(FOREST_CONFIG_DIR)
+        .unwrap_or_else(|e| panic!("Cannot locate home directory: {e}"))
+}
 
-    let _ = sqlx::query_file!("src/sql/create_tables.sql")
-        .execute(&mut *conn)
-        .await
-        .expect("Should be able to create tables");
+/// Returns the config directory of the application, which stores the default forest
+pub(super) fn get_config_home() -> PathBuf {
+    get_xdg_dirs().get_config_home()
 }
 
-/// Tries to access database
-/// Returns a `sqlx::Pool` on success.
+/// Returns the value of an environment variable, if it is set and not empty
+pub(super) fn get_env_path(var: &str) -> Option<PathBuf> {
+    env::var_os(var)
+        .filter(|value| !value.is_empty())
+        .map(PathBuf::from)
+}
+
+/// Returns the directory storing the given forest, or `None` for the default forest
+pub(super) fn get_profile_dir(name: &str) -> Option<PathBuf> {
+    (name != DEFAULT_PROFILE).then(|| get_config_home().join(FOREST_PROFILE_SUBDIR).join(name))
+}
+
+/// Returns the names of all forests, the default one first
+///
 /// # Panics
-/// This function may panic if db file cannot be located or if connection to db failed
-pub async fn load_db() -> sqlx::Pool<sqlx::Sqlite> {
-    // get config directory
-    let xdg_dirs = xdg::BaseDirectories::with_prefix(FOREST_CONFIG_DIR)
-        .unwrap_or_else(|e| panic!("Cannot locate home directory: {e}"));
-
-    // get path to database file
-    let forest_db_path = match xdg_dirs.find_config_file(SQLITE_DB_FILE_NAME) {
-        Some(path) => path,
-        None => match xdg_dirs.place_config_file(SQLITE_DB_FILE_NAME) {
-            Ok(path) => path,
-            Err(e) => panic!("Cannot create forest store file: {e}"),
+/// This function may panic if the directory storing forests cannot be read
+pub(super) fn get_profile_names() -> Vec<String> {
+    let profiles_dir = get_config_home().join(FOREST_PROFILE_SUBDIR);
+
+    let mut names: Vec<String> = match fs::read_dir(&profiles_dir) {
+        Ok(entries) => entries
+            .filter_map(Result::ok)
+            .filter(|entry| entry.path().is_dir())
+            .filter_map(|entry| entry.file_name().into_string().ok())
+            .collect(),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+        Err(e) => panic!("Cannot read '{}': {e}", profiles_dir.display()),
+    };
+    names.sort();
+    names.insert(0, String::from(DEFAULT_PROFILE));
+    names
+}
+
+/// Returns the name of the forest used by default, as set by `forest profile use`
+pub(super) fn get_active_profile() -> String {
+    get_xdg_dirs()
+        .find_config_file(FOREST_PROFILE_FILE_NAME)
+        .and_then(|path| fs::read_to_string(path).ok())
+        .map(|name| name.trim().to_string())
+        .filter(|name| get_profile_dir(name).is_none_or(|dir| dir.is_dir()))
+        .unwrap_or(String::from(DEFAULT_PROFILE))
+}
+
+/// Sets the forest used by default
+///
+/// # Errors
+/// Returns an error if the setting cannot be written
+pub(super) fn set_active_profile(name: &str) -> Result<(), Box<dyn Error>> {
+    let xdg_dirs = get_xdg_dirs();
+    if name == DEFAULT_PROFILE {
+        if let Some(path) = xdg_dirs.find_config_file(FOREST_PROFILE_FILE_NAME) {
+            fs::remove_file(path)?;
+        }
+    } else {
+        fs::write(xdg_dirs.place_config_file(FOREST_PROFILE_FILE_NAME)?, name)?;
+    }
+    Ok(())
+}
+
+/// Selects the forest to use for this run, whatever the environment and the forest used by default
+pub(super) fn select_profile(name: String) {
+    let _ = SELECTED_PROFILE.set(name);
+}
+
+/// Returns the name of the forest in use, or `None` if it was chosen through environment variables
+pub(super) fn get_current_profile() -> Option<String> {
+    match SELECTED_PROFILE.get() {
+        Some(name) => Some(name.clone()),
+        None if get_env_path(FOREST_DB_VAR).is_some()
+            || get_env_path(FOREST_HOME_VAR).is_some() =>
+        {
+            None
+        }
+        None => Some(get_active_profile()),
+    }
+}
+
+/// Returns the directory storing the database and the notes of the forest in use, or `None` if it
+/// is the default forest, stored in the config directory
+fn get_forest_dir() -> Option<PathBuf> {
+    match get_current_profile() {
+        Some(name) => get_profile_dir(&name),
+        None => match get_env_path(FOREST_DB_VAR) {
+            Some(db_path) => Some(db_path.parent().map(PathBuf::from).unwrap_or_default()),
+            None => get_env_path(FOREST_HOME_VAR),
         },
+    }
+}
+
+/// Returns the path to the given file of the forest in use, creating its directory if needed
+///
+/// Returns `None` if the directory cannot be created.
+fn get_forest_file_path(file_name: &str) -> Option<PathBuf> {
+    let Some(forest_dir) = get_forest_dir() else {
+        let xdg_dirs = get_xdg_dirs();
+        return match xdg_dirs.find_config_file(file_name) {
+            Some(path) => Some(path),
+            None => xdg_dirs.place_config_file(file_name).ok(),
+        };
     };
 
+    let path = forest_dir.join(file_name);
+    fs::create_dir_all(path.parent()?).ok()?;
+    Some(path)
+}
+
+/// Migrations bringing the database schema up to date, numbered by schema version
+pub(super) static MIGRATOR: Migrator = sqlx::migrate!();
+
+/// Returns the path to the database file, creating its directory if needed
+///
+/// # Panics
+/// This function may panic if db file cannot be located
+pub(super) fn get_db_path() -> PathBuf {
+    if get_current_profile().is_none() {
+        if let Some(db_path) = get_env_path(FOREST_DB_VAR) {
+            if let Some(dir) = db_path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+                if let Err(e) = fs::create_dir_all(dir) {
+                    panic!("Cannot create forest store file: {e}");
+                }
+            }
+            return db_path;
+        }
+    }
+
+    get_forest_file_path(SQLITE_DB_FILE_NAME).expect("Cannot create forest store file")
+}
+
+/// Connects to the database, without migrating it
+///
+/// # Panics
+/// This function may panic if db file cannot be located or if connection to db failed
+pub(super) async fn connect() -> sqlx::Pool<sqlx::Sqlite> {
     // database connection options
     let options = sqlx::sqlite::SqliteConnectOptions::new()
-        .filename(forest_db_path)
+        .filename(get_db_path())
         .create_if_missing(true);
 
     // connect to database
-    let pool = sqlx::sqlite::SqlitePool::connect_with(options)
+    sqlx::sqlite::SqlitePool::connect_with(options)
         .await
-        .expect("Connection to database should succeed in this context");
+        .expect("Connection to database should succeed in this context")
+}
+
+/// Tries to access database, applying pending migrations first
+/// Returns a `sqlx::Pool` on success.
+/// # Panics
+/// This function may panic if db file cannot be located, if connection to db failed, or if the
+/// database cannot be migrated
+pub async fn load_db() -> sqlx::Pool<sqlx::Sqlite> {
+    let pool = connect().await;
 
-    create_tables_if_not_exist(&pool).await;
+    record_baseline_migration(&pool).await;
+
+    if let Err(e) = MIGRATOR.run(&pool).await {
+        panic!("{}", format_migrate_error(e));
+    }
 
     pool
 }
 
-/// Returns the name of the current tree stored in db.
+/// Records the initial migration as applied to a database created before migrations were
+/// introduced, whose schema is the one of this migration
+///
+/// # Panics
+/// This function may panic if database operations fail
+pub(super) async fn record_baseline_migration(pool: &sqlx::sqlite::SqlitePool) {
+    let mut transaction = pool
+        .begin()
+        .await
+        .expect("Beginning a transaction should succeed");
+
+    // databases whose migrations are recorded, or which are empty, are migrated as usual
+    let is_unversioned: bool = sqlx::query_scalar(
+        r#"
+        SELECT
+            NOT EXISTS (SELECT * FROM sqlite_master WHERE name = '_sqlx_migrations') AND
+            EXISTS (SELECT * FROM sqlite_master WHERE name = 'task');
+        "#,
+    )
+    .fetch_one(&mut *transaction)
+    .await
+    .unwrap_or_else(|e| panic!("Database query failed: {e}"));
+    if !is_unversioned {
+        return;
+    }
+
+    transaction
+        .ensure_migrations_table()
+        .await
+        .unwrap_or_else(|e| panic!("Database query failed: {e}"));
+
+    let baseline = MIGRATOR
+        .iter()
+        .next()
+        .expect("Initial migration should be embedded");
+    let query_result = sqlx::query(
+        r#"
+        INSERT INTO _sqlx_migrations(version, description, success, checksum, execution_time)
+        VALUES (?, ?, TRUE, ?, 0);
+        "#,
+    )
+    .bind(baseline.version)
+    .bind(&*baseline.description)
+    .bind(&*baseline.checksum)
+    .execute(&mut *transaction)
+    .await;
+
+    // error handling
+    if let Err(query_error) = query_result {
+        panic!("Database query failed: {query_error}");
+    }
+
+    transaction
+        .commit()
+        .await
+        .expect("Committing a transaction should succeed");
+}
+
+/// Returns a human-friendly explanation of why the database could not be migrated
+pub(super) fn format_migrate_error(error: MigrateError) -> String {
+    match error {
+        MigrateError::VersionMissing(version) => format!(
+            "Database schema version {version} is unknown to this version of forest, which may be too old"
+        ),
+        other_error => format!("Cannot migrate database: {other_error}"),
+    }
+}
+
+/// Tree, and optionally task, pinned by a `.forest` file to a directory and its subdirectories
+pub(super) struct DirectoryContext {
+    /// path of the `.forest` file
+    pub(super) path: PathBuf,
+    pub(super) tree_name: String,
+    /// uid of the pinned task, possibly partial
+    pub(super) task_uid: Option<String>,
+}
+
+/// Parses the content of a `.forest` file, returning the name of the pinned tree and the uid of
+/// the pinned task, if any
+///
+/// The file holds `tree = <name>` and `task = <uid>` lines, or only the name of the tree.
+/// Blank lines and lines starting with `#` are ignored.
+///
+/// # Errors
+/// Returns an error if the content is ill-formed
+fn parse_directory_context(content: &str) -> Result<(String, Option<String>), String> {
+    let mut tree_name = None;
+    let mut task_uid = None;
+    let lines = content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'));
+    for line in lines {
+        let (key, value) = match line.split_once('=') {
+            Some((key, value)) => (key.trim(), value.trim()),
+            // a bare name pins the tree
+            None => ("tree", line),
+        };
+        match key {
+            "tree" => tree_name = Some(forest_types::tree_name_parser(value)?),
+            "task" => task_uid = Some(forest_types::uid_parser(value)?),
+            _ => return Err(format!("unknown key '{key}'")),
+        }
+    }
+
+    match tree_name {
+        Some(tree_name) => Ok((tree_name, task_uid)),
+        None => Err(String::from("no tree given")),
+    }
+}
+
+/// Reads the tree and the task pinned by a `.forest` file
+///
+/// # Errors
+/// Returns an error if the file cannot be read or is ill-formed
+fn read_directory_context(path: PathBuf) -> Result<DirectoryContext, Box<dyn Error>> {
+    let content =
+        fs::read_to_string(&path).map_err(|e| format!("Cannot read {}: {e}", path.display()))?;
+
+    let (tree_name, task_uid) =
+        parse_directory_context(&content).map_err(|e| format!("{}: {e}", path.display()))?;
+    Ok(DirectoryContext {
+        path,
+        tree_name,
+        task_uid,
+    })
+}
+
+/// Returns the context pinned by the `.forest` file of the current directory or of its closest
+/// ancestor, if any
+///
+/// # Errors
+/// Returns an error if the `.forest` file found is ill-formed
+pub(super) fn find_directory_context() -> Result<Option<DirectoryContext>, Box<dyn Error>> {
+    // without a current directory, eg. when it was deleted, there is nothing to pin
+    let Ok(current_dir) = env::current_dir() else {
+        return Ok(None);
+    };
+
+    for dir in current_dir.ancestors() {
+        let path = dir.join(DIRECTORY_CONTEXT_FILE_NAME);
+        if path.is_file() {
+            return read_directory_context(path).map(Some);
+        }
+    }
+
+    Ok(None)
+}
+
+/// Returns the name of the current tree: the one pinned by a `.forest` file if any, otherwise
+/// the one stored in db.
 ///
 /// # Error
-/// Returns an error if there is no current tree (ie. the forest is empty)
+/// Returns an error if there is no current tree (ie. the forest is empty), or if the pinned tree
+/// does not exist
 ///
 /// # Panics
 /// This function may panic if connection to db fails
@@ -75,6 +378,31 @@ pub async fn get_current_tree_name(
         .await
         .expect("Acquiring connection to database should succeed");
 
+    // a tree pinned to the current directory takes precedence over `forest switch`
+    if let Some(context) = find_directory_context()? {
+        let tree_name = context.tree_name;
+        let record_optional = sqlx::query!(
+            r#"
+            SELECT name
+            FROM tree
+            WHERE name = ?;
+            "#,
+            tree_name,
+        )
+        .fetch_optional(&mut *conn)
+        .await
+        .expect("Database query should succeed.");
+
+        return match record_optional {
+            Some(_) => Ok(tree_name),
+            None => Err(format!(
+                "Tree '{tree_name}' pinned by {} not found",
+                context.path.display()
+            )
+            .into()),
+        };
+    }
+
     let record_optional = sqlx::query!(
         r#"
         SELECT 
@@ -114,19 +442,149 @@ pub async fn get_current_tree_name(
     }
 }
 
-pub fn get_note_path(uid: &Uid) -> Option<PathBuf> {
-    // get config directory
-    let xdg_dirs = xdg::BaseDirectories::with_prefix(FOREST_CONFIG_DIR)
-        .unwrap_or_else(|e| panic!("Cannot locate home directory: {e}"));
+/// Returns the first day of the week stored in db, monday by default
+///
+/// # Panics
+/// This function may panic if connection to db fails
+pub async fn get_week_start(pool: &sqlx::sqlite::SqlitePool) -> Weekday {
+    let mut conn = pool
+        .acquire()
+        .await
+        .expect("Acquiring connection to database should succeed");
+
+    let record_optional = sqlx::query!(
+        r#"
+        SELECT week_start
+        FROM state;
+        "#
+    )
+    .fetch_optional(&mut *conn)
+    .await
+    .expect("Database query should succeed.");
 
+    record_optional
+        .and_then(|record| record.week_start.parse().ok())
+        .unwrap_or(Weekday::Mon)
+}
+
+pub fn get_note_path(uid: &Uid) -> Option<PathBuf> {
     let file_name = format!("{FOREST_NOTE_SUBDIR}/{uid}.md");
 
-    // get path to database file
-    match xdg_dirs.find_config_file(&file_name) {
-        Some(path) => Some(path),
-        None => match xdg_dirs.place_config_file(&file_name) {
-            Ok(path) => Some(path),
-            Err(_) => None,
-        },
+    get_forest_file_path(&file_name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn migrates_baseline_databases() {
+        // a single connection which is kept open, as each connection opens its own in-memory db
+        let pool = sqlx::sqlite::SqlitePoolOptions::new()
+            .max_connections(1)
+            .idle_timeout(None)
+            .max_lifetime(None)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+
+        // forest created before migrations were introduced, with a running frame
+        sqlx::raw_sql(include_str!("../../migrations/0001_initial.sql"))
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::raw_sql(
+            r#"
+            INSERT INTO tree(name, description) VALUES ('work', 'daily work');
+            INSERT INTO task(id, tree_name, "left", "right", name, description)
+            VALUES
+                ('00000000000000000000000000000001', 'work', 1, 4, 'work', ''),
+                ('00000000000000000000000000000002', 'work', 2, 3, 'report', 'weekly');
+            UPDATE state SET current_tree = 'work';
+            INSERT INTO frame(id, "start", "end", task_id)
+            VALUES
+                ('00000000000000000000000000000010', 1000, 2000, '00000000000000000000000000000002'),
+                ('00000000000000000000000000000011', 3000, NULL, '00000000000000000000000000000002');
+            INSERT INTO note(id, date, task_id, time_tracking)
+            VALUES ('00000000000000000000000000000020', 1500, '00000000000000000000000000000002', 1);
+            "#,
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        record_baseline_migration(&pool).await;
+        MIGRATOR.run(&pool).await.unwrap();
+
+        let versions: Vec<i64> =
+            sqlx::query_scalar("SELECT version FROM _sqlx_migrations ORDER BY version;")
+                .fetch_all(&pool)
+                .await
+                .unwrap();
+        let expected_versions: Vec<i64> = MIGRATOR.iter().map(|m| m.version).collect();
+        assert_eq!(versions, expected_versions);
+
+        // data is kept, and new columns take their default values
+        let tasks: Vec<(String, String, String)> =
+            sqlx::query_as("SELECT name, description, status FROM task ORDER BY \"left\";")
+                .fetch_all(&pool)
+                .await
+                .unwrap();
+        assert_eq!(
+            tasks,
+            vec![
+                ("work".into(), "".into(), "todo".into()),
+                ("report".into(), "weekly".into(), "todo".into())
+            ]
+        );
+        let state: (String, String) = sqlx::query_as("SELECT current_tree, week_start FROM state;")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(state, ("work".into(), "Mon".into()));
+        let frames: Vec<(i64, Option<i64>, bool)> =
+            sqlx::query_as(r#"SELECT "start", "end", paused FROM frame ORDER BY "start";"#)
+                .fetch_all(&pool)
+                .await
+                .unwrap();
+        assert_eq!(frames, vec![(1000, Some(2000), false), (3000, None, false)]);
+        let note_frame: Option<String> = sqlx::query_scalar("SELECT frame_id FROM note;")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(note_frame, None);
+    }
+
+    #[test]
+    fn parses_directory_contexts() {
+        assert_eq!(
+            parse_directory_context("website\n"),
+            Ok((String::from("website"), None))
+        );
+        assert_eq!(
+            parse_directory_context(
+                "# pinned by the website repository\n\
+                 \n\
+                 tree = website\n\
+                 task=3fa9c2e\n"
+            ),
+            Ok((String::from("website"), Some(String::from("3fa9c2e"))))
+        );
+    }
+
+    #[test]
+    fn rejects_ill_formed_directory_contexts() {
+        assert_eq!(
+            parse_directory_context("tree = website\nproject = forest\n"),
+            Err(String::from("unknown key 'project'"))
+        );
+        assert_eq!(
+            parse_directory_context(""),
+            Err(String::from("no tree given"))
+        );
+        assert_eq!(
+            parse_directory_context("# tree = website\ntask = 3fa9c2e\n"),
+            Err(String::from("no tree given"))
+        );
     }
 }
@@ -0,0 +1,283 @@
+use chrono::{DateTime, Utc};
+use std::error::Error;
+use std::fs;
+
+use super::dbutils;
+use super::task;
+use super::timetracking;
+use forest_types::{TaskStatus, Uid};
+
+/// Maximum number of characters of a note kept in the description of an event
+const NOTE_EXCERPT_LENGTH: usize = 280;
+
+/// Maximum length of a line of an iCalendar file, in bytes
+const ICS_LINE_LENGTH: usize = 75;
+
+/// Returns a timestamp in milliseconds as an iCalendar date and time in UTC
+fn format_ics_datetime(timestamp: i64) -> String {
+    DateTime::from_timestamp_millis(timestamp)
+        .unwrap_or_default()
+        .format("%Y%m%dT%H%M%SZ")
+        .to_string()
+}
+
+/// Escapes a text so that it can be used as an iCalendar property value
+fn escape_ics_text(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace("\r\n", "\\n")
+        .replace('\n', "\\n")
+}
+
+/// Appends a content line to an iCalendar file, folding it into lines of at most 75 bytes
+fn push_ics_line(ics: &mut String, name: &str, value: &str) {
+    let line = format!("{name}:{value}");
+    let mut length = 0;
+    for character in line.chars() {
+        if length + character.len_utf8() > ICS_LINE_LENGTH {
+            // continuation lines start with a space, which counts in their length
+            ics.push_str("\r\n ");
+            length = 1;
+        }
+        ics.push(character);
+        length += character.len_utf8();
+    }
+    ics.push_str("\r\n");
+}
+
+/// Returns the title of an event or a to-do of a tree or of a task
+fn summary(tree_name: &str, path: &[String]) -> String {
+    if path.is_empty() {
+        tree_name.to_string()
+    } else {
+        format!("{tree_name}: {}", path.join(" > "))
+    }
+}
+
+/// Returns the beginning of the content of a note, or `None` if the note is empty or missing
+fn note_excerpt(note_id: String) -> Option<String> {
+    let note_uid = Uid::try_from(note_id).ok()?;
+    let content = fs::read_to_string(dbutils::get_note_path(&note_uid)?).ok()?;
+    let content = content.trim();
+    if content.is_empty() {
+        return None;
+    }
+
+    let mut excerpt: String = content.chars().take(NOTE_EXCERPT_LENGTH).collect();
+    if excerpt.len() < content.len() {
+        excerpt.push('…');
+    }
+    Some(excerpt)
+}
+
+/// Exports time tracking frames as events and due dates of tasks as to-dos, in the iCalendar
+/// format
+///
+/// Events are titled after the tree or the task on which time was spent, and described by the
+/// notes written when their recording stopped. Frames still being recorded are not exported. With
+/// `from` and `to`, only frames overlapping this range and tasks due within it are exported.
+///
+/// # Errors
+/// Returns an error if the given dates and times are ill-formed
+///
+/// # Panics
+/// This function may panic if database operations fail
+pub async fn ics(from: Option<String>, to: Option<String>) -> Result<(), Box<dyn Error>> {
+    let from = match from {
+        Some(from) => timetracking::parse_user_datetime(&Some(from))?.timestamp_millis(),
+        None => i64::MIN,
+    };
+    let to = match to {
+        Some(to) => timetracking::parse_user_datetime(&Some(to))?.timestamp_millis(),
+        None => i64::MAX,
+    };
+    let now = format_ics_datetime(Utc::now().timestamp_millis());
+
+    let pool = dbutils::load_db().await;
+
+    let mut conn = pool
+        .acquire()
+        .await
+        .expect("Acquiring connection to database should succeed");
+
+    let mut ics = String::new();
+    push_ics_line(&mut ics, "BEGIN", "VCALENDAR");
+    push_ics_line(&mut ics, "VERSION", "2.0");
+    push_ics_line(
+        &mut ics,
+        "PRODID",
+        &format!("-//forest//forest {}//EN", env!("CARGO_PKG_VERSION")),
+    );
+    push_ics_line(&mut ics, "CALSCALE", "GREGORIAN");
+
+    let query_result = sqlx::query!(
+        r#"
+        SELECT f.id, f."start", f."end" AS "end!: i64", t.id AS task_id, t.tree_name
+        FROM frame f INNER JOIN task t ON f.task_id = t.id
+        WHERE
+            f."end" IS NOT NULL AND
+            f."start" < ? AND f."end" > ?
+        ORDER BY f."start";
+        "#,
+        to,
+        from,
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    // error handling
+    let frames = match query_result {
+        Ok(records) => records,
+        Err(query_error) => panic!("Database query failed: {query_error}"),
+    };
+
+    for frame in frames {
+        let path = task::path(&mut conn, &frame.task_id).await;
+
+        let query_result = sqlx::query!(
+            r#"
+            SELECT id
+            FROM note
+            WHERE frame_id = ?
+            ORDER BY date;
+            "#,
+            frame.id,
+        )
+        .fetch_all(&mut *conn)
+        .await;
+
+        // error handling
+        let notes = match query_result {
+            Ok(records) => records,
+            Err(query_error) => panic!("Database query failed: {query_error}"),
+        };
+        let excerpts: Vec<String> = notes
+            .into_iter()
+            .filter_map(|note| note_excerpt(note.id))
+            .collect();
+
+        push_ics_line(&mut ics, "BEGIN", "VEVENT");
+        push_ics_line(&mut ics, "UID", &format!("frame-{}@forest", frame.id));
+        push_ics_line(&mut ics, "DTSTAMP", &now);
+        push_ics_line(&mut ics, "DTSTART", &format_ics_datetime(frame.start));
+        push_ics_line(&mut ics, "DTEND", &format_ics_datetime(frame.end));
+        push_ics_line(
+            &mut ics,
+            "SUMMARY",
+            &escape_ics_text(&summary(&frame.tree_name, &path)),
+        );
+        if !excerpts.is_empty() {
+            push_ics_line(
+                &mut ics,
+                "DESCRIPTION",
+                &escape_ics_text(&excerpts.join("\n\n")),
+            );
+        }
+        push_ics_line(&mut ics, "END", "VEVENT");
+    }
+
+    let query_result = sqlx::query!(
+        r#"
+        SELECT id, tree_name, description, status, due AS "due!: i64"
+        FROM task
+        WHERE
+            "left" != 1 AND
+            due IS NOT NULL AND
+            ? <= due AND due < ?
+        ORDER BY due;
+        "#,
+        from,
+        to,
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    // error handling
+    let tasks = match query_result {
+        Ok(records) => records,
+        Err(query_error) => panic!("Database query failed: {query_error}"),
+    };
+
+    for task in tasks {
+        let path = task::path(&mut conn, &task.id).await;
+        let status = match TaskStatus::try_from(task.status)? {
+            TaskStatus::Todo => "NEEDS-ACTION",
+            TaskStatus::InProgress => "IN-PROCESS",
+            TaskStatus::Done => "COMPLETED",
+            TaskStatus::Cancelled => "CANCELLED",
+        };
+
+        push_ics_line(&mut ics, "BEGIN", "VTODO");
+        push_ics_line(&mut ics, "UID", &format!("task-{}@forest", task.id));
+        push_ics_line(&mut ics, "DTSTAMP", &now);
+        push_ics_line(&mut ics, "DUE", &format_ics_datetime(task.due));
+        push_ics_line(&mut ics, "STATUS", status);
+        push_ics_line(
+            &mut ics,
+            "SUMMARY",
+            &escape_ics_text(&summary(&task.tree_name, &path)),
+        );
+        if !task.description.trim().is_empty() {
+            push_ics_line(
+                &mut ics,
+                "DESCRIPTION",
+                &escape_ics_text(task.description.trim()),
+            );
+        }
+        push_ics_line(&mut ics, "END", "VTODO");
+    }
+
+    push_ics_line(&mut ics, "END", "VCALENDAR");
+    print!("{ics}");
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escapes_ics_text() {
+        assert_eq!(escape_ics_text("a\\b; c, d\r\ne\nf"), r"a\\b\; c\, d\ne\nf");
+    }
+
+    #[test]
+    fn keeps_short_ics_lines() {
+        let mut ics = String::new();
+        push_ics_line(&mut ics, "SUMMARY", "short");
+        assert_eq!(ics, "SUMMARY:short\r\n");
+    }
+
+    #[test]
+    fn folds_long_ics_lines() {
+        let mut ics = String::new();
+        push_ics_line(&mut ics, "DESCRIPTION", &"a".repeat(200));
+
+        let lines: Vec<&str> = ics.strip_suffix("\r\n").unwrap().split("\r\n").collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines.iter().all(|line| line.len() <= ICS_LINE_LENGTH));
+        assert_eq!(lines[0].len(), ICS_LINE_LENGTH);
+        assert!(lines[1..].iter().all(|line| line.starts_with(' ')));
+
+        // unfolding gives back the content line
+        let unfolded = ics.replace("\r\n ", "");
+        assert_eq!(unfolded, format!("DESCRIPTION:{}\r\n", "a".repeat(200)));
+    }
+
+    #[test]
+    fn folds_ics_lines_between_characters() {
+        // "é" is two bytes long, so the first line cannot end exactly at 75 bytes
+        let mut ics = String::new();
+        push_ics_line(&mut ics, "SUMMARY", &"é".repeat(50));
+
+        let lines: Vec<&str> = ics.strip_suffix("\r\n").unwrap().split("\r\n").collect();
+        assert!(lines.iter().all(|line| line.len() <= ICS_LINE_LENGTH));
+        assert_eq!(lines[0].len(), ICS_LINE_LENGTH - 1);
+        assert_eq!(
+            ics.replace("\r\n ", ""),
+            format!("SUMMARY:{}\r\n", "é".repeat(50))
+        );
+    }
+}
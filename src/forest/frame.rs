@@ -0,0 +1,943 @@
+use chrono::Local;
+use serde::Serialize;
+use sqlx::SqliteConnection;
+use std::error::Error;
+
+use super::ansi;
+use super::dbutils;
+use super::output::{self, Render};
+use super::task;
+use super::timetracking;
+use forest_types::{OutputFormat, Uid};
+
+/// Finds a UID in frame table that matches the provided partial uid
+///
+/// # Errors
+/// Returns an error if no uid is found or if more than one uid fits the partial uid
+///
+/// # Panic
+/// This function may panic if db operations fail
+async fn find_uid_from_partial(partial_uid: &String) -> Result<Uid, Box<dyn Error>> {
+    let pool = dbutils::load_db().await;
+
+    let mut conn = pool
+        .acquire()
+        .await
+        .expect("Acquiring connection to database should succeed");
+
+    // get all uids that match the provided short uid
+    let query_result = sqlx::query!(
+        r#"
+        SELECT id
+        FROM frame
+        WHERE id LIKE ? || '%';
+        "#,
+        partial_uid,
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    match query_result {
+        // Database error
+        Err(query_error) => panic!("Database query failed: {query_error}"),
+
+        // Query succeeded
+        Ok(mut records) => {
+            // if no frame matching short uid was found
+            if records.is_empty() {
+                Err(format!("Frame '{partial_uid}' not found").into())
+
+                // if more than one frame matches the short uid
+            } else if records.len() > 1 {
+                let mut error_message = format!("At least two frames match '{partial_uid}...':\n");
+                for frame in records {
+                    error_message.push_str(&format!("- {}\n", frame.id));
+                }
+                error_message
+                    .push_str("Please try to be more precise when refering to frame uids\n");
+                Err(error_message.into())
+
+            // if the short uid only matched a single uid
+            } else {
+                let matching_record = records.pop().expect(
+                    "There should be exactly one record in the records vector at this point",
+                );
+
+                Ok(Uid::try_from(matching_record.id)?)
+            }
+        }
+    }
+}
+
+/// Parses a user datetime into a timestamp in milliseconds
+///
+/// # Errors
+/// Returns an error if the input string is ill-formed
+fn parse_timestamp(datetime: String) -> Result<i64, Box<dyn Error>> {
+    Ok(timetracking::parse_user_datetime(&Some(datetime))?.timestamp_millis())
+}
+
+/// Returns a human-friendly representation of the start and end of a frame
+///
+/// Times are shown at the offset from UTC at which the frame was recorded, if any, and this offset
+/// is shown when it differs from the current one. The date of the end is omitted if it is the same
+/// as the date of the start.
+pub(super) fn format_span(start: i64, end: Option<i64>, utc_offset: Option<i64>) -> String {
+    let start = output::at_offset(start, utc_offset);
+    let end = end.map(|end| output::at_offset(end, utc_offset));
+
+    let mut span = format!(
+        "{} {} - ",
+        ansi::format(
+            &start.format("%Y-%m-%d").to_string(),
+            ansi::ForestFormat::Date
+        ),
+        ansi::format(&start.format("%H:%M").to_string(), ansi::ForestFormat::Time),
+    );
+    match end {
+        Some(end) => {
+            if end.date_naive() != start.date_naive() {
+                span.push_str(&ansi::format(
+                    &end.format("%Y-%m-%d ").to_string(),
+                    ansi::ForestFormat::Date,
+                ));
+            }
+            span.push_str(&ansi::format(
+                &end.format("%H:%M").to_string(),
+                ansi::ForestFormat::Time,
+            ));
+        }
+        None => span.push_str("..."),
+    }
+    if start.offset().local_minus_utc() != Local::now().offset().local_minus_utc() {
+        span.push_str(&format!(" UTC{}", start.format("%:z")));
+    }
+    span
+}
+
+/// Frame stored in database
+struct Frame {
+    id: String,
+    start: i64,
+    end: Option<i64>,
+    task_id: String,
+    session_id: Option<String>,
+    paused: bool,
+    utc_offset: Option<i64>,
+}
+
+/// Returns the frame of the given uid
+///
+/// # Errors
+/// Returns an error if the frame does not exist
+///
+/// # Panics
+/// This function may panic if database operations fail
+async fn get_frame(conn: &mut SqliteConnection, uid: &Uid) -> Result<Frame, Box<dyn Error>> {
+    let query_result = sqlx::query_as!(
+        Frame,
+        r#"
+        SELECT id, "start", "end", task_id, session_id, paused AS "paused: bool", utc_offset
+        FROM frame
+        WHERE id = ?;
+        "#,
+        uid,
+    )
+    .fetch_one(&mut *conn)
+    .await;
+
+    match query_result {
+        Ok(frame) => Ok(frame),
+        Err(query_error) => match query_error {
+            sqlx::Error::RowNotFound => Err(format!("Frame '{uid}' not found").into()),
+            other_error => panic!("Database query failed: {other_error}"),
+        },
+    }
+}
+
+/// Returns the frames overlapping the given span, except the excluded ones
+///
+/// A running frame, or a span without end, is considered to last indefinitely.
+///
+/// # Panics
+/// This function may panic if database operations fail
+async fn get_overlapping(
+    conn: &mut SqliteConnection,
+    start: i64,
+    end: Option<i64>,
+    excluded: &[&String],
+) -> Vec<Frame> {
+    let end = end.unwrap_or(i64::MAX);
+    let query_result = sqlx::query_as!(
+        Frame,
+        r#"
+        SELECT id, "start", "end", task_id, session_id, paused AS "paused: bool", utc_offset
+        FROM frame
+        WHERE "start" < ? AND COALESCE("end", ?) > ?
+        ORDER BY "start";
+        "#,
+        end,
+        i64::MAX,
+        start,
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    match query_result {
+        Ok(frames) => frames
+            .into_iter()
+            .filter(|frame| !excluded.contains(&&frame.id))
+            .collect(),
+        Err(query_error) => panic!("Database query failed: {query_error}"),
+    }
+}
+
+/// Makes sure a frame spanning from `start` to `end` does not overlap any other frame
+///
+/// If `adjust` is set, overlapping frames are trimmed, split or removed so that the span is left
+/// free. Frames in `excluded` are ignored, typically the frames being edited.
+///
+/// # Errors
+/// Returns an error listing the overlapping frames if there are any and `adjust` is not set
+///
+/// # Panics
+/// This function may panic if database operations fail
+pub(super) async fn resolve_overlaps(
+    conn: &mut SqliteConnection,
+    start: i64,
+    end: Option<i64>,
+    excluded: &[&String],
+    adjust: bool,
+) -> Result<(), Box<dyn Error>> {
+    let overlapping = get_overlapping(conn, start, end, excluded).await;
+    if overlapping.is_empty() {
+        return Ok(());
+    }
+
+    if !adjust {
+        let mut error_message = format!(
+            "The frame ({}) would overlap the following frames:\n",
+            format_span(start, end, None)
+        );
+        for frame in overlapping {
+            error_message.push_str(&format!(
+                "- {} ({})\n",
+                ansi::format(Uid::try_from(frame.id)?.short(), ansi::ForestFormat::Uid),
+                format_span(frame.start, frame.end, frame.utc_offset),
+            ));
+        }
+        error_message.push_str("Use --adjust to trim them");
+        return Err(error_message.into());
+    }
+
+    let span_end = end.unwrap_or(i64::MAX);
+    for frame in overlapping {
+        let uid = Uid::try_from(frame.id.clone())?;
+        let frame_end = frame.end.unwrap_or(i64::MAX);
+
+        if start <= frame.start && frame_end <= span_end {
+            // the frame is entirely covered by the span
+            let query_result = sqlx::query!(
+                r#"
+                DELETE FROM frame
+                WHERE id = ?;
+                "#,
+                uid,
+            )
+            .execute(&mut *conn)
+            .await;
+
+            if let Err(query_error) = query_result {
+                panic!("Database query failed: {query_error}");
+            }
+
+            println!(
+                "Removed frame {} ({})",
+                ansi::format(uid.short(), ansi::ForestFormat::Uid),
+                format_span(frame.start, frame.end, frame.utc_offset),
+            );
+        } else if frame.start < start && span_end < frame_end {
+            // the span is entirely within the frame, which is split around it
+            let query_result = sqlx::query!(
+                r#"
+                UPDATE frame
+                SET "end" = ?, paused = 0
+                WHERE id = ?;
+                "#,
+                start,
+                uid,
+            )
+            .execute(&mut *conn)
+            .await;
+
+            if let Err(query_error) = query_result {
+                panic!("Database query failed: {query_error}");
+            }
+
+            // a new frame of the same session becomes the second part
+            let new_frame_uid = Uid::new();
+            let session_id = frame.session_id.unwrap_or(frame.id);
+            let query_result = sqlx::query!(
+                r#"
+                INSERT INTO frame("id", "start", "end", "task_id", "session_id", "paused", "utc_offset")
+                VALUES(?, ?, ?, ?, ?, ?, ?);
+                "#,
+                new_frame_uid,
+                span_end,
+                frame.end,
+                frame.task_id,
+                session_id,
+                frame.paused,
+                frame.utc_offset,
+            )
+            .execute(&mut *conn)
+            .await;
+
+            if let Err(query_error) = query_result {
+                panic!("Database query failed: {query_error}");
+            }
+
+            println!(
+                "Split frame {} into {} ({}) and {} ({})",
+                ansi::format(uid.short(), ansi::ForestFormat::Uid),
+                ansi::format(uid.short(), ansi::ForestFormat::Uid),
+                format_span(frame.start, Some(start), frame.utc_offset),
+                ansi::format(new_frame_uid.short(), ansi::ForestFormat::Uid),
+                format_span(span_end, frame.end, frame.utc_offset),
+            );
+        } else {
+            // the frame overlaps either the start or the end of the span
+            let (new_start, new_end) = if frame.start < start {
+                (frame.start, Some(start))
+            } else {
+                (span_end, frame.end)
+            };
+
+            let query_result = sqlx::query!(
+                r#"
+                UPDATE frame
+                SET "start" = ?, "end" = ?
+                WHERE id = ?;
+                "#,
+                new_start,
+                new_end,
+                uid,
+            )
+            .execute(&mut *conn)
+            .await;
+
+            if let Err(query_error) = query_result {
+                panic!("Database query failed: {query_error}");
+            }
+
+            println!(
+                "Trimmed frame {} to {}",
+                ansi::format(uid.short(), ansi::ForestFormat::Uid),
+                format_span(new_start, new_end, frame.utc_offset),
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Makes sure a frame ends after it starts
+///
+/// # Errors
+/// Returns an error if the frame does not end after it starts
+fn validate_span(start: i64, end: Option<i64>) -> Result<(), Box<dyn Error>> {
+    if end.is_some_and(|end| end <= start) {
+        return Err("The end of a frame should be after its start".into());
+    }
+    Ok(())
+}
+
+/// Listing of time tracking frames
+#[derive(Serialize)]
+struct FrameList {
+    frames: Vec<FrameListItem>,
+}
+
+/// Frame of a listing
+#[derive(Serialize)]
+struct FrameListItem {
+    uid: String,
+    tree: String,
+    task: Option<String>,
+    path: Vec<String>,
+    start: String,
+    end: Option<String>,
+    seconds: i64,
+    overlaps: bool,
+
+    #[serde(skip)]
+    timestamps: (i64, Option<i64>),
+    #[serde(skip)]
+    utc_offset: Option<i64>,
+}
+
+/// Flat record of a frame listing
+#[derive(Serialize)]
+struct FrameListRow {
+    uid: String,
+    tree: String,
+    task: Option<String>,
+    path: String,
+    start: String,
+    end: Option<String>,
+    seconds: i64,
+    overlaps: bool,
+}
+
+impl Render for FrameList {
+    type Row = FrameListRow;
+
+    fn print_text(&self) {
+        for frame in &self.frames {
+            print!(
+                "{} {} ({}) {}",
+                ansi::format(
+                    Uid::try_from(frame.uid.clone()).unwrap().short(),
+                    ansi::ForestFormat::Uid
+                ),
+                format_span(frame.timestamps.0, frame.timestamps.1, frame.utc_offset),
+                timetracking::format_duration(frame.seconds),
+                timetracking::format_target(&frame.tree, &frame.path),
+            );
+            if frame.overlaps {
+                print!(
+                    " {}",
+                    ansi::format("(overlaps)", ansi::ForestFormat::Blocked)
+                );
+            }
+            println!();
+        }
+    }
+
+    fn rows(&self) -> Vec<Self::Row> {
+        self.frames
+            .iter()
+            .map(|frame| FrameListRow {
+                uid: frame.uid.clone(),
+                tree: frame.tree.clone(),
+                task: frame.task.clone(),
+                path: frame.path.join(" > "),
+                start: frame.start.clone(),
+                end: frame.end.clone(),
+                seconds: frame.seconds,
+                overlaps: frame.overlaps,
+            })
+            .collect()
+    }
+}
+
+/// Lists time tracking frames, most recent first
+///
+/// Only frames of the given tree are listed if `tree_name` is provided, and only frames
+/// overlapping the given period if `from` or `to` are provided.
+///
+/// # Errors
+/// Returns an error if a date is ill-formed
+///
+/// # Panics
+/// This function may panic if database operations fail
+pub async fn list(
+    tree_name: Option<String>,
+    from: Option<String>,
+    to: Option<String>,
+    output: OutputFormat,
+) -> Result<(), Box<dyn Error>> {
+    let from = match from {
+        Some(from) => parse_timestamp(from)?,
+        None => i64::MIN,
+    };
+    let to = match to {
+        Some(to) => parse_timestamp(to)?,
+        None => i64::MAX,
+    };
+    let now = Local::now().timestamp_millis();
+
+    let pool = dbutils::load_db().await;
+
+    let mut conn = pool
+        .acquire()
+        .await
+        .expect("Acquiring connection to database should succeed");
+
+    let query_result = sqlx::query!(
+        r#"
+        SELECT
+            f.id, f."start", f."end", f.utc_offset, t.id AS task_id, t.tree_name, t."left",
+            EXISTS (
+                SELECT *
+                FROM frame o
+                WHERE
+                    o.id != f.id AND
+                    o."start" < COALESCE(f."end", ?) AND COALESCE(o."end", ?) > f."start"
+            ) AS "overlaps: bool"
+        FROM frame f INNER JOIN task t ON f.task_id = t.id
+        WHERE
+            (? IS NULL OR t.tree_name = ?) AND
+            f."start" < ? AND COALESCE(f."end", ?) > ?
+        ORDER BY f."start" DESC;
+        "#,
+        now,
+        now,
+        tree_name,
+        tree_name,
+        to,
+        now,
+        from,
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    // error handling
+    let records = match query_result {
+        Ok(records) => records,
+        Err(query_error) => panic!("Database query failed: {query_error}"),
+    };
+
+    let mut frames = Vec::with_capacity(records.len());
+    for frame in records {
+        frames.push(FrameListItem {
+            path: task::path(&mut conn, &frame.task_id).await,
+            uid: frame.id,
+            tree: frame.tree_name,
+            task: (frame.left != 1).then_some(frame.task_id),
+            start: output::at_offset(frame.start, frame.utc_offset).to_rfc3339(),
+            end: frame
+                .end
+                .map(|end| output::at_offset(end, frame.utc_offset).to_rfc3339()),
+            seconds: (frame.end.unwrap_or(now) - frame.start) / 1000,
+            overlaps: frame.overlaps,
+            timestamps: (frame.start, frame.end),
+            utc_offset: frame.utc_offset,
+        });
+    }
+
+    output::print(&FrameList { frames }, output)
+}
+
+/// Adds a time tracking frame on a tree or on a task
+///
+/// `target` may either be the name of a tree or the uid of a task, as for `start`. Overlapping
+/// frames are trimmed if `adjust` is set.
+///
+/// # Errors
+/// Returns an error if a date is ill-formed, if the frame does not end after it starts, if the
+/// given tree or task does not exist in forest, or if the frame overlaps other frames
+///
+/// # Panics
+/// This function may panic if database operations fail
+pub async fn add(
+    target: Option<String>,
+    task_uid: Option<String>,
+    start: String,
+    end: String,
+    adjust: bool,
+) -> Result<(), Box<dyn Error>> {
+    let start = parse_timestamp(start)?;
+    let end = parse_timestamp(end)?;
+    validate_span(start, Some(end))?;
+
+    let pool = dbutils::load_db().await;
+
+    let (task_id, tree_name) = timetracking::resolve_target(&pool, target, task_uid).await?;
+
+    let mut transaction = pool
+        .begin()
+        .await
+        .expect("Beginning a transaction should succeed");
+
+    resolve_overlaps(&mut transaction, start, Some(end), &[], adjust).await?;
+
+    let new_frame_uid = Uid::new();
+    let utc_offset = output::local_utc_offset(start);
+    let query_result = sqlx::query!(
+        r#"
+        INSERT INTO frame("id", "start", "end", "task_id", "utc_offset")
+        VALUES(?, ?, ?, ?, ?);
+        "#,
+        new_frame_uid,
+        start,
+        end,
+        task_id,
+        utc_offset,
+    )
+    .execute(&mut *transaction)
+    .await;
+
+    // error handling
+    if let Err(query_error) = query_result {
+        panic!("Database query failed: {query_error}");
+    }
+
+    let path = task::path(&mut transaction, &task_id).await;
+
+    transaction
+        .commit()
+        .await
+        .expect("Committing a transaction should succeed");
+
+    println!(
+        "Added frame {} ({}) on {}",
+        ansi::format(new_frame_uid.short(), ansi::ForestFormat::Uid),
+        format_span(start, Some(end), Some(utc_offset)),
+        timetracking::format_target(&tree_name, &path),
+    );
+
+    Ok(())
+}
+
+/// Edits the start, the end, or the tree or task of a time tracking frame
+///
+/// Overlapping frames are trimmed if `adjust` is set.
+///
+/// # Errors
+/// Returns an error if the frame does not exist, if a date is ill-formed, if the frame would not
+/// end after it starts, if the given tree or task does not exist in forest, or if the frame would
+/// overlap other frames
+///
+/// # Panics
+/// This function may panic if database operations fail
+pub async fn edit(
+    partial_uid: &String,
+    start: Option<String>,
+    end: Option<String>,
+    tree_name: Option<String>,
+    task_uid: Option<String>,
+    adjust: bool,
+) -> Result<(), Box<dyn Error>> {
+    let pool = dbutils::load_db().await;
+
+    let uid = find_uid_from_partial(partial_uid).await?;
+
+    let mut transaction = pool
+        .begin()
+        .await
+        .expect("Beginning a transaction should succeed");
+
+    let frame = get_frame(&mut transaction, &uid).await?;
+
+    // a new start is recorded at the current offset from UTC
+    let (start, utc_offset) = match start {
+        Some(start) => {
+            let start = parse_timestamp(start)?;
+            (start, Some(output::local_utc_offset(start)))
+        }
+        None => (frame.start, frame.utc_offset),
+    };
+    let end = match end {
+        Some(end) => Some(parse_timestamp(end)?),
+        None => frame.end,
+    };
+    validate_span(start, end)?;
+
+    let task_id = if tree_name.is_some() || task_uid.is_some() {
+        timetracking::resolve_target(&pool, tree_name, task_uid)
+            .await?
+            .0
+    } else {
+        frame.task_id
+    };
+
+    resolve_overlaps(&mut transaction, start, end, &[&frame.id], adjust).await?;
+
+    let query_result = sqlx::query!(
+        r#"
+        UPDATE frame
+        SET "start" = ?, "end" = ?, task_id = ?, utc_offset = ?
+        WHERE id = ?
+        RETURNING (SELECT tree_name FROM task WHERE id = frame.task_id) AS "tree_name!: String";
+        "#,
+        start,
+        end,
+        task_id,
+        utc_offset,
+        uid,
+    )
+    .fetch_one(&mut *transaction)
+    .await;
+
+    // error handling
+    let record = match query_result {
+        Ok(record) => record,
+        Err(query_error) => panic!("Database query failed: {query_error}"),
+    };
+
+    let path = task::path(&mut transaction, &task_id).await;
+
+    transaction
+        .commit()
+        .await
+        .expect("Committing a transaction should succeed");
+
+    println!(
+        "Edited frame {} ({}) on {}",
+        ansi::format(uid.short(), ansi::ForestFormat::Uid),
+        format_span(start, end, utc_offset),
+        timetracking::format_target(&record.tree_name, &path),
+    );
+
+    Ok(())
+}
+
+/// Removes a time tracking frame
+///
+/// # Errors
+/// Returns an error if the frame does not exist
+///
+/// # Panics
+/// This function may panic if database operations fail
+pub async fn remove(partial_uid: &String) -> Result<(), Box<dyn Error>> {
+    let pool = dbutils::load_db().await;
+
+    let uid = find_uid_from_partial(partial_uid).await?;
+
+    let mut conn = pool
+        .acquire()
+        .await
+        .expect("Acquiring connection to database should succeed");
+
+    let query_result = sqlx::query!(
+        r#"
+        DELETE FROM frame
+        WHERE id = ?
+        RETURNING "start", "end", utc_offset;
+        "#,
+        uid,
+    )
+    .fetch_one(&mut *conn)
+    .await;
+
+    // error handling
+    let frame = match query_result {
+        Ok(record) => record,
+        Err(query_error) => match query_error {
+            sqlx::Error::RowNotFound => return Err(format!("Frame '{uid}' not found").into()),
+            other_error => panic!("Database query failed: {other_error}"),
+        },
+    };
+
+    println!(
+        "Removed frame {} ({})",
+        ansi::format(uid.short(), ansi::ForestFormat::Uid),
+        format_span(frame.start, frame.end, frame.utc_offset),
+    );
+
+    Ok(())
+}
+
+/// Splits a time tracking frame in two frames at the given date and time
+///
+/// The second frame keeps the end of the original frame, so it is still running if the original
+/// frame was.
+///
+/// # Errors
+/// Returns an error if the frame does not exist, if the date is ill-formed or if it is not strictly
+/// within the frame
+///
+/// # Panics
+/// This function may panic if database operations fail
+pub async fn split(partial_uid: &String, at: String) -> Result<(), Box<dyn Error>> {
+    let at = parse_timestamp(at)?;
+
+    let pool = dbutils::load_db().await;
+
+    let uid = find_uid_from_partial(partial_uid).await?;
+
+    let mut transaction = pool
+        .begin()
+        .await
+        .expect("Beginning a transaction should succeed");
+
+    let frame = get_frame(&mut transaction, &uid).await?;
+
+    let end = frame.end.unwrap_or(Local::now().timestamp_millis());
+    if at <= frame.start || end <= at {
+        return Err("Frames can only be split strictly between their start and their end".into());
+    }
+
+    // the original frame becomes the first part
+    let query_result = sqlx::query!(
+        r#"
+        UPDATE frame
+        SET "end" = ?, paused = 0
+        WHERE id = ?;
+        "#,
+        at,
+        uid,
+    )
+    .execute(&mut *transaction)
+    .await;
+
+    // error handling
+    if let Err(query_error) = query_result {
+        panic!("Database query failed: {query_error}");
+    }
+
+    // a new frame of the same session becomes the second part
+    let new_frame_uid = Uid::new();
+    let session_id = frame.session_id.unwrap_or(frame.id);
+    let query_result = sqlx::query!(
+        r#"
+        INSERT INTO frame("id", "start", "end", "task_id", "session_id", "paused", "utc_offset")
+        VALUES(?, ?, ?, ?, ?, ?, ?);
+        "#,
+        new_frame_uid,
+        at,
+        frame.end,
+        frame.task_id,
+        session_id,
+        frame.paused,
+        frame.utc_offset,
+    )
+    .execute(&mut *transaction)
+    .await;
+
+    // error handling
+    if let Err(query_error) = query_result {
+        panic!("Database query failed: {query_error}");
+    }
+
+    transaction
+        .commit()
+        .await
+        .expect("Committing a transaction should succeed");
+
+    println!(
+        "Split frame {} into {} ({}) and {} ({})",
+        ansi::format(uid.short(), ansi::ForestFormat::Uid),
+        ansi::format(uid.short(), ansi::ForestFormat::Uid),
+        format_span(frame.start, Some(at), frame.utc_offset),
+        ansi::format(new_frame_uid.short(), ansi::ForestFormat::Uid),
+        format_span(at, frame.end, frame.utc_offset),
+    );
+
+    Ok(())
+}
+
+/// Merges two time tracking frames of the same tree or task into a single frame
+///
+/// The merged frame spans from the earliest start to the latest end of both frames, including any
+/// gap between them. It is still running if one of the frames was. Other frames overlapping the
+/// merged frame are trimmed if `adjust` is set.
+///
+/// # Errors
+/// Returns an error if a frame does not exist, if the frames are not attached to the same tree or
+/// task, or if the merged frame would overlap other frames
+///
+/// # Panics
+/// This function may panic if database operations fail
+pub async fn merge(
+    partial_uid: &String,
+    other_partial_uid: &String,
+    adjust: bool,
+) -> Result<(), Box<dyn Error>> {
+    let pool = dbutils::load_db().await;
+
+    let uid = find_uid_from_partial(partial_uid).await?;
+    let other_uid = find_uid_from_partial(other_partial_uid).await?;
+
+    let mut transaction = pool
+        .begin()
+        .await
+        .expect("Beginning a transaction should succeed");
+
+    let mut first = get_frame(&mut transaction, &uid).await?;
+    let mut second = get_frame(&mut transaction, &other_uid).await?;
+
+    if first.id == second.id {
+        return Err("A frame cannot be merged with itself".into());
+    }
+    if first.task_id != second.task_id {
+        return Err("Only frames of the same tree or task can be merged".into());
+    }
+
+    // the earliest frame is kept
+    if second.start < first.start {
+        std::mem::swap(&mut first, &mut second);
+    }
+    let end = match (first.end, second.end) {
+        (Some(first_end), Some(second_end)) => Some(first_end.max(second_end)),
+        _ => None,
+    };
+
+    resolve_overlaps(
+        &mut transaction,
+        first.start,
+        end,
+        &[&first.id, &second.id],
+        adjust,
+    )
+    .await?;
+
+    // notes written about the removed frame now describe the merged frame
+    let query_result = sqlx::query!(
+        r#"
+        UPDATE note
+        SET frame_id = ?
+        WHERE frame_id = ?;
+        "#,
+        first.id,
+        second.id,
+    )
+    .execute(&mut *transaction)
+    .await;
+
+    // error handling
+    if let Err(query_error) = query_result {
+        panic!("Database query failed: {query_error}");
+    }
+
+    let query_result = sqlx::query!(
+        r#"
+        DELETE FROM frame
+        WHERE id = ?;
+        "#,
+        second.id,
+    )
+    .execute(&mut *transaction)
+    .await;
+
+    // error handling
+    if let Err(query_error) = query_result {
+        panic!("Database query failed: {query_error}");
+    }
+
+    let paused = first.paused || second.paused;
+    let query_result = sqlx::query!(
+        r#"
+        UPDATE frame
+        SET "end" = ?, paused = ?
+        WHERE id = ?;
+        "#,
+        end,
+        paused,
+        first.id,
+    )
+    .execute(&mut *transaction)
+    .await;
+
+    // error handling
+    if let Err(query_error) = query_result {
+        panic!("Database query failed: {query_error}");
+    }
+
+    transaction
+        .commit()
+        .await
+        .expect("Committing a transaction should succeed");
+
+    let first_uid = Uid::try_from(first.id)?;
+    println!(
+        "Merged frames {} and {} into {} ({})",
+        ansi::format(first_uid.short(), ansi::ForestFormat::Uid),
+        ansi::format(Uid::try_from(second.id)?.short(), ansi::ForestFormat::Uid),
+        ansi::format(first_uid.short(), ansi::ForestFormat::Uid),
+        format_span(first.start, end, first.utc_offset),
+    );
+
+    Ok(())
+}
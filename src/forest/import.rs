@@ -0,0 +1,967 @@
+use chrono::{Local, NaiveDate, NaiveDateTime, NaiveTime, TimeZone};
+use serde_json::Value;
+use sqlx::SqliteConnection;
+use std::collections::HashMap;
+use std::error::Error;
+use std::path::PathBuf;
+
+use super::ansi;
+use super::dbutils;
+use super::frame;
+use super::output;
+use super::tag;
+use super::task;
+use super::timetracking;
+use super::tree;
+use forest_types::{ImportSource, TaskImportFormat, TaskStatus, Uid};
+
+/// Tree receiving records that are not attached to any project
+const DEFAULT_TREE_NAME: &str = "imported";
+
+/// Time record read from another time tracker
+struct ImportedFrame {
+    /// identifier of the record in the source, used to avoid importing it twice
+    import_id: String,
+    start: i64,
+    end: i64,
+    tree_name: String,
+    task_name: Option<String>,
+    tags: Vec<String>,
+}
+
+/// Returns the tags that are valid forest tags, spaces being replaced with dashes
+fn valid_tags<'a>(tags: impl Iterator<Item = &'a str>) -> Vec<String> {
+    tags.filter_map(|tag| forest_types::tag_parser(&tag.trim().replace(' ', "-")).ok())
+        .collect()
+}
+
+/// Returns the given name, or `None` if it is empty
+fn non_empty(name: &str) -> Option<String> {
+    let name = name.trim();
+    (!name.is_empty()).then(|| name.to_string())
+}
+
+/// Reads a Watson frames file
+///
+/// Each frame is an array made of its start and stop timestamps in seconds, its project, its id,
+/// its tags and its last update. Projects are mapped to trees, the first tag to a task of the
+/// tree, and the other tags to tags of this task.
+///
+/// # Errors
+/// Returns an error if the file is not a Watson frames file
+fn read_watson(content: &str) -> Result<Vec<ImportedFrame>, Box<dyn Error>> {
+    let error = || "Ill-formed Watson frames file";
+
+    let frames: Vec<Value> = serde_json::from_str(content).map_err(|_| error())?;
+    frames
+        .iter()
+        .map(|frame| {
+            let start = frame[0].as_i64().ok_or_else(error)?;
+            let end = frame[1].as_i64().ok_or_else(error)?;
+            let project = frame[2].as_str().ok_or_else(error)?;
+            let id = frame[3].as_str().ok_or_else(error)?;
+            let tags: Vec<&str> = match frame[4].as_array() {
+                Some(tags) => tags.iter().filter_map(Value::as_str).collect(),
+                None => Vec::new(),
+            };
+
+            Ok(ImportedFrame {
+                import_id: format!("watson:{id}"),
+                start: start * 1000,
+                end: end * 1000,
+                tree_name: non_empty(project).unwrap_or(String::from(DEFAULT_TREE_NAME)),
+                task_name: tags.first().and_then(|tag| non_empty(tag)),
+                tags: valid_tags(tags.into_iter().skip(1)),
+            })
+        })
+        .collect()
+}
+
+/// Reads the JSON output of `timew export`
+///
+/// Timewarrior only has tags: the first one is mapped to a tree, the second one to a task of the
+/// tree, and the others to tags of this task. Open intervals are skipped.
+///
+/// # Errors
+/// Returns an error if the file is not a Timewarrior export
+fn read_timewarrior(content: &str) -> Result<Vec<ImportedFrame>, Box<dyn Error>> {
+    let error = || "Ill-formed Timewarrior export";
+    let parse_timestamp = |datetime: &str| {
+        NaiveDateTime::parse_from_str(datetime, "%Y%m%dT%H%M%SZ")
+            .map(|datetime| datetime.and_utc().timestamp_millis())
+            .map_err(|_| error())
+    };
+
+    let intervals: Vec<Value> = serde_json::from_str(content).map_err(|_| error())?;
+    let mut frames = Vec::new();
+    for interval in &intervals {
+        let start = interval["start"].as_str().ok_or_else(error)?;
+        let Some(end) = interval["end"].as_str() else {
+            continue;
+        };
+        let tags: Vec<&str> = match interval["tags"].as_array() {
+            Some(tags) => tags.iter().filter_map(Value::as_str).collect(),
+            None => Vec::new(),
+        };
+
+        frames.push(ImportedFrame {
+            // intervals cannot overlap in Timewarrior, so their start identifies them
+            import_id: format!("timewarrior:{start}"),
+            start: parse_timestamp(start)?,
+            end: parse_timestamp(end)?,
+            tree_name: tags
+                .first()
+                .and_then(|tag| non_empty(tag))
+                .unwrap_or(String::from(DEFAULT_TREE_NAME)),
+            task_name: tags.get(1).and_then(|tag| non_empty(tag)),
+            tags: valid_tags(tags.into_iter().skip(2)),
+        });
+    }
+    Ok(frames)
+}
+
+/// Reads a CSV export of detailed time entries from Toggl Track or Clockify
+///
+/// Projects are mapped to trees, and tasks, or descriptions if there is no task, to tasks of the
+/// tree. Tags are attached to these tasks. Dates and times are read in local time.
+///
+/// # Errors
+/// Returns an error if the file is not a CSV export with start and end dates and times
+fn read_toggl_csv(content: &str) -> Result<Vec<ImportedFrame>, Box<dyn Error>> {
+    let mut reader = csv::Reader::from_reader(content.as_bytes());
+
+    // columns are found by name, as Toggl and Clockify do not use the same layout
+    let headers: Vec<String> = reader
+        .headers()?
+        .iter()
+        .map(|header| header.trim().to_lowercase())
+        .collect();
+    let column = |name: &str| headers.iter().position(|header| header == name);
+    let required_column =
+        |name: &str| column(name).ok_or_else(|| format!("Column '{name}' not found in CSV export"));
+    let project = column("project");
+    let task = column("task");
+    let description = column("description");
+    let tags = column("tags");
+    let start_date = required_column("start date")?;
+    let start_time = required_column("start time")?;
+    let end_date = required_column("end date")?;
+    let end_time = required_column("end time")?;
+
+    let parse_datetime = |date: &str, time: &str| -> Result<NaiveDateTime, Box<dyn Error>> {
+        let error = || format!("Ill-formed date and time in CSV export: '{date} {time}'");
+        let date = ["%Y-%m-%d", "%m/%d/%Y", "%d.%m.%Y"]
+            .iter()
+            .find_map(|format| NaiveDate::parse_from_str(date.trim(), format).ok())
+            .ok_or_else(error)?;
+        let time = ["%H:%M:%S", "%H:%M", "%I:%M:%S %p", "%I:%M %p"]
+            .iter()
+            .find_map(|format| NaiveTime::parse_from_str(time.trim(), format).ok())
+            .ok_or_else(error)?;
+        Ok(date.and_time(time))
+    };
+
+    // local times are resolved like the ones given by the user, including around DST changes
+    let timestamp = |datetime: NaiveDateTime| -> Result<i64, Box<dyn Error>> {
+        let local_datetime = Local.from_local_datetime(&datetime);
+        let local_datetime =
+            forest_types::resolve_local_datetime(&datetime.to_string(), local_datetime)?;
+        Ok(local_datetime.timestamp_millis())
+    };
+
+    let mut frames = Vec::new();
+    for record in reader.records() {
+        let record = record?;
+        let field = |index: Option<usize>| index.and_then(|index| record.get(index)).unwrap_or("");
+
+        let project = field(project);
+        let description = field(description);
+        let start = parse_datetime(field(Some(start_date)), field(Some(start_time)))?;
+        let end = parse_datetime(field(Some(end_date)), field(Some(end_time)))?;
+
+        frames.push(ImportedFrame {
+            // records are identified by their times as written in the export, so that importing
+            // them again in another timezone does not duplicate them
+            import_id: format!(
+                "toggl:{}:{}:{project}:{description}",
+                start.format("%Y-%m-%dT%H:%M:%S"),
+                end.format("%Y-%m-%dT%H:%M:%S"),
+            ),
+            start: timestamp(start)?,
+            end: timestamp(end)?,
+            tree_name: non_empty(project).unwrap_or(String::from(DEFAULT_TREE_NAME)),
+            task_name: non_empty(field(task)).or_else(|| non_empty(description)),
+            tags: valid_tags(field(tags).split(',')),
+        });
+    }
+    Ok(frames)
+}
+
+/// Returns the id of the task of the given name in the given tree, creating it if it is missing
+///
+/// The tree is created as well if it is missing. Without a task name, the root of the tree is
+/// returned. Returns whether the tree and the task were created.
+///
+/// # Panics
+/// This function may panic if database operations fail
+async fn get_or_create_task(
+    conn: &mut SqliteConnection,
+    tree_name: &String,
+    task_name: Option<&String>,
+) -> (String, bool, bool) {
+    let created_tree = tree::create_if_not_exist(conn, tree_name).await;
+
+    // any task of the tree with this name matches, in case it was moved since the last import
+    let query_result = sqlx::query!(
+        r#"
+        SELECT id, "left" = 1 AS "is_root!: bool"
+        FROM task
+        WHERE tree_name = ? AND ("left" = 1 OR name = ?)
+        ORDER BY "left" = 1, "left"
+        LIMIT 1;
+        "#,
+        tree_name,
+        task_name,
+    )
+    .fetch_one(&mut *conn)
+    .await;
+
+    // error handling
+    let task = match query_result {
+        Ok(record) => record,
+        Err(query_error) => panic!("Database query failed: {query_error}"),
+    };
+
+    match task_name {
+        Some(task_name) if task.is_root => {
+            let uid =
+                task::insert_child(conn, &task.id, task_name, &String::new(), None, None, None)
+                    .await;
+            (uid.to_string(), created_tree, true)
+        }
+        _ => (task.id, created_tree, false),
+    }
+}
+
+/// Imports time records from another time tracker
+///
+/// Projects and tags of the records are mapped onto trees and tasks, which are created if they are
+/// missing. Records that were already imported are skipped, as well as records overlapping other
+/// frames. With `dry_run`, the records that would be imported are listed and nothing is changed.
+///
+/// # Errors
+/// Returns an error if the file cannot be read or if it is ill-formed
+///
+/// # Panics
+/// This function may panic if database operations fail
+pub async fn import(
+    source: ImportSource,
+    path: &PathBuf,
+    dry_run: bool,
+) -> Result<(), Box<dyn Error>> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| format!("Cannot read '{}': {e}", path.display()))?;
+
+    let mut frames = match source {
+        ImportSource::Watson => read_watson(&content)?,
+        ImportSource::Timewarrior => read_timewarrior(&content)?,
+        ImportSource::TogglCsv => read_toggl_csv(&content)?,
+    };
+    frames.sort_by_key(|frame| frame.start);
+
+    let pool = dbutils::load_db().await;
+
+    // the whole import is rolled back on dry runs
+    let mut transaction = pool
+        .begin()
+        .await
+        .expect("Beginning a transaction should succeed");
+
+    let (mut imported, mut imported_time, mut created_trees, mut created_tasks) = (0, 0, 0, 0);
+    let (mut duplicates, mut overlapping, mut invalid) = (0, 0, 0);
+    for imported_frame in &frames {
+        let query_result = sqlx::query!(
+            r#"
+            SELECT COUNT(*) AS "count!: i64"
+            FROM frame
+            WHERE import_id = ?;
+            "#,
+            imported_frame.import_id,
+        )
+        .fetch_one(&mut *transaction)
+        .await;
+
+        // error handling
+        match query_result {
+            Ok(record) => {
+                if record.count > 0 {
+                    duplicates += 1;
+                    continue;
+                }
+            }
+            Err(query_error) => panic!("Database query failed: {query_error}"),
+        }
+
+        if imported_frame.end <= imported_frame.start {
+            invalid += 1;
+            continue;
+        }
+
+        let span = frame::format_span(imported_frame.start, Some(imported_frame.end), None);
+        if frame::resolve_overlaps(
+            &mut transaction,
+            imported_frame.start,
+            Some(imported_frame.end),
+            &[],
+            false,
+        )
+        .await
+        .is_err()
+        {
+            overlapping += 1;
+            println!(
+                "{} record ({span}) overlaps existing frames",
+                ansi::format("Skipped", ansi::ForestFormat::Blocked),
+            );
+            continue;
+        }
+
+        let (task_id, created_tree, created_task) = get_or_create_task(
+            &mut transaction,
+            &imported_frame.tree_name,
+            imported_frame.task_name.as_ref(),
+        )
+        .await;
+        created_trees += i32::from(created_tree);
+        created_tasks += i32::from(created_task);
+
+        // tags can only be attached to tasks, not to trees
+        if !imported_frame.tags.is_empty() && imported_frame.task_name.is_some() {
+            tag::attach_to_task(&mut transaction, &task_id, &imported_frame.tags).await;
+        }
+
+        let new_frame_uid = Uid::new();
+        let utc_offset = output::local_utc_offset(imported_frame.start);
+        let query_result = sqlx::query!(
+            r#"
+            INSERT INTO frame("id", "start", "end", "task_id", "utc_offset", "import_id")
+            VALUES(?, ?, ?, ?, ?, ?);
+            "#,
+            new_frame_uid,
+            imported_frame.start,
+            imported_frame.end,
+            task_id,
+            utc_offset,
+            imported_frame.import_id,
+        )
+        .execute(&mut *transaction)
+        .await;
+
+        // error handling
+        if let Err(query_error) = query_result {
+            panic!("Database query failed: {query_error}");
+        }
+
+        if dry_run {
+            let path = task::path(&mut transaction, &task_id).await;
+            println!(
+                "{} ({}) on {}",
+                span,
+                timetracking::format_duration((imported_frame.end - imported_frame.start) / 1000),
+                timetracking::format_target(&imported_frame.tree_name, &path),
+            );
+        }
+
+        imported += 1;
+        imported_time += (imported_frame.end - imported_frame.start) / 1000;
+    }
+
+    if dry_run {
+        transaction
+            .rollback()
+            .await
+            .expect("Rolling back a transaction should succeed");
+    } else {
+        transaction
+            .commit()
+            .await
+            .expect("Committing a transaction should succeed");
+    }
+
+    println!(
+        "{} {} frames ({}), creating {} trees and {} tasks",
+        if dry_run { "Would import" } else { "Imported" },
+        imported,
+        timetracking::format_duration(imported_time),
+        created_trees,
+        created_tasks,
+    );
+    if duplicates + overlapping + invalid > 0 {
+        println!(
+            "Skipped {duplicates} already imported, {overlapping} overlapping and {invalid} invalid records"
+        );
+    }
+
+    Ok(())
+}
+
+/// Task read from another task manager
+pub(super) struct ImportedTask {
+    /// index of the parent task among the imported tasks, or `None` for top-level tasks
+    pub(super) parent: Option<usize>,
+    pub(super) name: String,
+    pub(super) description: String,
+    pub(super) status: TaskStatus,
+    pub(super) due: Option<i64>,
+    pub(super) scheduled: Option<i64>,
+    pub(super) tags: Vec<String>,
+}
+
+impl ImportedTask {
+    fn new(parent: Option<usize>, name: String) -> Self {
+        ImportedTask {
+            parent,
+            name,
+            description: String::new(),
+            status: TaskStatus::Todo,
+            due: None,
+            scheduled: None,
+            tags: Vec::new(),
+        }
+    }
+}
+
+/// Returns the index of the task standing for the given project, adding it and the tasks of its
+/// parent projects if they are missing
+///
+/// Projects are given as the list of their names from the top-level project down.
+fn project_task(
+    tasks: &mut Vec<ImportedTask>,
+    projects: &mut HashMap<Vec<String>, usize>,
+    project: &[String],
+) -> Option<usize> {
+    let mut parent = None;
+    for depth in 1..=project.len() {
+        let path = &project[..depth];
+        parent = Some(match projects.get(path) {
+            Some(&index) => index,
+            None => {
+                tasks.push(ImportedTask::new(parent, path[depth - 1].clone()));
+                projects.insert(path.to_vec(), tasks.len() - 1);
+                tasks.len() - 1
+            }
+        });
+    }
+    parent
+}
+
+/// Reads a todo.txt file
+///
+/// Each line is a task, done if it starts with `x`. The first project of a task is mapped to a
+/// parent task, its contexts and other projects to tags, and its `due:` and `t:` (threshold)
+/// dates to its due and scheduled dates.
+///
+/// # Errors
+/// Returns an error if a date is ill-formed
+fn read_todotxt(content: &str) -> Result<Vec<ImportedTask>, Box<dyn Error>> {
+    let parse_date = |date: &str| -> Result<i64, Box<dyn Error>> {
+        NaiveDate::parse_from_str(date, "%Y-%m-%d")
+            .map_err(|_| format!("Ill-formed date in todo.txt file: '{date}'"))?;
+        Ok(forest_types::parse_datetime(date, &Local::now())?.timestamp_millis())
+    };
+    let is_date = |word: &str| NaiveDate::parse_from_str(word, "%Y-%m-%d").is_ok();
+
+    let mut tasks = Vec::new();
+    let mut projects = HashMap::new();
+    for line in content.lines() {
+        let mut words = line.split_whitespace().peekable();
+        if words.peek().is_none() {
+            continue;
+        }
+
+        let done = words.next_if_eq(&"x").is_some();
+        // completion and creation dates
+        words.next_if(|word| is_date(word));
+        // priority, eg. "(A)", which is not mapped to forest priorities
+        words.next_if(|word| {
+            let word = word.as_bytes();
+            word.len() == 3 && word[0] == b'(' && word[1].is_ascii_uppercase() && word[2] == b')'
+        });
+        words.next_if(|word| is_date(word));
+
+        let mut name = Vec::new();
+        let mut project = None;
+        let mut tags = Vec::new();
+        let (mut due, mut scheduled) = (None, None);
+        for word in words {
+            if let Some(date) = word.strip_prefix("due:") {
+                due = Some(parse_date(date)?);
+            } else if let Some(date) = word.strip_prefix("t:") {
+                scheduled = Some(parse_date(date)?);
+            } else if let Some(other) = word.strip_prefix('+').filter(|name| !name.is_empty()) {
+                if project.is_none() {
+                    project = Some(other.to_string());
+                } else {
+                    tags.push(other);
+                }
+            } else if let Some(context) = word.strip_prefix('@').filter(|name| !name.is_empty()) {
+                tags.push(context);
+            } else {
+                name.push(word);
+            }
+        }
+        if name.is_empty() {
+            continue;
+        }
+
+        let project: Vec<String> = project.into_iter().collect();
+        let parent = project_task(&mut tasks, &mut projects, &project);
+        let mut task = ImportedTask::new(parent, name.join(" "));
+        if done {
+            task.status = TaskStatus::Done;
+        }
+        task.due = due;
+        task.scheduled = scheduled;
+        task.tags = valid_tags(tags.into_iter());
+        tasks.push(task);
+    }
+    Ok(tasks)
+}
+
+/// Reads the JSON output of `task export`
+///
+/// Projects are mapped to parent tasks, nested along the dots of their names. Annotations are
+/// gathered in descriptions, and recurring task templates are skipped.
+///
+/// # Errors
+/// Returns an error if the file is not a Taskwarrior export
+fn read_taskwarrior(content: &str) -> Result<Vec<ImportedTask>, Box<dyn Error>> {
+    let error = || "Ill-formed Taskwarrior export";
+    let parse_timestamp = |datetime: &Value| -> Result<Option<i64>, Box<dyn Error>> {
+        match datetime.as_str() {
+            Some(datetime) => Ok(Some(
+                NaiveDateTime::parse_from_str(datetime, "%Y%m%dT%H%M%SZ")
+                    .map_err(|_| error())?
+                    .and_utc()
+                    .timestamp_millis(),
+            )),
+            None => Ok(None),
+        }
+    };
+
+    // older versions of Taskwarrior export one task per line instead of an array
+    let exported: Vec<Value> = match serde_json::from_str(content) {
+        Ok(exported) => exported,
+        Err(_) => content
+            .lines()
+            .map(|line| line.trim().trim_end_matches(','))
+            .filter(|line| !line.is_empty())
+            .map(serde_json::from_str)
+            .collect::<Result<_, _>>()
+            .map_err(|_| error())?,
+    };
+
+    let mut tasks = Vec::new();
+    let mut projects = HashMap::new();
+    for exported_task in &exported {
+        let status = match exported_task["status"].as_str() {
+            Some("pending" | "waiting") if exported_task["start"].is_string() => {
+                TaskStatus::InProgress
+            }
+            Some("pending" | "waiting") => TaskStatus::Todo,
+            Some("completed") => TaskStatus::Done,
+            Some("deleted") => TaskStatus::Cancelled,
+            Some("recurring") => continue,
+            _ => return Err(error().into()),
+        };
+        let name = exported_task["description"].as_str().ok_or_else(error)?;
+
+        let project: Vec<String> = match exported_task["project"].as_str() {
+            Some(project) => project.split('.').map(String::from).collect(),
+            None => Vec::new(),
+        };
+        let parent = project_task(&mut tasks, &mut projects, &project);
+
+        let mut task = ImportedTask::new(parent, name.to_string());
+        task.status = status;
+        task.due = parse_timestamp(&exported_task["due"])?;
+        task.scheduled = parse_timestamp(&exported_task["scheduled"])?;
+        if let Some(tags) = exported_task["tags"].as_array() {
+            task.tags = valid_tags(tags.iter().filter_map(Value::as_str));
+        }
+        if let Some(annotations) = exported_task["annotations"].as_array() {
+            let annotations: Vec<&str> = annotations
+                .iter()
+                .filter_map(|annotation| annotation["description"].as_str())
+                .collect();
+            task.description = annotations.join("\n");
+        }
+        tasks.push(task);
+    }
+    Ok(tasks)
+}
+
+/// Reads a Markdown checklist
+///
+/// Headings and list items are tasks, nested under the previous heading of a higher level and
+/// under the previous list item of a lower indentation. Items checked with `x` are done, with `/`
+/// in progress, and with `-` cancelled. Other lines are added to the description of the previous
+/// task.
+fn read_markdown(content: &str) -> Vec<ImportedTask> {
+    let mut tasks: Vec<ImportedTask> = Vec::new();
+    // tasks that may be parents of the next ones, along with their nesting level
+    let mut ancestors: Vec<(i64, usize)> = Vec::new();
+    for line in content.lines() {
+        let text = line.trim_start();
+        if text.is_empty() {
+            continue;
+        }
+
+        // headings are always at a lower nesting level than list items
+        let heading_level = text.chars().take_while(|&c| c == '#').count();
+        let (level, item) =
+            if (1..=6).contains(&heading_level) && text[heading_level..].starts_with(' ') {
+                (heading_level as i64 - 7, text[heading_level..].trim())
+            } else {
+                let indentation: usize = line[..line.len() - text.len()]
+                    .chars()
+                    .map(|c| if c == '\t' { 4 } else { 1 })
+                    .sum();
+                let marker_length = if text.starts_with(['-', '*', '+']) {
+                    1
+                } else {
+                    let digits = text.chars().take_while(char::is_ascii_digit).count();
+                    match text[digits..].chars().next() {
+                        Some('.' | ')') if digits > 0 => digits + 1,
+                        _ => 0,
+                    }
+                };
+                match text[marker_length..].strip_prefix(' ') {
+                    Some(item) if marker_length > 0 => (indentation as i64, item.trim()),
+                    _ => {
+                        // text of the previous task
+                        if let Some(task) = tasks.last_mut() {
+                            if !task.description.is_empty() {
+                                task.description.push('\n');
+                            }
+                            task.description.push_str(text.trim_end());
+                        }
+                        continue;
+                    }
+                }
+            };
+
+        let (status, name) = match item.get(..3).zip(item.get(3..)) {
+            Some(("[ ]", name)) => (TaskStatus::Todo, name),
+            Some(("[x]" | "[X]", name)) => (TaskStatus::Done, name),
+            Some(("[/]", name)) => (TaskStatus::InProgress, name),
+            Some(("[-]", name)) => (TaskStatus::Cancelled, name),
+            _ => (TaskStatus::Todo, item),
+        };
+        let name = name.trim();
+        if name.is_empty() {
+            continue;
+        }
+
+        while ancestors
+            .last()
+            .is_some_and(|&(ancestor_level, _)| ancestor_level >= level)
+        {
+            ancestors.pop();
+        }
+        let mut task = ImportedTask::new(ancestors.last().map(|&(_, index)| index), name.into());
+        task.status = status;
+        tasks.push(task);
+        ancestors.push((level, tasks.len() - 1));
+    }
+    tasks
+}
+
+/// Reads tasks exported by another task manager, parents first
+///
+/// # Errors
+/// Returns an error if the content is ill-formed
+pub(super) fn read_tasks(
+    format: TaskImportFormat,
+    content: &str,
+) -> Result<Vec<ImportedTask>, Box<dyn Error>> {
+    match format {
+        TaskImportFormat::Todotxt => read_todotxt(content),
+        TaskImportFormat::Taskwarrior => read_taskwarrior(content),
+        TaskImportFormat::Markdown => Ok(read_markdown(content)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the parent, name and status of each task
+    fn outline(tasks: &[ImportedTask]) -> Vec<(Option<usize>, &str, &str)> {
+        tasks
+            .iter()
+            .map(|task| (task.parent, task.name.as_str(), task.status.as_str()))
+            .collect()
+    }
+
+    /// Import id, duration in minutes, tree, task and tags of a frame
+    type FrameOutline<'a> = (&'a str, i64, &'a str, Option<&'a str>, Vec<&'a str>);
+
+    /// Returns the import id, duration in minutes, tree, task and tags of each frame
+    fn frame_outline(frames: &[ImportedFrame]) -> Vec<FrameOutline<'_>> {
+        frames
+            .iter()
+            .map(|frame| {
+                (
+                    frame.import_id.as_str(),
+                    (frame.end - frame.start) / 60_000,
+                    frame.tree_name.as_str(),
+                    frame.task_name.as_deref(),
+                    frame.tags.iter().map(String::as_str).collect(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn reads_watson() {
+        let frames = read_watson(
+            r#"[
+                [1718181000, 1718184600, "work", "a1b2c3", ["review", "urgent", "two words"], 1718184700],
+                [1718186400, 1718187300, "", "d4e5f6", [], 1718187400]
+            ]"#,
+        )
+        .unwrap();
+        assert_eq!(
+            frame_outline(&frames),
+            vec![
+                (
+                    "watson:a1b2c3",
+                    60,
+                    "work",
+                    Some("review"),
+                    vec!["urgent", "two-words"]
+                ),
+                ("watson:d4e5f6", 15, "imported", None, vec![]),
+            ]
+        );
+        assert_eq!(frames[0].start, 1_718_181_000_000);
+
+        assert!(read_watson(r#"[[1718181000, "work"]]"#).is_err());
+    }
+
+    #[test]
+    fn reads_timewarrior() {
+        let frames = read_timewarrior(
+            r#"[
+                {"id": 2, "start": "20240612T080000Z", "end": "20240612T093000Z",
+                 "tags": ["work", "review", "urgent"]},
+                {"id": 1, "start": "20240612T100000Z", "tags": ["work"]}
+            ]"#,
+        )
+        .unwrap();
+
+        // the open interval is still being recorded, so it is skipped
+        assert_eq!(
+            frame_outline(&frames),
+            vec![(
+                "timewarrior:20240612T080000Z",
+                90,
+                "work",
+                Some("review"),
+                vec!["urgent"]
+            )]
+        );
+        assert_eq!(frames[0].start, 1_718_179_200_000);
+
+        assert!(
+            read_timewarrior(r#"[{"start": "2024-06-12 08:00", "end": "2024-06-12 09:00"}]"#)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn reads_toggl_csv() {
+        let frames = read_toggl_csv(
+            "User,Email,Client,Project,Task,Description,Billable,Start date,Start time,End date,End time,Duration,Tags\n\
+             Ann,ann@example.com,,work,API,review,No,06/12/2024,2:30 PM,06/12/2024,03:45:00 PM,01:15:00,\"urgent, two words\"\n\
+             Ann,ann@example.com,,,,standup,No,2024-06-12,09:00,2024-06-12,09:15:00,00:15:00,\n",
+        )
+        .unwrap();
+        assert_eq!(
+            frame_outline(&frames),
+            vec![
+                (
+                    "toggl:2024-06-12T14:30:00:2024-06-12T15:45:00:work:review",
+                    75,
+                    "work",
+                    Some("API"),
+                    vec!["urgent", "two-words"]
+                ),
+                (
+                    "toggl:2024-06-12T09:00:00:2024-06-12T09:15:00::standup",
+                    15,
+                    "imported",
+                    Some("standup"),
+                    vec![]
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn reads_clockify_csv() {
+        // Clockify orders its columns differently and capitalizes their names
+        let frames = read_toggl_csv(
+            "Project,Client,Description,Task,User,Group,Email,Tags,Billable,Start Date,Start Time,End Date,End Time,Duration (h)\n\
+             work,,review,API,Ann,,ann@example.com,urgent,No,12.06.2024,14:30:00,12.06.2024,15:45:00,01:15:00\n",
+        )
+        .unwrap();
+        assert_eq!(
+            frame_outline(&frames),
+            vec![(
+                "toggl:2024-06-12T14:30:00:2024-06-12T15:45:00:work:review",
+                75,
+                "work",
+                Some("API"),
+                vec!["urgent"]
+            )]
+        );
+    }
+
+    #[test]
+    fn rejects_csv_without_times() {
+        let error = read_toggl_csv("Project,Description,Start date,Start time,End date\n")
+            .err()
+            .unwrap()
+            .to_string();
+        assert_eq!(error, "Column 'end time' not found in CSV export");
+
+        let error = read_toggl_csv(
+            "Project,Start date,Start time,End date,End time\nwork,2024-06-12,noon,2024-06-12,13:00\n",
+        )
+        .err()
+        .unwrap()
+        .to_string();
+        assert_eq!(
+            error,
+            "Ill-formed date and time in CSV export: '2024-06-12 noon'"
+        );
+    }
+
+    #[test]
+    fn reads_markdown_nesting() {
+        let tasks = read_markdown(
+            "# Project\n\
+             ## Backend\n\
+             - [ ] API\n\
+             \x20 - [x] routes\n\
+             \t- [/] auth\n\
+             - database\n\
+             ## Frontend\n\
+             1. [-] mockups\n\
+             # Other\n",
+        );
+        assert_eq!(
+            outline(&tasks),
+            vec![
+                (None, "Project", "todo"),
+                (Some(0), "Backend", "todo"),
+                (Some(1), "API", "todo"),
+                (Some(2), "routes", "done"),
+                // a tab is wider than two spaces
+                (Some(3), "auth", "in-progress"),
+                (Some(1), "database", "todo"),
+                (Some(0), "Frontend", "todo"),
+                (Some(6), "mockups", "cancelled"),
+                (None, "Other", "todo"),
+            ]
+        );
+    }
+
+    #[test]
+    fn reads_markdown_descriptions() {
+        let tasks = read_markdown("- [X] task\n  first line\n\n  second line\n#not a heading\n");
+        assert_eq!(outline(&tasks), vec![(None, "task", "done")]);
+        assert_eq!(
+            tasks[0].description,
+            "first line\nsecond line\n#not a heading"
+        );
+    }
+
+    #[test]
+    fn reads_todotxt() {
+        let tasks = read_todotxt(
+            "x 2024-06-10 2024-06-01 write report +work @office\n\
+             (A) 2024-06-01 call Bob +work +phone due:2024-06-14 t:2024-06-12\n\
+             \n\
+             buy milk @shop\n\
+             +work\n",
+        )
+        .unwrap();
+        assert_eq!(
+            outline(&tasks),
+            vec![
+                (None, "work", "todo"),
+                (Some(0), "write report", "done"),
+                (Some(0), "call Bob", "todo"),
+                (None, "buy milk", "todo"),
+            ]
+        );
+        assert_eq!(tasks[1].tags, vec!["office"]);
+        assert_eq!(tasks[2].tags, vec!["phone"]);
+        assert!(tasks[2].due.is_some() && tasks[2].scheduled.is_some());
+        assert!(tasks[2].scheduled < tasks[2].due);
+        assert_eq!(tasks[3].tags, vec!["shop"]);
+    }
+
+    #[test]
+    fn rejects_ill_formed_todotxt_dates() {
+        assert!(read_todotxt("task due:tomorrow\n").is_err());
+    }
+
+    #[test]
+    fn reads_taskwarrior() {
+        let tasks = read_taskwarrior(
+            r#"[
+                {"description": "write", "status": "pending", "project": "home.garden",
+                 "due": "20240614T100000Z", "tags": ["outdoor", "two words"]},
+                {"description": "dig", "status": "pending", "project": "home.garden",
+                 "start": "20240601T080000Z"},
+                {"description": "paint", "status": "completed", "project": "home",
+                 "annotations": [{"description": "blue"}, {"description": "twice"}]},
+                {"description": "old", "status": "deleted"},
+                {"description": "weekly", "status": "recurring"}
+            ]"#,
+        )
+        .unwrap();
+        assert_eq!(
+            outline(&tasks),
+            vec![
+                (None, "home", "todo"),
+                (Some(0), "garden", "todo"),
+                (Some(1), "write", "todo"),
+                (Some(1), "dig", "in-progress"),
+                (Some(0), "paint", "done"),
+                (None, "old", "cancelled"),
+            ]
+        );
+        assert_eq!(tasks[2].due, Some(1_718_359_200_000));
+        assert_eq!(tasks[2].tags, vec!["outdoor", "two-words"]);
+        assert_eq!(tasks[4].description, "blue\ntwice");
+    }
+
+    #[test]
+    fn reads_taskwarrior_lines() {
+        let tasks = read_taskwarrior(
+            "{\"description\": \"first\", \"status\": \"pending\"},\n\
+             {\"description\": \"second\", \"status\": \"waiting\"}\n",
+        )
+        .unwrap();
+        assert_eq!(
+            outline(&tasks),
+            vec![(None, "first", "todo"), (None, "second", "todo")]
+        );
+        assert!(read_taskwarrior("{\"description\": \"first\"}").is_err());
+    }
+}
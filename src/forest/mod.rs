@@ -1,6 +1,17 @@
+pub mod agenda;
 mod ansi;
+pub mod backup;
+pub mod config;
+pub mod db;
 mod dbutils;
+pub mod export;
+pub mod frame;
+pub mod import;
 pub mod notetaking;
+mod output;
+pub mod profile;
+pub mod tag;
 pub mod task;
+pub mod timesheet;
 pub mod timetracking;
 pub mod tree;
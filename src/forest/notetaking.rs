@@ -1,12 +1,15 @@
 use super::dbutils;
 use chrono::{DateTime, Local};
 use edit as default_editor;
+use serde::Serialize;
 use std::error::Error;
 use std::fs::{self, File};
 use std::io::{self, BufRead};
 
 use super::ansi;
-use forest_types::Uid;
+use super::output::{self, Render};
+use super::tag;
+use forest_types::{OutputFormat, Uid};
 
 /// Finds a UID in note table that matches the provided partial uid
 ///
@@ -67,7 +70,7 @@ async fn find_uid_from_partial(partial_uid: &String) -> Result<Uid, Box<dyn Erro
     }
 }
 
-/// Create a new note linked to the current tree
+/// Create a new note linked to the current tree, and to the given time tracking frame if any
 ///
 /// # Errors
 /// Returns an error if the forest is empty or if the given tree name does not exist in forest
@@ -76,7 +79,7 @@ async fn find_uid_from_partial(partial_uid: &String) -> Result<Uid, Box<dyn Erro
 /// This function may panic if database operations fail
 pub async fn add(
     tree_name: Option<String>,
-    from_time_tracking: bool,
+    frame_id: Option<String>,
 ) -> Result<(), Box<dyn Error>> {
     let new_note_uid = Uid::new();
 
@@ -137,15 +140,17 @@ pub async fn add(
     // insert new note into database
     let date = Local::now().timestamp_millis();
     let task_id = task.id;
+    let from_time_tracking = frame_id.is_some();
     let query_result = sqlx::query!(
         r#"
-        INSERT INTO note("id", "date", "task_id", "time_tracking")
-        VALUES (?, ?, ?, ?);
+        INSERT INTO note("id", "date", "task_id", "time_tracking", "frame_id")
+        VALUES (?, ?, ?, ?, ?);
         "#,
         new_note_uid,
         date,
         task_id,
-        from_time_tracking
+        from_time_tracking,
+        frame_id,
     )
     .execute(&mut *conn)
     .await;
@@ -177,14 +182,131 @@ pub async fn add(
     Ok(())
 }
 
+/// Listing of notes
+#[derive(Serialize)]
+struct NoteList {
+    notes: Vec<NoteListItem>,
+
+    #[serde(skip)]
+    show_time_tracking: bool,
+}
+
+/// Note of a listing
+#[derive(Serialize)]
+struct NoteListItem {
+    uid: String,
+    date: String,
+    tree: String,
+    time_tracking: bool,
+    preview: Option<String>,
+    tags: Vec<String>,
+
+    #[serde(skip)]
+    timestamp: i64,
+}
+
+/// Flat record of a note listing
+#[derive(Serialize)]
+struct NoteListRow {
+    uid: String,
+    date: String,
+    tree: String,
+    time_tracking: bool,
+    preview: Option<String>,
+    tags: String,
+}
+
+impl Render for NoteList {
+    type Row = NoteListRow;
+
+    fn print_text(&self) {
+        // get length of the longest tree name associated with a note for pretty alignment
+        let max_tree_name_length = self
+            .notes
+            .iter()
+            .map(|note| ansi::format(&note.tree, ansi::ForestFormat::TreeName).len())
+            .max()
+            .unwrap_or_default();
+
+        // print each note
+        for note in &self.notes {
+            let note_uid = Uid::try_from(note.uid.clone()).unwrap();
+
+            print!(
+                "{} ",
+                ansi::format(note_uid.short(), ansi::ForestFormat::Uid)
+            );
+
+            // print note date
+            let note_datetime: DateTime<Local> = DateTime::from_timestamp_millis(note.timestamp)
+                .unwrap()
+                .into();
+            print!(
+                "{} {} ",
+                ansi::format(
+                    &note_datetime.format("%Y-%m-%d").to_string(),
+                    ansi::ForestFormat::Date
+                ),
+                ansi::format(
+                    &note_datetime.format("%H:%M").to_string(),
+                    ansi::ForestFormat::Time
+                ),
+            );
+
+            if self.show_time_tracking {
+                if note.time_tracking {
+                    print!("tt   ");
+                } else {
+                    print!("user ");
+                }
+            }
+            // print tree_name with padding for alignment
+            print!(
+                "{:width$} ",
+                ansi::format(&note.tree, ansi::ForestFormat::TreeName),
+                width = &max_tree_name_length
+            );
+
+            // print a "note preview"
+            if let Some(line) = &note.preview {
+                print!("{line}");
+            }
+            if !note.tags.is_empty() {
+                print!(" {}", tag::format(&note.tags.join(" ")));
+            }
+            println!();
+        }
+    }
+
+    fn rows(&self) -> Vec<Self::Row> {
+        self.notes
+            .iter()
+            .map(|note| NoteListRow {
+                uid: note.uid.clone(),
+                date: note.date.clone(),
+                tree: note.tree.clone(),
+                time_tracking: note.time_tracking,
+                preview: note.preview.clone(),
+                tags: note.tags.join(" "),
+            })
+            .collect()
+    }
+}
+
 /// List all notes
 ///
+/// If `tag_filter` is not empty, only notes with one of the given tags are displayed.
+///
 /// # Errors
 /// Returns an error if no notes exist in the forest
 ///
 /// # Panics
 /// This function may panic if database operations fail
-pub async fn list(show_time_tracking: bool) -> Result<(), Box<dyn Error>> {
+pub async fn list(
+    show_time_tracking: bool,
+    tag_filter: &[String],
+    output: OutputFormat,
+) -> Result<(), Box<dyn Error>> {
     let pool = dbutils::load_db().await;
 
     let mut conn = pool
@@ -197,7 +319,9 @@ pub async fn list(show_time_tracking: bool) -> Result<(), Box<dyn Error>> {
         r#"
         -- foreach note, get date, tree name and note id
 
-        SELECT tree_name, date, n.id, n.time_tracking
+        SELECT
+            tree_name, date, n.id, n.time_tracking,
+            (SELECT group_concat(tag_name, ' ') FROM note_tag WHERE note_id = n.id) AS "tags?: String"
         FROM note n INNER JOIN task t ON n.task_id = t.id
         ORDER BY date DESC;
         "#
@@ -217,56 +341,22 @@ pub async fn list(show_time_tracking: bool) -> Result<(), Box<dyn Error>> {
         Err(query_error) => panic!("Database query failed: {query_error}"),
     };
 
-    // get length of the longest tree name associated with a note for pretty alignment
-    let max_tree_name_length = records
-        .iter()
-        .max_by(|a, b| a.tree_name.len().cmp(&b.tree_name.len()))
-        .map(|a| ansi::format(&a.tree_name, ansi::ForestFormat::TreeName).len())
-        .expect(
-            "At this point in the function, the itterator should point to a non empty collection",
-        );
-
-    // print each note
+    let mut notes: Vec<NoteListItem> = Vec::new();
     for note in records {
         if !show_time_tracking && note.time_tracking == 1 {
             continue;
         }
 
-        let note_uid = Uid::try_from(note.id).unwrap();
-
-        print!(
-            "{} ",
-            ansi::format(note_uid.short(), ansi::ForestFormat::Uid)
-        );
-
-        // print note date
-        let note_datetime: DateTime<Local> =
-            DateTime::from_timestamp_millis(note.date).unwrap().into();
-        print!(
-            "{} {} ",
-            ansi::format(
-                &note_datetime.format("%Y-%m-%d").to_string(),
-                ansi::ForestFormat::Date
-            ),
-            ansi::format(
-                &note_datetime.format("%H:%M").to_string(),
-                ansi::ForestFormat::Time
-            ),
-        );
-
-        if show_time_tracking {
-            if note.time_tracking == 1 {
-                print!("tt   ");
-            } else {
-                print!("user ");
-            }
+        let note_tags = note.tags.unwrap_or_default();
+        if !tag_filter.is_empty()
+            && !note_tags
+                .split_whitespace()
+                .any(|tag| tag_filter.iter().any(|filter| filter == tag))
+        {
+            continue;
         }
-        // print tree_name with padding for alignment
-        print!(
-            "{:width$} ",
-            ansi::format(&note.tree_name, ansi::ForestFormat::TreeName),
-            width = &max_tree_name_length
-        );
+
+        let note_uid = Uid::try_from(note.id).unwrap();
 
         // try to open note file to display its first line
         let note_file_path = dbutils::get_note_path(&note_uid)
@@ -274,14 +364,30 @@ pub async fn list(show_time_tracking: bool) -> Result<(), Box<dyn Error>> {
         let note_file = File::open(note_file_path).expect("Path to the note file should exist");
         let reader = io::BufReader::new(note_file);
 
-        // try to get first line of file if any to print a "note preview"
-        match reader.lines().next() {
-            Some(line) => println!("{}", line.expect("Failed to read first line of note file")),
-            None => println!(),
-        }
+        // try to get first line of file if any to get a "note preview"
+        let preview = reader
+            .lines()
+            .next()
+            .map(|line| line.expect("Failed to read first line of note file"));
+
+        notes.push(NoteListItem {
+            uid: note_uid.to_string(),
+            date: output::format_rfc3339(note.date),
+            tree: note.tree_name,
+            time_tracking: note.time_tracking == 1,
+            preview,
+            tags: note_tags.split_whitespace().map(String::from).collect(),
+            timestamp: note.date,
+        });
     }
 
-    Ok(())
+    output::print(
+        &NoteList {
+            notes,
+            show_time_tracking,
+        },
+        output,
+    )
 }
 
 /// Remove a note
@@ -326,6 +432,8 @@ pub async fn remove(partial_uid: &String) -> Result<(), Box<dyn Error>> {
         Err(query_error) => panic!("Database query failed: {query_error}"),
     }
 
+    tag::remove_unused(&mut conn).await;
+
     println!(
         "Removed note {}",
         ansi::format(uid.short(), ansi::ForestFormat::Uid)
@@ -462,3 +570,105 @@ pub async fn show(partial_uid: &String) -> Result<(), Box<dyn Error>> {
 
     Ok(())
 }
+
+/// Add tags to a note
+///
+/// # Errors
+/// Returns an error if the note does not exist
+///
+/// # Panics
+/// This function may panic if database operations fail
+pub async fn tag(partial_uid: &String, tags: &[String]) -> Result<(), Box<dyn Error>> {
+    let pool = dbutils::load_db().await;
+
+    let mut conn = pool
+        .acquire()
+        .await
+        .expect("Acquiring connection to database should succeed");
+
+    let uid = find_uid_from_partial(partial_uid).await?;
+
+    tag::create_if_not_exist(&mut conn, tags).await;
+
+    // link tags to the note, ignoring the ones it already has
+    let tags_json = tag::json_array(tags);
+    let query_result = sqlx::query!(
+        r#"
+        INSERT OR IGNORE INTO note_tag("note_id", "tag_name")
+        SELECT ?, value FROM json_each(?);
+        "#,
+        uid,
+        tags_json,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    // error handling
+    if let Err(query_error) = query_result {
+        panic!("Database query failed: {query_error}");
+    }
+
+    println!(
+        "Tagged note {} with {}",
+        ansi::format(uid.short(), ansi::ForestFormat::Uid),
+        tag::format(&tags.join(" ")),
+    );
+
+    Ok(())
+}
+
+/// Remove tags from a note
+///
+/// # Errors
+/// Returns an error if the note does not exist or if it has none of the given tags
+///
+/// # Panics
+/// This function may panic if database operations fail
+pub async fn untag(partial_uid: &String, tags: &[String]) -> Result<(), Box<dyn Error>> {
+    let pool = dbutils::load_db().await;
+
+    let mut conn = pool
+        .acquire()
+        .await
+        .expect("Acquiring connection to database should succeed");
+
+    let uid = find_uid_from_partial(partial_uid).await?;
+
+    // unlink tags from the note
+    let tags_json = tag::json_array(tags);
+    let query_result = sqlx::query!(
+        r#"
+        DELETE FROM note_tag
+        WHERE note_id = ? AND tag_name IN (SELECT value FROM json_each(?));
+        "#,
+        uid,
+        tags_json,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    // error handling
+    match query_result {
+        Ok(result) => {
+            if result.rows_affected() < 1 {
+                return Err(format!(
+                    "Note '{}' has none of the tags {}",
+                    uid.short(),
+                    tags.join(", ")
+                )
+                .into());
+            }
+        }
+        Err(query_error) => panic!("Database query failed: {query_error}"),
+    }
+
+    tag::remove_unused(&mut conn).await;
+
+    println!(
+        "Removed tags {} from note {}",
+        tag::format(&tags.join(" ")),
+        ansi::format(uid.short(), ansi::ForestFormat::Uid),
+    );
+
+    Ok(())
+}
@@ -0,0 +1,113 @@
+use chrono::{DateTime, FixedOffset, Local};
+use serde::Serialize;
+use std::error::Error;
+use std::io::{self, Write};
+
+use forest_types::OutputFormat;
+
+/// Data that can be printed in any output format
+///
+/// Structured formats (JSON) serialize the data itself, while tabular formats (CSV and Markdown)
+/// serialize the flat records returned by `rows`.
+pub(super) trait Render: Serialize {
+    /// Flat record used by tabular formats
+    type Row: Serialize;
+
+    /// Prints the data as human-readable colored text
+    fn print_text(&self);
+
+    /// Returns the data as flat records
+    fn rows(&self) -> Vec<Self::Row>;
+}
+
+/// Prints the given data in the given output format
+///
+/// # Errors
+/// Returns an error if the data cannot be serialized or written to the standard output
+pub(super) fn print<T: Render>(data: &T, format: OutputFormat) -> Result<(), Box<dyn Error>> {
+    match format {
+        OutputFormat::Text => data.print_text(),
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(data)?),
+        OutputFormat::Csv => {
+            let mut writer = csv::Writer::from_writer(io::stdout());
+            for row in data.rows() {
+                writer.serialize(row)?;
+            }
+            writer.flush()?;
+        }
+        OutputFormat::Markdown => print_markdown(&data.rows())?,
+    }
+
+    Ok(())
+}
+
+/// Prints records as a Markdown table
+///
+/// Records are serialized to CSV first, so that the column names and the formatting of values
+/// are the same in both tabular formats.
+///
+/// # Errors
+/// Returns an error if the records cannot be serialized or written to the standard output
+fn print_markdown<R: Serialize>(rows: &[R]) -> Result<(), Box<dyn Error>> {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    for row in rows {
+        writer.serialize(row)?;
+    }
+    let csv_data = writer.into_inner()?;
+
+    let mut reader = csv::Reader::from_reader(csv_data.as_slice());
+    let headers = reader.headers()?.clone();
+    if headers.is_empty() {
+        return Ok(());
+    }
+
+    // pipes would end cells early, and newlines would end rows early
+    let escape = |cell: &str| cell.replace('|', "\\|").replace('\n', "<br>");
+    let format_row = |cells: Vec<String>| format!("| {} |", cells.join(" | "));
+
+    let mut stdout = io::stdout().lock();
+    writeln!(
+        stdout,
+        "{}",
+        format_row(headers.iter().map(escape).collect())
+    )?;
+    writeln!(
+        stdout,
+        "{}",
+        format_row(headers.iter().map(|_| String::from("---")).collect())
+    )?;
+    for record in reader.records() {
+        writeln!(
+            stdout,
+            "{}",
+            format_row(record?.iter().map(escape).collect())
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Returns the RFC 3339 representation of a timestamp in milliseconds, in local time
+pub(super) fn format_rfc3339(timestamp: i64) -> String {
+    let datetime: DateTime<Local> = DateTime::from_timestamp_millis(timestamp).unwrap().into();
+    datetime.to_rfc3339()
+}
+
+/// Returns the date and time of a timestamp in milliseconds at the given offset from UTC in
+/// seconds, typically the offset at which it was recorded, or in local time if there is none
+pub(super) fn at_offset(timestamp: i64, utc_offset: Option<i64>) -> DateTime<FixedOffset> {
+    let datetime = DateTime::from_timestamp_millis(timestamp).unwrap();
+    match utc_offset
+        .and_then(|utc_offset| i32::try_from(utc_offset).ok())
+        .and_then(FixedOffset::east_opt)
+    {
+        Some(utc_offset) => datetime.with_timezone(&utc_offset),
+        None => datetime.with_timezone(&Local).fixed_offset(),
+    }
+}
+
+/// Returns the offset from UTC in seconds of the local time at a timestamp in milliseconds
+pub(super) fn local_utc_offset(timestamp: i64) -> i64 {
+    let datetime: DateTime<Local> = DateTime::from_timestamp_millis(timestamp).unwrap().into();
+    datetime.offset().local_minus_utc().into()
+}
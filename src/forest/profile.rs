@@ -0,0 +1,146 @@
+use serde::Serialize;
+use std::error::Error;
+use std::fs;
+
+use super::ansi;
+use super::dbutils;
+use super::output::{self, Render};
+use forest_types::OutputFormat;
+
+/// Forest of the list of forests
+#[derive(Serialize, Clone)]
+struct ProfileListItem {
+    name: String,
+    /// directory storing the database and the notes of the forest
+    path: String,
+    current: bool,
+}
+
+/// List of forests
+#[derive(Serialize)]
+struct ProfileList {
+    profiles: Vec<ProfileListItem>,
+    /// environment variable choosing the forest instead of profiles, if any
+    environment: Option<String>,
+}
+
+impl Render for ProfileList {
+    type Row = ProfileListItem;
+
+    fn print_text(&self) {
+        for profile in &self.profiles {
+            println!(
+                "{} {} ({})",
+                if profile.current { "*" } else { " " },
+                ansi::format(&profile.name, ansi::ForestFormat::TreeName),
+                profile.path,
+            );
+        }
+
+        if let Some(var) = &self.environment {
+            println!();
+            println!("{var} is set, so the forest it points to is used instead");
+        }
+    }
+
+    fn rows(&self) -> Vec<Self::Row> {
+        self.profiles.clone()
+    }
+}
+
+/// Returns the directory storing the given forest, for display
+fn format_profile_dir(name: &str) -> String {
+    dbutils::get_profile_dir(name)
+        .unwrap_or_else(dbutils::get_config_home)
+        .display()
+        .to_string()
+}
+
+/// Lists all forests, marking the one in use
+///
+/// # Errors
+/// Returns an error if the list cannot be printed
+pub fn list(output: OutputFormat) -> Result<(), Box<dyn Error>> {
+    let current = dbutils::get_current_profile();
+
+    let profiles = dbutils::get_profile_names()
+        .into_iter()
+        .map(|name| ProfileListItem {
+            path: format_profile_dir(&name),
+            current: current.as_ref() == Some(&name),
+            name,
+        })
+        .collect();
+
+    let environment = [dbutils::FOREST_DB_VAR, dbutils::FOREST_HOME_VAR]
+        .into_iter()
+        .find(|var| current.is_none() && dbutils::get_env_path(var).is_some())
+        .map(String::from);
+
+    output::print(
+        &ProfileList {
+            profiles,
+            environment,
+        },
+        output,
+    )
+}
+
+/// Creates a new empty forest
+///
+/// # Errors
+/// Returns an error if the forest already exists or if its directory cannot be created
+pub fn create(name: &String) -> Result<(), Box<dyn Error>> {
+    let dir = match dbutils::get_profile_dir(name) {
+        Some(dir) if !dir.exists() => dir,
+        _ => return Err(format!("Forest '{name}' already exists").into()),
+    };
+
+    fs::create_dir_all(&dir)
+        .map_err(|e| format!("Cannot create forest directory '{}': {e}", dir.display()))?;
+
+    println!(
+        "Created forest {} in {}",
+        ansi::format(name, ansi::ForestFormat::TreeName),
+        dir.display()
+    );
+
+    Ok(())
+}
+
+/// Returns an error if the given forest does not exist
+fn check_exists(name: &String) -> Result<(), Box<dyn Error>> {
+    if dbutils::get_profile_dir(name).is_some_and(|dir| !dir.is_dir()) {
+        return Err(format!(
+            "Forest '{name}' does not exist, create it with `forest profile create {name}`"
+        )
+        .into());
+    }
+    Ok(())
+}
+
+/// Uses the given forest for this run only, instead of the current one
+///
+/// # Errors
+/// Returns an error if the forest does not exist
+pub fn select(name: String) -> Result<(), Box<dyn Error>> {
+    check_exists(&name)?;
+    dbutils::select_profile(name);
+    Ok(())
+}
+
+/// Uses the given forest by default, until another one is used
+///
+/// # Errors
+/// Returns an error if the forest does not exist or if the setting cannot be saved
+pub fn use_profile(name: &String) -> Result<(), Box<dyn Error>> {
+    check_exists(name)?;
+    dbutils::set_active_profile(name)?;
+
+    println!(
+        "Now using forest {}",
+        ansi::format(name, ansi::ForestFormat::TreeName)
+    );
+
+    Ok(())
+}
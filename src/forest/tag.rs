@@ -0,0 +1,115 @@
+use sqlx::SqliteConnection;
+
+use super::ansi;
+use super::dbutils;
+
+/// Returns the names of all tags of the forest
+///
+/// # Panics
+/// This function may panic if database operations fail
+pub async fn names() -> Vec<String> {
+    let pool = dbutils::load_db().await;
+
+    let mut conn = pool
+        .acquire()
+        .await
+        .expect("Acquiring connection to database should succeed");
+
+    let query_result = sqlx::query!(
+        r#"
+        SELECT name
+        FROM tag
+        ORDER BY name ASC;
+        "#
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    match query_result {
+        Ok(records) => records.into_iter().map(|tag| tag.name).collect(),
+        Err(query_error) => panic!("Database query failed: {query_error}"),
+    }
+}
+
+/// Formats a list of tags as a JSON array, so that it can be bound to a single query parameter and
+/// expanded with SQLite's `json_each`
+///
+/// Tags are validated by `forest_types::tag_parser`, so they never need escaping.
+pub(super) fn json_array(tags: &[String]) -> String {
+    let quoted: Vec<String> = tags.iter().map(|tag| format!("\"{tag}\"")).collect();
+    format!("[{}]", quoted.join(","))
+}
+
+/// Formats a space separated list of tags for display
+pub(super) fn format(tags: &str) -> String {
+    tags.split_whitespace()
+        .map(|tag| ansi::format(&format!("#{tag}"), ansi::ForestFormat::Tag))
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+/// Inserts the given tags into the tag table, if they do not exist yet
+///
+/// # Panics
+/// This function may panic if database operations fail
+pub(super) async fn create_if_not_exist(conn: &mut SqliteConnection, tags: &[String]) {
+    let tags = json_array(tags);
+    let query_result = sqlx::query!(
+        r#"
+        INSERT OR IGNORE INTO tag("name")
+        SELECT value FROM json_each(?);
+        "#,
+        tags,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    if let Err(query_error) = query_result {
+        panic!("Database query failed: {query_error}");
+    }
+}
+
+/// Attaches the given tags to a task, ignoring the ones it already has
+///
+/// # Panics
+/// This function may panic if database operations fail
+pub(super) async fn attach_to_task(conn: &mut SqliteConnection, task_id: &String, tags: &[String]) {
+    create_if_not_exist(conn, tags).await;
+
+    let tags_json = json_array(tags);
+    let query_result = sqlx::query!(
+        r#"
+        INSERT OR IGNORE INTO task_tag("task_id", "tag_name")
+        SELECT ?, value FROM json_each(?);
+        "#,
+        task_id,
+        tags_json,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    if let Err(query_error) = query_result {
+        panic!("Database query failed: {query_error}");
+    }
+}
+
+/// Removes tags that are not attached to any task nor note anymore
+///
+/// # Panics
+/// This function may panic if database operations fail
+pub(super) async fn remove_unused(conn: &mut SqliteConnection) {
+    let query_result = sqlx::query!(
+        r#"
+        DELETE FROM tag
+        WHERE
+            name NOT IN (SELECT tag_name FROM task_tag) AND
+            name NOT IN (SELECT tag_name FROM note_tag);
+        "#
+    )
+    .execute(&mut *conn)
+    .await;
+
+    if let Err(query_error) = query_result {
+        panic!("Database query failed: {query_error}");
+    }
+}
@@ -1,10 +1,38 @@
 use std::error::Error;
+use std::path::PathBuf;
+
+use chrono::{DateTime, Local};
+use serde::Serialize;
+use sqlx::SqliteConnection;
 
 use super::ansi;
 use super::dbutils;
-use forest_types::{Priority, Uid};
+use super::import;
+use super::output::{self, Render};
+use super::tag;
+use super::timetracking;
+use forest_types::{OutputFormat, Priority, Recurrence, TaskImportFormat, TaskStatus, Uid};
+
+/// Parses an optional user datetime into a timestamp in milliseconds, as stored in database
+///
+/// # Errors
+/// Returns an error if the input string is ill-formed
+fn parse_optional_datetime(datetime: &Option<String>) -> Result<Option<i64>, Box<dyn Error>> {
+    match datetime {
+        Some(_) => Ok(Some(
+            timetracking::parse_user_datetime(datetime)?.timestamp_millis(),
+        )),
+        None => Ok(None),
+    }
+}
+
+/// Formats a timestamp in milliseconds as a local date and time
+pub(super) fn format_timestamp(timestamp: i64, fmt: ansi::ForestFormat) -> String {
+    let datetime: DateTime<Local> = DateTime::from_timestamp_millis(timestamp).unwrap().into();
+    ansi::format(&datetime.format("%Y-%m-%d %H:%M").to_string(), fmt)
+}
 
-/// Finds a UID in task table that matches the provided partial uid
+/// Finds a UID in task table that matches the provided partial uid, in the current tree
 ///
 /// # Errors
 /// Returns an error if no uid is found or if more than one uid fits the partial uid
@@ -16,20 +44,38 @@ async fn find_uid_from_partial(short_uid: &String) -> Result<Uid, Box<dyn Error>
 
     let current_tree_name = dbutils::get_current_tree_name(&pool).await?;
 
+    find_uid_from_partial_in_forest(short_uid, Some(&current_tree_name)).await
+}
+
+/// Finds a UID in task table that matches the provided partial uid, in any tree of the forest or
+/// only in the given tree
+///
+/// # Errors
+/// Returns an error if no uid is found or if more than one uid fits the partial uid
+///
+/// # Panic
+/// This function may panic if db operations fail
+pub(super) async fn find_uid_from_partial_in_forest(
+    short_uid: &String,
+    tree_name: Option<&String>,
+) -> Result<Uid, Box<dyn Error>> {
+    let pool = dbutils::load_db().await;
+
     let mut conn = pool
         .acquire()
         .await
         .expect("Acquiring connection to database should succeed");
 
-    // get all uids from this current tree, that match the provided short uid, except for the root
-    // task
+    // get all uids from the given tree, or from all trees, that match the provided short uid,
+    // except for the roots
     let query_result = sqlx::query!(
         r#"
-        SELECT id, name
+        SELECT id, name, tree_name
         FROM task
-        WHERE tree_name = ? AND "left" != 1 AND id LIKE ? || '%';
+        WHERE (? IS NULL OR tree_name = ?) AND "left" != 1 AND id LIKE ? || '%';
         "#,
-        current_tree_name,
+        tree_name,
+        tree_name,
         short_uid,
     )
     .fetch_all(&mut *conn)
@@ -43,13 +89,21 @@ async fn find_uid_from_partial(short_uid: &String) -> Result<Uid, Box<dyn Error>
         Ok(mut records) => {
             // if no task matching short uid was found
             if records.is_empty() {
-                Err(format!("Task '{short_uid}' not found in tree '{current_tree_name}'").into())
+                match tree_name {
+                    Some(tree_name) => {
+                        Err(format!("Task '{short_uid}' not found in tree '{tree_name}'").into())
+                    }
+                    None => Err(format!("Task '{short_uid}' not found in forest").into()),
+                }
 
                 // if more than one task matches the short uid
             } else if records.len() > 1 {
                 let mut error_message = format!("At least two tasks match '{short_uid}...':\n");
                 for task in records {
-                    error_message.push_str(&format!("- {}: {}\n", task.id, task.name));
+                    error_message.push_str(&format!(
+                        "- {}: {} (tree {})\n",
+                        task.id, task.name, task.tree_name
+                    ));
                 }
                 error_message
                     .push_str("Please try to be more precise when refering to task uids\n");
@@ -67,42 +121,55 @@ async fn find_uid_from_partial(short_uid: &String) -> Result<Uid, Box<dyn Error>
     }
 }
 
-/// Adds a task to the current tree
+/// Returns the names of the ancestors of a task, from the top-level task down to the task itself
 ///
-/// # Errors
-/// Returns an error if the parent uid does not exist in the current tree
+/// The root task of the tree is not part of the path, so the path of a root task is empty.
 ///
 /// # Panics
 /// This function may panic if database operations fail
-pub async fn add(
-    name: String,
-    parent_uid: Option<&String>,
-    description: String,
-    edit: bool,
-) -> Result<(), Box<dyn Error>> {
-    let pool = dbutils::load_db().await;
-
-    let current_tree_name = dbutils::get_current_tree_name(&pool).await?;
-
-    let mut conn = pool
-        .acquire()
-        .await
-        .expect("Acquiring connection to database should succeed");
+pub(super) async fn path(conn: &mut SqliteConnection, uid: &String) -> Vec<String> {
+    let query_result = sqlx::query!(
+        r#"
+        SELECT a.name
+        FROM task a INNER JOIN task t ON a.tree_name = t.tree_name
+        WHERE
+            t.id = ? AND
+            a."left" != 1 AND
+            a."left" <= t."left" AND t."right" <= a."right"
+        ORDER BY a."left";
+        "#,
+        uid,
+    )
+    .fetch_all(&mut *conn)
+    .await;
 
-    let task_description = if edit {
-        edit::edit(description).expect("Could not open default editor")
-    } else {
-        description
-    };
+    match query_result {
+        Ok(records) => records.into_iter().map(|record| record.name).collect(),
+        Err(query_error) => panic!("Database query failed: {query_error}"),
+    }
+}
 
-    let parent_right = match parent_uid {
+/// Returns the id of the task of the given uid in the given tree, or of the root of the tree if
+/// no uid is given
+///
+/// # Errors
+/// Returns an error if the uid does not exist in the tree
+///
+/// # Panics
+/// This function may panic if database operations fail
+async fn get_parent_id(
+    conn: &mut SqliteConnection,
+    current_tree_name: &String,
+    parent_uid: Option<&String>,
+) -> Result<String, Box<dyn Error>> {
+    match parent_uid {
         Some(partial_uid) => {
             let uid = find_uid_from_partial(partial_uid).await?;
 
-            // get parent's "right" field
+            // check that the parent is in the current tree
             let query_result = sqlx::query!(
                 r#"
-                SELECT "right", id, name
+                SELECT id
                 FROM task
                 WHERE tree_name = ? AND id = ?;
                 "#,
@@ -113,26 +180,21 @@ pub async fn add(
             .await;
 
             // error handling
-            let parent_task = match query_result {
-                Ok(record) => record,
+            match query_result {
+                Ok(record) => Ok(record.id),
                 Err(query_error) => match query_error {
                     sqlx::Error::RowNotFound => {
-                        return Err(
-                            format!("Task '{uid}' not found in tree '{current_tree_name}'").into(),
-                        )
+                        Err(format!("Task '{uid}' not found in tree '{current_tree_name}'").into())
                     }
                     other_error => panic!("Database query failed: {other_error}"),
                 },
-            };
-            parent_task.right
+            }
         }
         None => {
             // if no parent_uid, parent is tree root
-
-            // get tree root's "right" field
             let tree_root = sqlx::query!(
                 r#"
-                SELECT "right"
+                SELECT id
                 FROM task
                 WHERE tree_name = ? AND "left" = 1;
                 "#,
@@ -142,64 +204,243 @@ pub async fn add(
             .await
             .expect("Should be able to get the root of the current tree");
 
-            tree_root.right
+            Ok(tree_root.id)
         }
+    }
+}
+
+/// Adds a task to the current tree
+///
+/// # Errors
+/// Returns an error if the parent uid does not exist in the current tree
+///
+/// # Panics
+/// This function may panic if database operations fail
+pub async fn add(
+    name: String,
+    parent_uid: Option<&String>,
+    description: String,
+    edit: bool,
+    due: Option<String>,
+    scheduled: Option<String>,
+    recurrence: Option<Recurrence>,
+) -> Result<(), Box<dyn Error>> {
+    // parse dates before touching the database so that ill-formed dates do not leave any trace
+    let due = parse_optional_datetime(&due)?;
+    let scheduled = parse_optional_datetime(&scheduled)?;
+    let recurrence = recurrence.map(|rule| rule.to_string());
+
+    let pool = dbutils::load_db().await;
+
+    let current_tree_name = dbutils::get_current_tree_name(&pool).await?;
+
+    let mut conn = pool
+        .acquire()
+        .await
+        .expect("Acquiring connection to database should succeed");
+
+    let task_description = if edit {
+        edit::edit(description).expect("Could not open default editor")
+    } else {
+        description
     };
 
-    let new_task_uid = Uid::new();
+    let parent_id = get_parent_id(&mut conn, &current_tree_name, parent_uid).await?;
 
-    // check that new uid's short version is not in the db
-    if find_uid_from_partial(&new_task_uid.short().to_string())
+    let new_task_uid = insert_child(
+        &mut conn,
+        &parent_id,
+        &name,
+        &task_description,
+        due,
+        scheduled,
+        recurrence,
+    )
+    .await;
+
+    println!(
+        "Added task {} ({}) to tree {}",
+        ansi::format(&name, ansi::ForestFormat::TaskName),
+        ansi::format(new_task_uid.short(), ansi::ForestFormat::Uid),
+        ansi::format(&current_tree_name, ansi::ForestFormat::TreeName)
+    );
+
+    Ok(())
+}
+
+/// Imports tasks from another task manager into the current tree
+///
+/// Imported tasks are added under the given parent task, or under the root of the tree, keeping
+/// their hierarchy. Nothing is imported if any task cannot be.
+///
+/// # Errors
+/// Returns an error if the file cannot be read or is ill-formed, or if the parent uid does not
+/// exist in the current tree
+///
+/// # Panics
+/// This function may panic if database operations fail
+pub async fn import(
+    format: TaskImportFormat,
+    path: &PathBuf,
+    parent_uid: Option<&String>,
+) -> Result<(), Box<dyn Error>> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| format!("Cannot read '{}': {e}", path.display()))?;
+    let tasks = import::read_tasks(format, &content)?;
+    if tasks.is_empty() {
+        return Err(format!("No task found in '{}'", path.display()).into());
+    }
+
+    let pool = dbutils::load_db().await;
+
+    let current_tree_name = dbutils::get_current_tree_name(&pool).await?;
+
+    let mut transaction = pool
+        .begin()
+        .await
+        .expect("Beginning a transaction should succeed");
+
+    let parent_id = get_parent_id(&mut transaction, &current_tree_name, parent_uid).await?;
+
+    // ids of the imported tasks, in the same order, so that children can find their parent
+    let mut task_ids: Vec<String> = Vec::with_capacity(tasks.len());
+    for task in &tasks {
+        let task_parent_id = match task.parent {
+            Some(index) => &task_ids[index],
+            None => &parent_id,
+        };
+        let task_id = insert_child(
+            &mut transaction,
+            task_parent_id,
+            &task.name,
+            &task.description,
+            task.due,
+            task.scheduled,
+            None,
+        )
         .await
-        .is_ok()
-    {
-        panic!("Birthday paradox hit");
+        .to_string();
+
+        if task.status != TaskStatus::Todo {
+            let status = task.status.as_str();
+            let query_result = sqlx::query!(
+                r#"
+                UPDATE task
+                SET status = ?
+                WHERE id = ?;
+                "#,
+                status,
+                task_id,
+            )
+            .execute(&mut *transaction)
+            .await;
+
+            // error handling
+            if let Err(query_error) = query_result {
+                panic!("Database query failed: {query_error}");
+            }
+        }
+
+        if !task.tags.is_empty() {
+            tag::attach_to_task(&mut transaction, &task_id, &task.tags).await;
+        }
+
+        task_ids.push(task_id);
     }
-    // update position of all tasks at the right of the parent
+
+    transaction
+        .commit()
+        .await
+        .expect("Committing a transaction should succeed");
+
+    println!(
+        "Imported {} tasks to tree {}",
+        tasks.len(),
+        ansi::format(&current_tree_name, ansi::ForestFormat::TreeName)
+    );
+
+    Ok(())
+}
+
+/// Inserts a new task as the last child of the given parent task, in the tree of its parent
+///
+/// Returns the uid of the new task.
+///
+/// # Panics
+/// This function may panic if the parent does not exist or if database operations fail
+pub(super) async fn insert_child(
+    conn: &mut SqliteConnection,
+    parent_id: &String,
+    name: &String,
+    description: &String,
+    due: Option<i64>,
+    scheduled: Option<i64>,
+    recurrence: Option<String>,
+) -> Uid {
     let query_result = sqlx::query!(
         r#"
-        UPDATE task
-        SET 
-            -- all tasks at the right of the inserted task should be shifted right
-            -- by the width of the task, ie. 2
+        SELECT tree_name, "right"
+        FROM task
+        WHERE id = ?;
+        "#,
+        parent_id,
+    )
+    .fetch_one(&mut *conn)
+    .await;
 
-            "left" = CASE WHEN "left" > ? THEN "left" + 2 ELSE "left" END,
-            "right" = CASE WHEN "right" >= ? THEN "right" + 2 ELSE "right" END
+    // error handling
+    let parent = match query_result {
+        Ok(record) => record,
+        Err(query_error) => panic!("Database query failed: {query_error}"),
+    };
 
-        WHERE "right" >= ? AND tree_name = ?;
+    let new_task_uid = Uid::new();
+
+    // check that new uid's short version is not in the db
+    let short_uid = new_task_uid.short().to_string();
+    let query_result = sqlx::query!(
+        r#"
+        SELECT COUNT(*) AS "count!: i64"
+        FROM task
+        WHERE id LIKE ? || '%';
         "#,
-        parent_right,
-        parent_right,
-        parent_right,
-        current_tree_name
+        short_uid,
     )
-    .execute(&mut *conn)
+    .fetch_one(&mut *conn)
     .await;
 
     // error handling
     match query_result {
-        Ok(result) => {
-            if result.rows_affected() < 1 {
-                panic!("Insertion should at least update one row (root)");
+        Ok(record) => {
+            if record.count > 0 {
+                panic!("Birthday paradox hit");
             }
         }
         Err(query_error) => panic!("Database query failed: {query_error}"),
-    };
+    }
 
-    // insert the new task
-    let new_task_left = parent_right;
-    let new_task_right = parent_right + 1;
+    // all tasks at the right of the inserted task are shifted right by the width of the task
+    shift_tasks(conn, &parent.tree_name, parent.right, 2).await;
+
+    let new_task_left = parent.right;
+    let new_task_right = parent.right + 1;
     let query_result = sqlx::query!(
         r#"
-        INSERT INTO task("id", "tree_name", "left", "right", "name", "description")
-        VALUES(?, ?, ?, ?, ?, ?);
+        INSERT INTO task(
+            "id", "tree_name", "left", "right", "name", "description", "due", "scheduled",
+            "recurrence"
+        )
+        VALUES(?, ?, ?, ?, ?, ?, ?, ?, ?);
         "#,
         new_task_uid,
-        current_tree_name,
+        parent.tree_name,
         new_task_left,
         new_task_right,
         name,
-        task_description,
+        description,
+        due,
+        scheduled,
+        recurrence,
     )
     .execute(&mut *conn)
     .await;
@@ -222,14 +463,7 @@ pub async fn add(
         },
     }
 
-    println!(
-        "Added task {} ({}) to tree {}",
-        ansi::format(&name, ansi::ForestFormat::TaskName),
-        ansi::format(new_task_uid.short(), ansi::ForestFormat::Uid),
-        ansi::format(&current_tree_name, ansi::ForestFormat::TreeName)
-    );
-
-    Ok(())
+    new_task_uid
 }
 
 /// Removes a task from the current tree
@@ -277,6 +511,8 @@ pub async fn remove(partial_uid: &String) -> Result<(), Box<dyn Error>> {
     };
 
     // remove the task and all subtasks
+    // because of ON DELETE CASCADE constraints, frames, notes, tags and dependencies of the removed
+    // tasks are removed too
     let removed_task_left = task.left;
     let removed_task_right = task.right;
     let shift = task.right - task.left + 1;
@@ -335,6 +571,8 @@ pub async fn remove(partial_uid: &String) -> Result<(), Box<dyn Error>> {
         Err(query_error) => panic!("Database query failed: {query_error}"),
     };
 
+    tag::remove_unused(&mut conn).await;
+
     println!(
         "Removed task {} ({}) from tree {}",
         ansi::format(&task.name, ansi::ForestFormat::TaskName),
@@ -490,117 +728,339 @@ pub async fn edit(partial_uid: &String) -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
-/// Lists all tasks in the current tree
-pub async fn list() -> Result<(), Box<dyn Error>> {
-    let pool = dbutils::load_db().await;
-
-    let current_tree_name = dbutils::get_current_tree_name(&pool).await?;
-
-    let mut conn = pool
-        .acquire()
-        .await
-        .expect("Acquiring connection to database should succeed");
-
-    // get all tasks of a tree, order by ascending "left"
-    let task_vec = sqlx::query!(
-        r#"
-        SELECT id, name, "right", "left"
-        FROM task
-        WHERE tree_name = ?
-        ORDER BY "left" ASC;
-        "#,
-        current_tree_name,
-    )
-    .fetch_all(&mut *conn)
-    .await
-    .expect("Should be able to get tasks from the tree");
-
-    // this stack will hold the "right" field of the ancestor tasks as we traverse the tree
-    // it is used to print indent characters
-    let mut stack: Vec<i64> = Vec::new();
+/// Returns the marker displayed in front of a task with the given status
+fn status_marker(status: TaskStatus) -> &'static str {
+    match status {
+        TaskStatus::Todo => "[ ]",
+        TaskStatus::InProgress => "[~]",
+        TaskStatus::Done => "[x]",
+        TaskStatus::Cancelled => "[-]",
+    }
+}
 
-    // print tree name as a header
-    println!(
-        "{}",
-        ansi::format(&current_tree_name, ansi::ForestFormat::TreeName)
-    );
+/// Computes the box-drawing indent to print in front of each node of a tree.
+///
+/// `bounds` holds the ("left", "right") nested-set boundaries of the nodes to display, ordered by
+/// ascending "left". The first node is the root of the tree: it is not indented and no indent is
+/// returned for it. Nodes can be a subset of the tree, as long as the ancestors of every node are
+/// part of this subset too.
+pub(super) fn tree_indents(bounds: &[(i64, i64)]) -> Vec<String> {
+    // index of the displayed parent of each node
+    let mut parents: Vec<usize> = vec![0; bounds.len()];
+
+    // this stack will hold the indices of the ancestors of the node being processed
+    let mut stack: Vec<usize> = Vec::new();
+
+    for (index, &(left, _)) in bounds.iter().enumerate() {
+        // pop all previous nodes of which the current node is not a descendant
+        while let Some(&ancestor) = stack.last() {
+            if left > bounds[ancestor].1 {
+                stack.pop();
+            } else {
+                break;
+            }
+        }
+        if let Some(&parent) = stack.last() {
+            parents[index] = parent;
+        }
+        stack.push(index);
+    }
 
-    let mut task_iter = task_vec.iter();
+    // a node is the youngest (ie. last) child of its parent if no later node shares its parent
+    let mut is_last = vec![false; bounds.len()];
+    let mut seen_parents: Vec<usize> = Vec::new();
+    for index in (1..bounds.len()).rev() {
+        if !seen_parents.contains(&parents[index]) {
+            is_last[index] = true;
+            seen_parents.push(parents[index]);
+        }
+    }
 
-    // the first "task" of the tree is not really a task per se
-    // it just represents the root of the tree, meaning all tasks of the tree are descendants of
-    // this root "task"
-    //
-    // so here, we push its "right" value to the stack for later indenting and we "skip" it with
-    // the "next" method.
-    stack.push(task_iter.next().unwrap().right);
-
-    for task in task_iter {
-        // if the current task is "out" of the boundaries of previous parent tasks, it means we
-        // processed all descendants of these parent tasks, and are now processing "siblings" or
-        // ancestors of these parent tasks
-        // therefore, we can pop them out from the stack
-        while task.left > *stack.last().unwrap() {
-            stack.pop();
-        }
-
-        // print indent based on the number of ancestors of the current task
-        //
-        // foreach ancestor in the stack (starting at the root and descending to the current task)
-        // check whether each ancestor is the younger (ie. last) child of its own parent or not.
-        let mut stack_iter = stack.iter().peekable();
-        while let Some(parent) = stack_iter.next() {
-            if let Some(&child) = stack_iter.peek() {
-                // if ancestor is youngest child of its parent, print an empty indent
-                if *child == parent - 1 {
-                    print!("  ");
+    (1..bounds.len())
+        .map(|index| {
+            // foreach ancestor (excluding the root), check whether it is the youngest child of its
+            // own parent or not, starting from the closest ancestor
+            let mut segments: Vec<String> = Vec::new();
+            let mut ancestor = parents[index];
+            while ancestor != 0 {
+                if is_last[ancestor] {
+                    segments.push(String::from("  "));
                 } else {
-                    print!(
+                    segments.push(format!(
                         "{} ",
                         ansi::format(&String::from("│"), ansi::ForestFormat::Box)
-                    );
+                    ));
                 }
+                ancestor = parents[ancestor];
             }
-        }
-
-        // if current task is the youngest (ie. last) child of its parent
-        // "stop" the vertical line here
-        if task.right == (stack.last().unwrap() - 1) {
-            print!(
-                "{}",
-                ansi::format(&String::from("└╴"), ansi::ForestFormat::Box),
-            );
-        } else {
-            print!(
-                "{}",
-                ansi::format(&String::from("├╴"), ansi::ForestFormat::Box),
-            );
-        }
-        print!(
-            "{} {}",
-            ansi::format(
-                Uid::try_from(task.id.clone()).unwrap().short(),
-                ansi::ForestFormat::Uid
-            ),
-            ansi::format(&task.name, ansi::ForestFormat::TaskName)
-        );
+            segments.reverse();
 
-        println!();
+            // if current node is the youngest child of its parent, "stop" the vertical line here
+            if is_last[index] {
+                segments.push(ansi::format(&String::from("└╴"), ansi::ForestFormat::Box));
+            } else {
+                segments.push(ansi::format(&String::from("├╴"), ansi::ForestFormat::Box));
+            }
 
-        // if task is a parent (ie. its width is higher than 1), then push its "right" value to the
-        // stack to increment the indent of its children, which are the task we will process in the
-        // next loop cycle
-        let width = task.right - task.left;
-        if width > 1 {
-            stack.push(task.right);
-        }
-    }
-    Ok(())
+            segments.concat()
+        })
+        .collect()
 }
 
-/// Shows the description of a task in the current tree
-pub async fn show(partial_uid: &String) -> Result<(), Box<dyn Error>> {
-    let pool = dbutils::load_db().await;
+/// Listing of the tasks of a tree
+#[derive(Serialize)]
+struct TaskList {
+    tree: String,
+    tasks: Vec<TaskListItem>,
+}
+
+/// Task of a tree listing
+#[derive(Serialize)]
+struct TaskListItem {
+    uid: String,
+    name: String,
+    parent: Option<String>,
+    depth: usize,
+    status: TaskStatus,
+    due: Option<String>,
+    recurrence: Option<String>,
+    blocked: bool,
+    tags: Vec<String>,
+
+    #[serde(skip)]
+    bounds: (i64, i64),
+    #[serde(skip)]
+    due_timestamp: Option<i64>,
+}
+
+/// Flat record of a task listing
+#[derive(Serialize)]
+struct TaskListRow {
+    tree: String,
+    uid: String,
+    name: String,
+    parent: Option<String>,
+    depth: usize,
+    status: TaskStatus,
+    due: Option<String>,
+    recurrence: Option<String>,
+    blocked: bool,
+    tags: String,
+}
+
+impl Render for TaskList {
+    type Row = TaskListRow;
+
+    fn print_text(&self) {
+        // print tree name as a header
+        println!("{}", ansi::format(&self.tree, ansi::ForestFormat::TreeName));
+
+        let now = Local::now().timestamp_millis();
+
+        // the root of the tree is not listed, but it is needed to compute indents
+        let bounds: Vec<(i64, i64)> = std::iter::once((i64::MIN, i64::MAX))
+            .chain(self.tasks.iter().map(|task| task.bounds))
+            .collect();
+
+        for (task, indent) in self.tasks.iter().zip(tree_indents(&bounds)) {
+            let status = task.status;
+
+            // open tasks whose due date is in the past are overdue
+            let overdue = status.is_open() && task.due_timestamp.is_some_and(|due| due < now);
+
+            let name = if !status.is_open() {
+                ansi::format(&task.name, ansi::ForestFormat::Closed)
+            } else if overdue {
+                ansi::format(&task.name, ansi::ForestFormat::Overdue)
+            } else {
+                ansi::format(&task.name, ansi::ForestFormat::TaskName)
+            };
+
+            print!(
+                "{}{} {} {}",
+                indent,
+                ansi::format(
+                    Uid::try_from(task.uid.clone()).unwrap().short(),
+                    ansi::ForestFormat::Uid
+                ),
+                ansi::format(status_marker(status), ansi::ForestFormat::Status),
+                name
+            );
+
+            if status.is_open() && task.recurrence.is_some() {
+                print!(" {}", ansi::format("↻", ansi::ForestFormat::Box));
+            }
+
+            if status.is_open() && task.blocked {
+                print!(
+                    " {}",
+                    ansi::format("(blocked)", ansi::ForestFormat::Blocked)
+                );
+            }
+
+            if !task.tags.is_empty() {
+                print!(" {}", tag::format(&task.tags.join(" ")));
+            }
+
+            if let Some(due) = task.due_timestamp {
+                if overdue {
+                    print!(
+                        " (due {})",
+                        format_timestamp(due, ansi::ForestFormat::Overdue)
+                    );
+                } else if status.is_open() {
+                    print!(" (due {})", format_timestamp(due, ansi::ForestFormat::Date));
+                }
+            }
+
+            println!();
+        }
+    }
+
+    fn rows(&self) -> Vec<Self::Row> {
+        self.tasks
+            .iter()
+            .map(|task| TaskListRow {
+                tree: self.tree.clone(),
+                uid: task.uid.clone(),
+                name: task.name.clone(),
+                parent: task.parent.clone(),
+                depth: task.depth,
+                status: task.status,
+                due: task.due.clone(),
+                recurrence: task.recurrence.clone(),
+                blocked: task.blocked,
+                tags: task.tags.join(" "),
+            })
+            .collect()
+    }
+}
+
+/// Lists all tasks in the current tree
+///
+/// If `status_filter` is not empty, only tasks with one of the given statuses are displayed,
+/// along with their ancestors. Likewise, if `tag_filter` is not empty, only tasks with one of the
+/// given tags are displayed.
+///
+/// # Errors
+/// Returns an error if the forest is empty
+///
+/// # Panics
+/// This function may panic if database operations fail
+pub async fn list(
+    status_filter: &[TaskStatus],
+    tag_filter: &[String],
+    output: OutputFormat,
+) -> Result<(), Box<dyn Error>> {
+    let pool = dbutils::load_db().await;
+
+    let current_tree_name = dbutils::get_current_tree_name(&pool).await?;
+
+    let mut conn = pool
+        .acquire()
+        .await
+        .expect("Acquiring connection to database should succeed");
+
+    // get all tasks of a tree, order by ascending "left"
+    let task_vec = sqlx::query!(
+        r#"
+        SELECT
+            id, name, "right", "left", status, due, recurrence,
+            (SELECT group_concat(tag_name, ' ') FROM task_tag WHERE task_id = task.id) AS "tags?: String",
+            EXISTS (
+                SELECT *
+                FROM dependency d INNER JOIN task b ON d.blocker_id = b.id
+                WHERE d.task_id = task.id AND b.status IN ('todo', 'in-progress')
+            ) AS "blocked: bool"
+        FROM task
+        WHERE tree_name = ?
+        ORDER BY "left" ASC;
+        "#,
+        current_tree_name,
+    )
+    .fetch_all(&mut *conn)
+    .await
+    .expect("Should be able to get tasks from the tree");
+
+    let statuses: Vec<TaskStatus> = task_vec
+        .iter()
+        .map(|task| TaskStatus::try_from(task.status.clone()).unwrap_or_default())
+        .collect();
+
+    // the first "task" of the tree is not really a task per se
+    // it just represents the root of the tree, meaning all tasks of the tree are descendants of
+    // this root "task". It is always kept.
+    //
+    // other tasks are kept if they match the filter, or if one of their descendants does
+    let matches = |index: usize| {
+        let task_tags = task_vec[index].tags.clone().unwrap_or_default();
+        (status_filter.is_empty() || status_filter.contains(&statuses[index]))
+            && (tag_filter.is_empty()
+                || task_tags
+                    .split_whitespace()
+                    .any(|tag| tag_filter.iter().any(|filter| filter == tag)))
+    };
+    let displayed: Vec<usize> = (0..task_vec.len())
+        .filter(|&index| {
+            index == 0
+                || (0..task_vec.len()).any(|other| {
+                    task_vec[index].left <= task_vec[other].left
+                        && task_vec[other].right <= task_vec[index].right
+                        && matches(other)
+                })
+        })
+        .collect();
+
+    // the parent of each task is its closest displayed ancestor, the root having no parent
+    let mut tasks: Vec<TaskListItem> = Vec::new();
+    let mut ancestors: Vec<usize> = Vec::new();
+    for &index in &displayed {
+        let task = &task_vec[index];
+        while ancestors
+            .last()
+            .is_some_and(|&ancestor| task_vec[ancestor].right < task.left)
+        {
+            ancestors.pop();
+        }
+        if index != 0 {
+            tasks.push(TaskListItem {
+                uid: task.id.clone(),
+                name: task.name.clone(),
+                parent: ancestors
+                    .last()
+                    .filter(|&&ancestor| ancestor != 0)
+                    .map(|&ancestor| task_vec[ancestor].id.clone()),
+                depth: ancestors.len(),
+                status: statuses[index],
+                due: task.due.map(output::format_rfc3339),
+                recurrence: task.recurrence.clone(),
+                blocked: task.blocked,
+                tags: task
+                    .tags
+                    .as_deref()
+                    .unwrap_or_default()
+                    .split_whitespace()
+                    .map(String::from)
+                    .collect(),
+                bounds: (task.left, task.right),
+                due_timestamp: task.due,
+            });
+        }
+        ancestors.push(index);
+    }
+
+    output::print(
+        &TaskList {
+            tree: current_tree_name,
+            tasks,
+        },
+        output,
+    )
+}
+
+/// Shows the description of a task in the current tree
+pub async fn show(partial_uid: &String) -> Result<(), Box<dyn Error>> {
+    let pool = dbutils::load_db().await;
 
     let current_tree_name = dbutils::get_current_tree_name(&pool).await?;
 
@@ -614,7 +1074,9 @@ pub async fn show(partial_uid: &String) -> Result<(), Box<dyn Error>> {
     // get description of the desired task
     let query_result = sqlx::query!(
         r#"
-        SELECT name, description
+        SELECT
+            name, description, status, due, scheduled, recurrence,
+            (SELECT group_concat(tag_name, ' ') FROM task_tag WHERE task_id = task.id) AS "tags?: String"
         FROM task
         WHERE tree_name = ? AND id = ?;
         "#,
@@ -647,6 +1109,48 @@ pub async fn show(partial_uid: &String) -> Result<(), Box<dyn Error>> {
         "Name: {}",
         ansi::format(&task.name, ansi::ForestFormat::TaskName)
     );
+    println!(
+        "Status: {}",
+        ansi::format(&task.status, ansi::ForestFormat::Status)
+    );
+    if let Some(due) = task.due {
+        println!("Due: {}", format_timestamp(due, ansi::ForestFormat::Date));
+    }
+    if let Some(scheduled) = task.scheduled {
+        println!(
+            "Scheduled: {}",
+            format_timestamp(scheduled, ansi::ForestFormat::Date)
+        );
+    }
+    if let Some(rule) = task.recurrence {
+        let recurrence = Recurrence::try_from(rule)?;
+        print!("Recurrence: {}", recurrence.describe());
+        let base = task
+            .due
+            .or(task.scheduled)
+            .unwrap_or(Local::now().timestamp_millis());
+        if let Some(next_due) = next_occurrence(base, recurrence) {
+            print!(
+                " (next due {})",
+                format_timestamp(next_due, ansi::ForestFormat::Date)
+            );
+        }
+        println!();
+    }
+    if let Some(tags) = &task.tags {
+        println!("Tags: {}", tag::format(tags));
+    }
+
+    let blockers = get_blockers(&mut conn, &uid).await;
+    if !blockers.is_empty() {
+        println!("Blocked by:");
+        print_related_tasks(&blockers);
+    }
+    let blocked = get_blocked(&mut conn, &uid).await;
+    if !blocked.is_empty() {
+        println!("Blocks:");
+        print_related_tasks(&blocked);
+    }
     println!();
 
     for line in task.description.lines() {
@@ -928,3 +1432,1426 @@ pub async fn priority(partial_uid: &String, priority: Priority) -> Result<(), Bo
 
     Ok(())
 }
+
+/// Sets the status of a task in the current tree
+///
+/// A task cannot be marked as done or cancelled while some of its subtasks are still open, unless
+/// `recursive` is true, in which case all open subtasks get the same status.
+///
+/// # Errors
+/// Returns an error if the task does not exist in the current tree or if the task has open
+/// subtasks and `recursive` is false
+///
+/// # Panics
+/// This function may panic if database operations fail
+pub async fn set_status(
+    partial_uid: &String,
+    status: TaskStatus,
+    recursive: bool,
+) -> Result<(), Box<dyn Error>> {
+    let pool = dbutils::load_db().await;
+
+    let current_tree_name = dbutils::get_current_tree_name(&pool).await?;
+
+    // the status and the next occurrence of a recurring task are saved together
+    let mut transaction = pool
+        .begin()
+        .await
+        .expect("Beginning a transaction should succeed");
+
+    let uid = find_uid_from_partial(partial_uid).await?;
+
+    // get boundaries of the task, needed to find its subtasks
+    let query_result = sqlx::query!(
+        r#"
+        SELECT "left", "right", name, recurrence
+        FROM task
+        WHERE tree_name = ? AND id = ?;
+        "#,
+        current_tree_name,
+        uid,
+    )
+    .fetch_one(&mut *transaction)
+    .await;
+
+    // error handling
+    let task = match query_result {
+        Ok(record) => record,
+        Err(query_error) => match query_error {
+            sqlx::Error::RowNotFound => {
+                return Err(format!("Task '{uid}' not found in tree '{current_tree_name}'").into())
+            }
+            other_error => panic!("Database query failed: {other_error}"),
+        },
+    };
+
+    // a task cannot be started nor done while some of its blockers are still open
+    if matches!(status, TaskStatus::InProgress | TaskStatus::Done) {
+        let open_blockers: Vec<RelatedTask> = get_blockers(&mut transaction, &uid)
+            .await
+            .into_iter()
+            .filter(|blocker| {
+                TaskStatus::try_from(blocker.status.clone()).is_ok_and(|status| status.is_open())
+            })
+            .collect();
+
+        if !open_blockers.is_empty() {
+            let mut error_message = format!("Task '{}' is blocked by:\n", task.name);
+            for blocker in open_blockers {
+                error_message.push_str(&format!(
+                    "- {}: {} (tree {})\n",
+                    Uid::try_from(blocker.id)?.short(),
+                    blocker.name,
+                    blocker.tree_name
+                ));
+            }
+            return Err(error_message.into());
+        }
+    }
+
+    // closing a task also closes its open subtasks, so make sure this is what the user wants
+    let close_subtasks = !status.is_open();
+    if close_subtasks {
+        let query_result = sqlx::query!(
+            r#"
+            SELECT id, name
+            FROM task
+            WHERE
+                tree_name = ? AND
+                "left" > ? AND "right" < ? AND
+                status IN ('todo', 'in-progress')
+            ORDER BY "left" ASC;
+            "#,
+            current_tree_name,
+            task.left,
+            task.right,
+        )
+        .fetch_all(&mut *transaction)
+        .await;
+
+        let open_subtasks = match query_result {
+            Ok(records) => records,
+            Err(query_error) => panic!("Database query failed: {query_error}"),
+        };
+
+        if !open_subtasks.is_empty() && !recursive {
+            let mut error_message = format!(
+                "Task '{}' still has {} open subtask(s):\n",
+                task.name,
+                open_subtasks.len()
+            );
+            for subtask in open_subtasks {
+                let subtask_uid = Uid::try_from(subtask.id)?;
+                error_message.push_str(&format!("- {}: {}\n", subtask_uid.short(), subtask.name));
+            }
+            error_message.push_str("Use --recursive to also close these subtasks\n");
+            return Err(error_message.into());
+        }
+    }
+
+    // completing an occurrence of a recurring task creates the next occurrence
+    let recurrence = match (status, task.recurrence) {
+        (TaskStatus::Done, Some(rule)) => Some(Recurrence::try_from(rule)?),
+        _ => None,
+    };
+
+    // update the status of the task, and of its open subtasks if they should be closed too
+    let status_str = status.as_str();
+    let query_result = sqlx::query!(
+        r#"
+        UPDATE task
+        SET status = ?
+        WHERE
+            tree_name = ? AND (
+                id = ? OR (
+                    ? AND
+                    "left" > ? AND "right" < ? AND
+                    status IN ('todo', 'in-progress')
+                )
+            );
+        "#,
+        status_str,
+        current_tree_name,
+        uid,
+        close_subtasks,
+        task.left,
+        task.right,
+    )
+    .execute(&mut *transaction)
+    .await;
+
+    // error handling
+    let updated_rows = match query_result {
+        Ok(result) => {
+            if result.rows_affected() < 1 {
+                panic!("Setting a status should at least update one row (the task itself)");
+            }
+            result.rows_affected()
+        }
+        Err(query_error) => panic!("Database query failed: {query_error}"),
+    };
+
+    let next_occurrence = match recurrence {
+        Some(recurrence) => Some(
+            create_next_occurrence(&mut transaction, &current_tree_name, &uid, recurrence).await?,
+        ),
+        None => None,
+    };
+
+    transaction
+        .commit()
+        .await
+        .expect("Committing a transaction should succeed");
+
+    print!(
+        "Marked task {} ({}) as {}",
+        ansi::format(&task.name, ansi::ForestFormat::TaskName),
+        ansi::format(uid.short(), ansi::ForestFormat::Uid),
+        ansi::format(status_str, ansi::ForestFormat::Status),
+    );
+    if updated_rows > 1 {
+        print!(" along with {} subtask(s)", updated_rows - 1);
+    }
+    println!();
+
+    if let Some((next_uid, next_due)) = next_occurrence {
+        print!(
+            "Created next occurrence of task {} ({})",
+            ansi::format(&task.name, ansi::ForestFormat::TaskName),
+            ansi::format(next_uid.short(), ansi::ForestFormat::Uid),
+        );
+        if let Some(next_due) = next_due {
+            print!(
+                ", due {}",
+                format_timestamp(next_due, ansi::ForestFormat::Date)
+            );
+        }
+        println!();
+    }
+
+    Ok(())
+}
+
+/// Sets the due and scheduled dates of a task in the current tree
+///
+/// If `clear` is true, the current dates of the task are removed before setting the given ones.
+///
+/// # Errors
+/// Returns an error if the task does not exist in the current tree or if a date is ill-formed
+///
+/// # Panics
+/// This function may panic if database operations fail
+pub async fn due(
+    partial_uid: &String,
+    due: Option<String>,
+    scheduled: Option<String>,
+    clear: bool,
+) -> Result<(), Box<dyn Error>> {
+    let due = parse_optional_datetime(&due)?;
+    let scheduled = parse_optional_datetime(&scheduled)?;
+
+    let pool = dbutils::load_db().await;
+
+    let current_tree_name = dbutils::get_current_tree_name(&pool).await?;
+
+    let mut conn = pool
+        .acquire()
+        .await
+        .expect("Acquiring connection to database should succeed");
+
+    let uid = find_uid_from_partial(partial_uid).await?;
+
+    // update dates of the task
+    // dates that were not provided are left untouched, unless they should be cleared
+    let query_result = sqlx::query!(
+        r#"
+        UPDATE task
+        SET
+            due = CASE WHEN ? IS NOT NULL THEN ? WHEN ? THEN NULL ELSE due END,
+            scheduled = CASE WHEN ? IS NOT NULL THEN ? WHEN ? THEN NULL ELSE scheduled END
+        WHERE tree_name = ? AND id = ?
+        RETURNING name, due, scheduled;
+        "#,
+        due,
+        due,
+        clear,
+        scheduled,
+        scheduled,
+        clear,
+        current_tree_name,
+        uid,
+    )
+    .fetch_one(&mut *conn)
+    .await;
+
+    // error handling
+    let task = match query_result {
+        Ok(record) => record,
+        Err(query_error) => match query_error {
+            sqlx::Error::RowNotFound => {
+                return Err(format!("Task '{uid}' not found in tree '{current_tree_name}'").into())
+            }
+            other_error => panic!("Database query failed: {other_error}"),
+        },
+    };
+
+    print!(
+        "Updated dates of task {} ({}):",
+        ansi::format(&task.name, ansi::ForestFormat::TaskName),
+        ansi::format(uid.short(), ansi::ForestFormat::Uid),
+    );
+    match task.due {
+        Some(due) => print!(" due {}", format_timestamp(due, ansi::ForestFormat::Date)),
+        None => print!(" no due date"),
+    }
+    match task.scheduled {
+        Some(scheduled) => print!(
+            ", scheduled {}",
+            format_timestamp(scheduled, ansi::ForestFormat::Date)
+        ),
+        None => print!(", not scheduled"),
+    }
+    println!();
+
+    Ok(())
+}
+
+/// Adds tags to a task in the current tree
+///
+/// # Errors
+/// Returns an error if the task does not exist in the current tree
+///
+/// # Panics
+/// This function may panic if database operations fail
+pub async fn tag(partial_uid: &String, tags: &[String]) -> Result<(), Box<dyn Error>> {
+    let pool = dbutils::load_db().await;
+
+    let current_tree_name = dbutils::get_current_tree_name(&pool).await?;
+
+    let mut conn = pool
+        .acquire()
+        .await
+        .expect("Acquiring connection to database should succeed");
+
+    let uid = find_uid_from_partial(partial_uid).await?;
+
+    // get name of the task for output message
+    let query_result = sqlx::query!(
+        r#"
+        SELECT name
+        FROM task
+        WHERE tree_name = ? AND id = ?;
+        "#,
+        current_tree_name,
+        uid,
+    )
+    .fetch_one(&mut *conn)
+    .await;
+
+    // error handling
+    let task = match query_result {
+        Ok(record) => record,
+        Err(query_error) => match query_error {
+            sqlx::Error::RowNotFound => {
+                return Err(format!("Task '{uid}' not found in tree '{current_tree_name}'").into())
+            }
+            other_error => panic!("Database query failed: {other_error}"),
+        },
+    };
+
+    tag::attach_to_task(&mut conn, &uid.to_string(), tags).await;
+
+    println!(
+        "Tagged task {} ({}) with {}",
+        ansi::format(&task.name, ansi::ForestFormat::TaskName),
+        ansi::format(uid.short(), ansi::ForestFormat::Uid),
+        tag::format(&tags.join(" ")),
+    );
+
+    Ok(())
+}
+
+/// Removes tags from a task in the current tree
+///
+/// # Errors
+/// Returns an error if the task does not exist in the current tree or if it has none of the given
+/// tags
+///
+/// # Panics
+/// This function may panic if database operations fail
+pub async fn untag(partial_uid: &String, tags: &[String]) -> Result<(), Box<dyn Error>> {
+    let pool = dbutils::load_db().await;
+
+    let current_tree_name = dbutils::get_current_tree_name(&pool).await?;
+
+    let mut conn = pool
+        .acquire()
+        .await
+        .expect("Acquiring connection to database should succeed");
+
+    let uid = find_uid_from_partial(partial_uid).await?;
+
+    // get name of the task for output message
+    let query_result = sqlx::query!(
+        r#"
+        SELECT name
+        FROM task
+        WHERE tree_name = ? AND id = ?;
+        "#,
+        current_tree_name,
+        uid,
+    )
+    .fetch_one(&mut *conn)
+    .await;
+
+    // error handling
+    let task = match query_result {
+        Ok(record) => record,
+        Err(query_error) => match query_error {
+            sqlx::Error::RowNotFound => {
+                return Err(format!("Task '{uid}' not found in tree '{current_tree_name}'").into())
+            }
+            other_error => panic!("Database query failed: {other_error}"),
+        },
+    };
+
+    // unlink tags from the task
+    let tags_json = tag::json_array(tags);
+    let query_result = sqlx::query!(
+        r#"
+        DELETE FROM task_tag
+        WHERE task_id = ? AND tag_name IN (SELECT value FROM json_each(?));
+        "#,
+        uid,
+        tags_json,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    // error handling
+    match query_result {
+        Ok(result) => {
+            if result.rows_affected() < 1 {
+                return Err(format!(
+                    "Task '{}' has none of the tags {}",
+                    task.name,
+                    tags.join(", ")
+                )
+                .into());
+            }
+        }
+        Err(query_error) => panic!("Database query failed: {query_error}"),
+    }
+
+    tag::remove_unused(&mut conn).await;
+
+    println!(
+        "Removed tags {} from task {} ({})",
+        tag::format(&tags.join(" ")),
+        ansi::format(&task.name, ansi::ForestFormat::TaskName),
+        ansi::format(uid.short(), ansi::ForestFormat::Uid),
+    );
+
+    Ok(())
+}
+
+/// Nested-set boundaries of a task
+struct Bounds {
+    left: i64,
+    right: i64,
+    name: String,
+}
+
+/// Gets the boundaries of the given parent task in the given tree, or of the tree root if no
+/// parent is given
+///
+/// # Errors
+/// Returns an error if the parent task or the tree does not exist
+///
+/// # Panics
+/// This function may panic if database operations fail
+async fn get_parent_bounds(
+    conn: &mut SqliteConnection,
+    tree_name: &String,
+    parent_uid: &Option<Uid>,
+) -> Result<Bounds, Box<dyn Error>> {
+    let query_result = sqlx::query_as!(
+        Bounds,
+        r#"
+        SELECT "left", "right", name
+        FROM task
+        WHERE tree_name = ? AND (id = ? OR (? IS NULL AND "left" = 1));
+        "#,
+        tree_name,
+        parent_uid,
+        parent_uid,
+    )
+    .fetch_one(&mut *conn)
+    .await;
+
+    match query_result {
+        Ok(record) => Ok(record),
+        Err(query_error) => match query_error {
+            sqlx::Error::RowNotFound => match parent_uid {
+                Some(uid) => Err(format!("Task '{uid}' not found in tree '{tree_name}'").into()),
+                None => Err(format!("Tree '{tree_name}' not found").into()),
+            },
+            other_error => panic!("Database query failed: {other_error}"),
+        },
+    }
+}
+
+/// Returns the "left" value before which a subtree should be inserted to become the child of the
+/// given parent at the given position (starting at 1), or its last child if no position is given.
+///
+/// The child starting at `excluded_left` is ignored, since it is the subtree about to be moved.
+///
+/// # Errors
+/// Returns an error if the position cannot be converted into an index
+///
+/// # Panics
+/// This function may panic if database operations fail
+async fn get_insertion_point(
+    conn: &mut SqliteConnection,
+    tree_name: &String,
+    parent: &Bounds,
+    excluded_left: Option<i64>,
+    position: Option<Priority>,
+) -> Result<i64, Box<dyn Error>> {
+    // get all descendants of the parent, to find its immediate children
+    let query_result = sqlx::query!(
+        r#"
+        SELECT "left", "right"
+        FROM task
+        WHERE tree_name = ? AND "left" > ? AND "right" < ?
+        ORDER BY "left" ASC;
+        "#,
+        tree_name,
+        parent.left,
+        parent.right,
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    // error handling
+    let descendants = match query_result {
+        Ok(records) => records,
+        Err(query_error) => panic!("Database query failed: {query_error}"),
+    };
+
+    // immediate children are the descendants which are not nested in a previous descendant
+    let mut children_left: Vec<i64> = Vec::new();
+    let mut last_right = parent.left;
+    for descendant in descendants {
+        if descendant.left > last_right {
+            last_right = descendant.right;
+            if Some(descendant.left) != excluded_left {
+                children_left.push(descendant.left);
+            }
+        }
+    }
+
+    match position {
+        Some(position) => {
+            let child_index = usize::try_from(position - 1)?;
+            Ok(children_left
+                .get(child_index)
+                .copied()
+                .unwrap_or(parent.right))
+        }
+        None => Ok(parent.right),
+    }
+}
+
+/// Shifts all tasks of a tree that are at the right of `from` (included) by `shift`
+///
+/// # Panics
+/// This function may panic if database operations fail
+pub(super) async fn shift_tasks(
+    conn: &mut SqliteConnection,
+    tree_name: &String,
+    from: i64,
+    shift: i64,
+) {
+    let query_result = sqlx::query!(
+        r#"
+        UPDATE task
+        SET
+            "left" = CASE WHEN "left" >= ? THEN "left" + ? ELSE "left" END,
+            "right" = CASE WHEN "right" >= ? THEN "right" + ? ELSE "right" END
+        WHERE "right" >= ? AND tree_name = ?;
+        "#,
+        from,
+        shift,
+        from,
+        shift,
+        from,
+        tree_name,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    // error handling
+    match query_result {
+        Ok(result) => {
+            if result.rows_affected() < 1 {
+                panic!("Shifting tasks should at least update one row (root)");
+            }
+        }
+        Err(query_error) => panic!("Database query failed: {query_error}"),
+    };
+}
+
+/// Prints where a task was moved or copied to
+fn print_destination(
+    action: &str,
+    task_name: &str,
+    uid: &Uid,
+    tree_name: &str,
+    parent_name: Option<&str>,
+) {
+    print!(
+        "{} task {} ({}) to tree {}",
+        action,
+        ansi::format(task_name, ansi::ForestFormat::TaskName),
+        ansi::format(uid.short(), ansi::ForestFormat::Uid),
+        ansi::format(tree_name, ansi::ForestFormat::TreeName),
+    );
+    if let Some(parent_name) = parent_name {
+        print!(
+            " under task {}",
+            ansi::format(parent_name, ansi::ForestFormat::TaskName)
+        );
+    }
+    println!();
+}
+
+/// Moves a task and all its subtasks under another parent, in the current tree or in another tree
+///
+/// If no parent is given, the task is moved under the root of the destination tree. The task is
+/// inserted at the given position among the children of its new parent (starting at 1), or after
+/// the last child if no position is given. Frames and notes follow the moved tasks.
+///
+/// # Errors
+/// Returns an error if the task, the destination tree or the parent does not exist, or if the
+/// parent is the task itself or one of its descendants
+///
+/// # Panics
+/// This function may panic if database operations fail
+pub async fn move_subtree(
+    partial_uid: &String,
+    tree_name: Option<&String>,
+    parent_partial_uid: Option<&String>,
+    position: Option<Priority>,
+) -> Result<(), Box<dyn Error>> {
+    let pool = dbutils::load_db().await;
+
+    let current_tree_name = dbutils::get_current_tree_name(&pool).await?;
+    let destination_tree_name = tree_name.unwrap_or(&current_tree_name).clone();
+
+    let uid = find_uid_from_partial(partial_uid).await?;
+    let parent_uid = match parent_partial_uid {
+        Some(partial_uid) => {
+            Some(find_uid_from_partial_in_forest(partial_uid, Some(&destination_tree_name)).await?)
+        }
+        None => None,
+    };
+
+    let mut transaction = pool
+        .begin()
+        .await
+        .expect("Beginning a transaction should succeed");
+
+    // get boundaries of the task/subtree to move
+    let query_result = sqlx::query!(
+        r#"
+        SELECT "left", "right", name
+        FROM task
+        WHERE tree_name = ? AND id = ?;
+        "#,
+        current_tree_name,
+        uid,
+    )
+    .fetch_one(&mut *transaction)
+    .await;
+
+    // error handling
+    let moved_task = match query_result {
+        Ok(record) => record,
+        Err(query_error) => match query_error {
+            sqlx::Error::RowNotFound => {
+                return Err(format!("Task '{uid}' not found in tree '{current_tree_name}'").into())
+            }
+            other_error => panic!("Database query failed: {other_error}"),
+        },
+    };
+
+    let parent_task =
+        get_parent_bounds(&mut transaction, &destination_tree_name, &parent_uid).await?;
+    let width = moved_task.right - moved_task.left + 1;
+
+    if destination_tree_name == current_tree_name {
+        // a task cannot become its own ancestor
+        if moved_task.left <= parent_task.left && parent_task.right <= moved_task.right {
+            return Err(format!(
+                "Cannot move task '{}' under itself or one of its subtasks",
+                moved_task.name
+            )
+            .into());
+        }
+
+        // "left" value before which the moved subtree should be inserted
+        let destination = get_insertion_point(
+            &mut transaction,
+            &current_tree_name,
+            &parent_task,
+            Some(moved_task.left),
+            position,
+        )
+        .await?;
+
+        // compute the offset applied to the moved subtree, and the shift applied to the tasks
+        // between the subtree and its destination, to fill the gap it leaves
+        let (offset, shifted_from, shifted_to, shift) = if destination > moved_task.right {
+            (
+                destination - moved_task.right - 1,
+                moved_task.right + 1,
+                destination - 1,
+                -width,
+            )
+        } else {
+            (
+                destination - moved_task.left,
+                destination,
+                moved_task.left - 1,
+                width,
+            )
+        };
+
+        // move the subtree and update surrounding tasks in a single update
+        let query_result = sqlx::query!(
+            r#"
+            UPDATE task
+            SET
+                "left" =
+                    CASE
+                        -- if the task is the task to move or one of its descendants
+                        -- then move it to the destination
+                        WHEN "left" BETWEEN ? AND ? THEN "left" + ?
+
+                        -- if the task is between the moved subtree and its destination
+                        -- then shift it by the width of the moved subtree
+                        WHEN "left" BETWEEN ? AND ? THEN "left" + ?
+
+                        -- else, do not move
+                        ELSE "left" END,
+                "right" =
+                    CASE
+                        WHEN "right" BETWEEN ? AND ? THEN "right" + ?
+                        WHEN "right" BETWEEN ? AND ? THEN "right" + ?
+                        ELSE "right" END
+            WHERE tree_name = ?;
+            "#,
+            moved_task.left,
+            moved_task.right,
+            offset,
+            shifted_from,
+            shifted_to,
+            shift,
+            moved_task.left,
+            moved_task.right,
+            offset,
+            shifted_from,
+            shifted_to,
+            shift,
+            current_tree_name,
+        )
+        .execute(&mut *transaction)
+        .await;
+
+        // error handling
+        match query_result {
+            Ok(result) => {
+                if result.rows_affected() < 1 {
+                    panic!("Moving tasks should at least update one row (the moved task)");
+                }
+            }
+            Err(query_error) => panic!("Database query failed: {query_error}"),
+        };
+    } else {
+        let destination = get_insertion_point(
+            &mut transaction,
+            &destination_tree_name,
+            &parent_task,
+            None,
+            position,
+        )
+        .await?;
+
+        // open a gap in the destination tree to receive the moved subtree
+        shift_tasks(&mut transaction, &destination_tree_name, destination, width).await;
+
+        // move the subtree into the gap
+        let offset = destination - moved_task.left;
+        let query_result = sqlx::query!(
+            r#"
+            UPDATE task
+            SET
+                tree_name = ?,
+                "left" = "left" + ?,
+                "right" = "right" + ?
+            WHERE tree_name = ? AND "left" BETWEEN ? AND ?;
+            "#,
+            destination_tree_name,
+            offset,
+            offset,
+            current_tree_name,
+            moved_task.left,
+            moved_task.right,
+        )
+        .execute(&mut *transaction)
+        .await;
+
+        // error handling
+        match query_result {
+            Ok(result) => {
+                if result.rows_affected() < 1 {
+                    panic!("Moving tasks should at least update one row (the moved task)");
+                }
+            }
+            Err(query_error) => panic!("Database query failed: {query_error}"),
+        };
+
+        // fill the gap left by the subtree in the source tree
+        shift_tasks(
+            &mut transaction,
+            &current_tree_name,
+            moved_task.right + 1,
+            -width,
+        )
+        .await;
+    }
+
+    transaction
+        .commit()
+        .await
+        .expect("Committing a transaction should succeed");
+
+    print_destination(
+        "Moved",
+        &moved_task.name,
+        &uid,
+        &destination_tree_name,
+        parent_uid.as_ref().map(|_| parent_task.name.as_str()),
+    );
+
+    Ok(())
+}
+
+/// Inserts a copy of a task and of all its descendants into the destination tree, so that the
+/// copy starts at the `destination` "left" value. Copies get new uids and keep the tags of the
+/// original tasks.
+///
+/// Returns the uid and the name of the copy of the task.
+///
+/// # Errors
+/// Returns an error if the task does not exist in the given tree
+///
+/// # Panics
+/// This function may panic if database operations fail
+async fn duplicate_subtree(
+    conn: &mut SqliteConnection,
+    tree_name: &String,
+    uid: &Uid,
+    destination_tree_name: &String,
+    destination: i64,
+) -> Result<(Uid, String), Box<dyn Error>> {
+    // get the task to copy and all its descendants
+    let query_result = sqlx::query!(
+        r#"
+        SELECT
+            s.id, s."left", s."right", s.name, s.description, s.status, s.due, s.scheduled,
+            s.recurrence
+        FROM task s INNER JOIN task t ON s.tree_name = t.tree_name
+        WHERE
+            t.tree_name = ? AND t.id = ? AND
+            s."left" BETWEEN t."left" AND t."right"
+        ORDER BY s."left" ASC;
+        "#,
+        tree_name,
+        uid,
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    // error handling
+    let copied_tasks = match query_result {
+        Ok(records) => {
+            if records.is_empty() {
+                return Err(format!("Task '{uid}' not found in tree '{tree_name}'").into());
+            }
+            records
+        }
+        Err(query_error) => panic!("Database query failed: {query_error}"),
+    };
+
+    let copied_left = copied_tasks[0].left;
+    let width = copied_tasks[0].right - copied_left + 1;
+
+    // open a gap in the destination tree to receive the copied subtree
+    // the copied tasks were fetched beforehand, so it does not matter if this shifts them
+    shift_tasks(&mut *conn, destination_tree_name, destination, width).await;
+
+    // insert a copy of each task into the gap
+    let offset = destination - copied_left;
+    let mut copy_uid: Option<Uid> = None;
+    for task in copied_tasks.iter() {
+        let new_task_uid = Uid::new();
+        let new_task_left = task.left + offset;
+        let new_task_right = task.right + offset;
+        let query_result = sqlx::query!(
+            r#"
+            INSERT INTO task(
+                "id", "tree_name", "left", "right", "name", "description", "status", "due",
+                "scheduled", "recurrence"
+            )
+            VALUES(?, ?, ?, ?, ?, ?, ?, ?, ?, ?);
+            "#,
+            new_task_uid,
+            destination_tree_name,
+            new_task_left,
+            new_task_right,
+            task.name,
+            task.description,
+            task.status,
+            task.due,
+            task.scheduled,
+            task.recurrence,
+        )
+        .execute(&mut *conn)
+        .await;
+
+        // error handling
+        match query_result {
+            Ok(result) => {
+                if result.rows_affected() != 1 {
+                    panic!("A single line should have been inserted into the task table");
+                }
+            }
+            Err(query_error) => match query_error {
+                sqlx::Error::Database(db_error) => match db_error.kind() {
+                    sqlx::error::ErrorKind::UniqueViolation => {
+                        panic!("Task id should be unique");
+                    }
+                    _ => panic!("Database query failed: {db_error}"),
+                },
+                other_error => panic!("Database query failed: {other_error}"),
+            },
+        }
+
+        // copy tags of the task
+        let query_result = sqlx::query!(
+            r#"
+            INSERT INTO task_tag("task_id", "tag_name")
+            SELECT ?, tag_name FROM task_tag WHERE task_id = ?;
+            "#,
+            new_task_uid,
+            task.id,
+        )
+        .execute(&mut *conn)
+        .await;
+
+        // error handling
+        if let Err(query_error) = query_result {
+            panic!("Database query failed: {query_error}");
+        }
+
+        copy_uid.get_or_insert(new_task_uid);
+    }
+
+    Ok((
+        copy_uid.expect("At least one task should have been copied"),
+        copied_tasks[0].name.clone(),
+    ))
+}
+
+/// Copies a task and all its subtasks under another parent, in the current tree or in another
+/// tree
+///
+/// Copied tasks get new uids and keep the name, description, status, dates and tags of the
+/// original tasks. Frames and notes stay with the original tasks.
+///
+/// # Errors
+/// Returns an error if the task, the destination tree or the parent does not exist
+///
+/// # Panics
+/// This function may panic if database operations fail
+pub async fn copy_subtree(
+    partial_uid: &String,
+    tree_name: Option<&String>,
+    parent_partial_uid: Option<&String>,
+    position: Option<Priority>,
+) -> Result<(), Box<dyn Error>> {
+    let pool = dbutils::load_db().await;
+
+    let current_tree_name = dbutils::get_current_tree_name(&pool).await?;
+    let destination_tree_name = tree_name.unwrap_or(&current_tree_name).clone();
+
+    let uid = find_uid_from_partial(partial_uid).await?;
+    let parent_uid = match parent_partial_uid {
+        Some(partial_uid) => {
+            Some(find_uid_from_partial_in_forest(partial_uid, Some(&destination_tree_name)).await?)
+        }
+        None => None,
+    };
+
+    let mut transaction = pool
+        .begin()
+        .await
+        .expect("Beginning a transaction should succeed");
+
+    let parent_task =
+        get_parent_bounds(&mut transaction, &destination_tree_name, &parent_uid).await?;
+    let destination = get_insertion_point(
+        &mut transaction,
+        &destination_tree_name,
+        &parent_task,
+        None,
+        position,
+    )
+    .await?;
+
+    let (copied_uid, copied_task_name) = duplicate_subtree(
+        &mut transaction,
+        &current_tree_name,
+        &uid,
+        &destination_tree_name,
+        destination,
+    )
+    .await?;
+
+    transaction
+        .commit()
+        .await
+        .expect("Committing a transaction should succeed");
+
+    print_destination(
+        "Copied",
+        &copied_task_name,
+        &copied_uid,
+        &destination_tree_name,
+        parent_uid.as_ref().map(|_| parent_task.name.as_str()),
+    );
+
+    Ok(())
+}
+
+/// Task related to another task, possibly in another tree
+struct RelatedTask {
+    id: String,
+    name: String,
+    tree_name: String,
+    status: String,
+}
+
+/// Returns the tasks that block the given task
+///
+/// # Panics
+/// This function may panic if database operations fail
+async fn get_blockers(conn: &mut SqliteConnection, uid: &Uid) -> Vec<RelatedTask> {
+    let query_result = sqlx::query_as!(
+        RelatedTask,
+        r#"
+        SELECT t.id, t.name, t.tree_name, t.status
+        FROM dependency d INNER JOIN task t ON d.blocker_id = t.id
+        WHERE d.task_id = ?
+        ORDER BY t.tree_name, t."left";
+        "#,
+        uid,
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    match query_result {
+        Ok(records) => records,
+        Err(query_error) => panic!("Database query failed: {query_error}"),
+    }
+}
+
+/// Returns the tasks blocked by the given task
+///
+/// # Panics
+/// This function may panic if database operations fail
+async fn get_blocked(conn: &mut SqliteConnection, uid: &Uid) -> Vec<RelatedTask> {
+    let query_result = sqlx::query_as!(
+        RelatedTask,
+        r#"
+        SELECT t.id, t.name, t.tree_name, t.status
+        FROM dependency d INNER JOIN task t ON d.task_id = t.id
+        WHERE d.blocker_id = ?
+        ORDER BY t.tree_name, t."left";
+        "#,
+        uid,
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    match query_result {
+        Ok(records) => records,
+        Err(query_error) => panic!("Database query failed: {query_error}"),
+    }
+}
+
+/// Prints a list of related tasks, with their tree and status
+fn print_related_tasks(tasks: &[RelatedTask]) {
+    for task in tasks {
+        let status = TaskStatus::try_from(task.status.clone()).unwrap_or_default();
+        println!(
+            "    {} {} {} ({})",
+            ansi::format(
+                Uid::try_from(task.id.clone()).unwrap().short(),
+                ansi::ForestFormat::Uid
+            ),
+            ansi::format(status_marker(status), ansi::ForestFormat::Status),
+            ansi::format(&task.name, ansi::ForestFormat::TaskName),
+            ansi::format(&task.tree_name, ansi::ForestFormat::TreeName),
+        );
+    }
+}
+
+/// Returns whether making a task depend on a blocker would create a dependency cycle, ie. whether
+/// the blocker already depends, directly or not, on the task
+///
+/// # Panics
+/// This function may panic if database operations fail
+pub(super) async fn would_create_cycle(
+    conn: &mut SqliteConnection,
+    task_id: &String,
+    blocker_id: &String,
+) -> bool {
+    let query_result = sqlx::query!(
+        r#"
+        WITH RECURSIVE blockers(id) AS (
+            -- the blocker itself
+            SELECT ?
+
+            UNION
+
+            -- all tasks that the blocker depends on, recursively
+            SELECT d.blocker_id
+            FROM dependency d INNER JOIN blockers b ON d.task_id = b.id
+        )
+        SELECT EXISTS (SELECT * FROM blockers WHERE id = ?) AS "cycle: bool";
+        "#,
+        blocker_id,
+        task_id,
+    )
+    .fetch_one(&mut *conn)
+    .await;
+
+    // error handling
+    match query_result {
+        Ok(record) => record.cycle,
+        Err(query_error) => panic!("Database query failed: {query_error}"),
+    }
+}
+
+/// Makes a task of the current tree depend on another task, possibly in another tree
+///
+/// # Errors
+/// Returns an error if one of the tasks does not exist, or if the dependency would create a cycle
+///
+/// # Panics
+/// This function may panic if database operations fail
+pub async fn depend(
+    partial_uid: &String,
+    blocker_partial_uid: &String,
+) -> Result<(), Box<dyn Error>> {
+    let pool = dbutils::load_db().await;
+
+    let mut conn = pool
+        .acquire()
+        .await
+        .expect("Acquiring connection to database should succeed");
+
+    let uid = find_uid_from_partial(partial_uid).await?;
+    let blocker_uid = find_uid_from_partial_in_forest(blocker_partial_uid, None).await?;
+
+    if would_create_cycle(&mut conn, &uid.to_string(), &blocker_uid.to_string()).await {
+        return Err(format!(
+            "Task '{}' cannot depend on task '{}' since it would create a dependency cycle",
+            uid.short(),
+            blocker_uid.short()
+        )
+        .into());
+    }
+
+    // add the dependency
+    let query_result = sqlx::query!(
+        r#"
+        INSERT OR IGNORE INTO dependency("task_id", "blocker_id")
+        VALUES (?, ?);
+        "#,
+        uid,
+        blocker_uid,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    // error handling
+    if let Err(query_error) = query_result {
+        panic!("Database query failed: {query_error}");
+    }
+
+    println!(
+        "Task {} now depends on task {}",
+        ansi::format(uid.short(), ansi::ForestFormat::Uid),
+        ansi::format(blocker_uid.short(), ansi::ForestFormat::Uid),
+    );
+
+    Ok(())
+}
+
+/// Removes the dependency of a task of the current tree on another task
+///
+/// # Errors
+/// Returns an error if one of the tasks does not exist, or if the task does not depend on the
+/// other one
+///
+/// # Panics
+/// This function may panic if database operations fail
+pub async fn undepend(
+    partial_uid: &String,
+    blocker_partial_uid: &String,
+) -> Result<(), Box<dyn Error>> {
+    let pool = dbutils::load_db().await;
+
+    let mut conn = pool
+        .acquire()
+        .await
+        .expect("Acquiring connection to database should succeed");
+
+    let uid = find_uid_from_partial(partial_uid).await?;
+    let blocker_uid = find_uid_from_partial_in_forest(blocker_partial_uid, None).await?;
+
+    let query_result = sqlx::query!(
+        r#"
+        DELETE FROM dependency
+        WHERE task_id = ? AND blocker_id = ?;
+        "#,
+        uid,
+        blocker_uid,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    // error handling
+    match query_result {
+        Ok(result) => {
+            if result.rows_affected() != 1 {
+                return Err(format!(
+                    "Task '{}' does not depend on task '{}'",
+                    uid.short(),
+                    blocker_uid.short()
+                )
+                .into());
+            }
+        }
+        Err(query_error) => panic!("Database query failed: {query_error}"),
+    }
+
+    println!(
+        "Task {} no longer depends on task {}",
+        ansi::format(uid.short(), ansi::ForestFormat::Uid),
+        ansi::format(blocker_uid.short(), ansi::ForestFormat::Uid),
+    );
+
+    Ok(())
+}
+
+/// Returns the timestamp of the occurrence following the one at the given timestamp, in local
+/// time
+fn next_occurrence(timestamp: i64, recurrence: Recurrence) -> Option<i64> {
+    let datetime: DateTime<Local> = DateTime::from_timestamp_millis(timestamp)?.into();
+    recurrence
+        .next(datetime.naive_local())?
+        .and_local_timezone(Local)
+        .earliest()
+        .map(|next| next.timestamp_millis())
+}
+
+/// Creates the next occurrence of a recurring task that was just completed
+///
+/// The next occurrence is a copy of the task and its subtasks, inserted at the position of the
+/// completed task, with all its dates moved to the next occurrence. The recurrence rule is moved
+/// from the completed task to the new occurrence, so that completing it again does nothing.
+///
+/// Returns the uid and the due date of the new occurrence.
+///
+/// # Errors
+/// Returns an error if the task does not exist or if the date of the next occurrence is out of
+/// range
+///
+/// # Panics
+/// This function may panic if database operations fail
+async fn create_next_occurrence(
+    conn: &mut SqliteConnection,
+    tree_name: &String,
+    uid: &Uid,
+    recurrence: Recurrence,
+) -> Result<(Uid, Option<i64>), Box<dyn Error>> {
+    let query_result = sqlx::query!(
+        r#"
+        SELECT "left", "right", due, scheduled
+        FROM task
+        WHERE tree_name = ? AND id = ?;
+        "#,
+        tree_name,
+        uid,
+    )
+    .fetch_one(&mut *conn)
+    .await;
+
+    // error handling
+    let task = match query_result {
+        Ok(record) => record,
+        Err(query_error) => match query_error {
+            sqlx::Error::RowNotFound => {
+                return Err(format!("Task '{uid}' not found in tree '{tree_name}'").into())
+            }
+            other_error => panic!("Database query failed: {other_error}"),
+        },
+    };
+
+    // the next occurrence follows the due date, the scheduled date, or the completion date
+    let base = task
+        .due
+        .or(task.scheduled)
+        .unwrap_or(Local::now().timestamp_millis());
+    let next = next_occurrence(base, recurrence).ok_or("Next occurrence is out of range")?;
+    let delta = next - base;
+
+    // the copy takes the place of the completed task, which is shifted to its right
+    let (next_uid, _) = duplicate_subtree(&mut *conn, tree_name, uid, tree_name, task.left).await?;
+    let copy_left = task.left;
+    let copy_right = task.right;
+
+    // reopen the copied tasks and move their dates to the next occurrence
+    // tasks without dates get a due date if they are the occurrence itself
+    let query_result = sqlx::query!(
+        r#"
+        UPDATE task
+        SET
+            status = 'todo',
+            due = CASE
+                WHEN id = ? AND due IS NULL AND scheduled IS NULL THEN ?
+                ELSE due + ? END,
+            scheduled = scheduled + ?
+        WHERE tree_name = ? AND "left" BETWEEN ? AND ?
+        "#,
+        next_uid,
+        next,
+        delta,
+        delta,
+        tree_name,
+        copy_left,
+        copy_right,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    // error handling
+    if let Err(query_error) = query_result {
+        panic!("Database query failed: {query_error}");
+    }
+
+    // the recurrence rule now belongs to the next occurrence
+    let query_result = sqlx::query!(
+        r#"
+        UPDATE task
+        SET recurrence = NULL
+        WHERE id = ?
+        "#,
+        uid,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    // error handling
+    if let Err(query_error) = query_result {
+        panic!("Database query failed: {query_error}");
+    }
+
+    let next_due = if task.due.is_some() || task.scheduled.is_none() {
+        task.due.map(|due| due + delta).or(Some(next))
+    } else {
+        None
+    };
+
+    Ok((next_uid, next_due))
+}
+
+/// Sets or clears the recurrence rule of a task in the current tree
+///
+/// # Errors
+/// Returns an error if the task does not exist in the current tree
+///
+/// # Panics
+/// This function may panic if database operations fail
+pub async fn recur(
+    partial_uid: &String,
+    recurrence: Option<Recurrence>,
+) -> Result<(), Box<dyn Error>> {
+    let pool = dbutils::load_db().await;
+
+    let current_tree_name = dbutils::get_current_tree_name(&pool).await?;
+
+    let mut conn = pool
+        .acquire()
+        .await
+        .expect("Acquiring connection to database should succeed");
+
+    let uid = find_uid_from_partial(partial_uid).await?;
+
+    let rule = recurrence.map(|recurrence| recurrence.to_string());
+    let query_result = sqlx::query!(
+        r#"
+        UPDATE task
+        SET recurrence = ?
+        WHERE tree_name = ? AND id = ?
+        RETURNING name;
+        "#,
+        rule,
+        current_tree_name,
+        uid,
+    )
+    .fetch_one(&mut *conn)
+    .await;
+
+    // error handling
+    let task = match query_result {
+        Ok(record) => record,
+        Err(query_error) => match query_error {
+            sqlx::Error::RowNotFound => {
+                return Err(format!("Task '{uid}' not found in tree '{current_tree_name}'").into())
+            }
+            other_error => panic!("Database query failed: {other_error}"),
+        },
+    };
+
+    match recurrence {
+        Some(recurrence) => println!(
+            "Task {} ({}) now repeats {}",
+            ansi::format(&task.name, ansi::ForestFormat::TaskName),
+            ansi::format(uid.short(), ansi::ForestFormat::Uid),
+            recurrence.describe(),
+        ),
+        None => println!(
+            "Task {} ({}) no longer repeats",
+            ansi::format(&task.name, ansi::ForestFormat::TaskName),
+            ansi::format(uid.short(), ansi::ForestFormat::Uid),
+        ),
+    }
+
+    Ok(())
+}
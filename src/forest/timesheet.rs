@@ -0,0 +1,464 @@
+use chrono::{Datelike, Days, Local, Months, NaiveDate, TimeDelta};
+use serde::Serialize;
+use sqlx::SqliteConnection;
+use std::collections::HashMap;
+use std::error::Error;
+
+use super::ansi;
+use super::dbutils;
+use super::output::{self, Render};
+use super::tag;
+use super::timetracking;
+use forest_types::{GridPeriod, OutputFormat, ReportGrouping, RoundingMode};
+
+/// Grid of the time spent on each tree or task, on each day of a period
+#[derive(Serialize)]
+struct Timesheet {
+    from: String,
+    to: String,
+    days: Vec<String>,
+    rows: Vec<TimesheetRow>,
+    totals: Vec<i64>,
+    total: i64,
+
+    #[serde(skip)]
+    dates: Vec<NaiveDate>,
+}
+
+/// Time spent on a tree or on a task, in seconds, on each day of a timesheet
+#[derive(Serialize)]
+struct TimesheetRow {
+    name: String,
+    uid: Option<String>,
+    seconds: Vec<i64>,
+    total: i64,
+}
+
+/// Returns a compact representation of a duration in seconds, eg. "1:30"
+fn format_cell(seconds: i64) -> String {
+    if seconds == 0 {
+        return String::from("-");
+    }
+    let time_delta = TimeDelta::seconds(seconds);
+    format!(
+        "{}:{:02}",
+        time_delta.num_hours(),
+        time_delta.num_minutes() % 60
+    )
+}
+
+/// Returns a duration in seconds as decimal hours, as expected by most timesheet tools
+fn format_hours(seconds: i64) -> String {
+    let hours = seconds as f64 / 3600.0;
+    format!("{hours:.2}")
+}
+
+impl Render for Timesheet {
+    // the first record holds the column names, since there is one column per day
+    type Row = Vec<String>;
+
+    fn print_text(&self) {
+        let headers: Vec<String> = self
+            .dates
+            .iter()
+            .map(|day| day.format("%a %d").to_string())
+            .collect();
+
+        // width of each column, names being padded before they are colored
+        let name_width = self
+            .rows
+            .iter()
+            .map(|row| row.name.chars().count())
+            .chain(std::iter::once("Total".len()))
+            .max()
+            .unwrap_or_default();
+        let cell_width = self
+            .rows
+            .iter()
+            .flat_map(|row| row.seconds.iter().chain(std::iter::once(&row.total)))
+            .chain(self.totals.iter())
+            .chain(std::iter::once(&self.total))
+            .map(|&seconds| format_cell(seconds).len())
+            .chain(headers.iter().map(String::len))
+            .max()
+            .unwrap_or_default();
+
+        let print_line = |name: String, cells: Vec<String>, total: String| {
+            print!("{name}");
+            for cell in cells {
+                print!("  {cell:>cell_width$}");
+            }
+            println!("  {total:>cell_width$}");
+        };
+
+        print_line(
+            format!("{:name_width$}", ""),
+            headers
+                .iter()
+                .map(|header| {
+                    ansi::format(&format!("{header:>cell_width$}"), ansi::ForestFormat::Date)
+                })
+                .collect(),
+            String::from("Total"),
+        );
+        for row in &self.rows {
+            let name_format = if row.uid.is_some() {
+                ansi::ForestFormat::TaskName
+            } else {
+                ansi::ForestFormat::TreeName
+            };
+            print_line(
+                ansi::format(&format!("{:name_width$}", row.name), name_format),
+                row.seconds
+                    .iter()
+                    .map(|&seconds| format_cell(seconds))
+                    .collect(),
+                format_cell(row.total),
+            );
+        }
+        print_line(
+            format!("{:name_width$}", "Total"),
+            self.totals
+                .iter()
+                .map(|&seconds| format_cell(seconds))
+                .collect(),
+            format_cell(self.total),
+        );
+    }
+
+    fn rows(&self) -> Vec<Self::Row> {
+        let mut rows = Vec::new();
+
+        let mut headers = vec![String::from("name"), String::from("uid")];
+        headers.extend(self.days.iter().cloned());
+        headers.push(String::from("total"));
+        rows.push(headers);
+
+        for row in &self.rows {
+            let mut record = vec![row.name.clone(), row.uid.clone().unwrap_or_default()];
+            record.extend(row.seconds.iter().map(|&seconds| format_hours(seconds)));
+            record.push(format_hours(row.total));
+            rows.push(record);
+        }
+
+        let mut totals = vec![String::from("Total"), String::new()];
+        totals.extend(self.totals.iter().map(|&seconds| format_hours(seconds)));
+        totals.push(format_hours(self.total));
+        rows.push(totals);
+
+        rows
+    }
+}
+
+/// Days covered by a timesheet grid and rounding of its cells
+pub struct Grid {
+    /// week or month covered by the grid
+    pub period: GridPeriod,
+
+    /// date and time within the period, now if there is none
+    pub anchor: Option<String>,
+
+    /// unit and mode of rounding of each cell, if any
+    pub round: Option<(TimeDelta, RoundingMode)>,
+}
+
+/// Row of a timesheet that a task contributes to
+struct RowKey {
+    name: String,
+    uid: Option<String>,
+}
+
+/// Returns the row each task of a tree contributes to, by task id
+///
+/// Each task has its own row, except for tasks deeper than `max_depth` which contribute to the
+/// row of their ancestor at `max_depth`. The root task contributes to a row named after the tree.
+///
+/// # Panics
+/// This function may panic if database operations fail
+async fn task_rows(
+    conn: &mut SqliteConnection,
+    tree_name: &String,
+    max_depth: Option<u32>,
+) -> Result<HashMap<String, RowKey>, Box<dyn Error>> {
+    let query_result = sqlx::query!(
+        r#"
+        SELECT id, name, "left", "right"
+        FROM task
+        WHERE tree_name = ?
+        ORDER BY "left";
+        "#,
+        tree_name,
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    // error handling
+    let tasks = match query_result {
+        Ok(records) => {
+            if records.is_empty() {
+                return Err(format!("Tree '{tree_name}' not found").into());
+            }
+            records
+        }
+        Err(query_error) => panic!("Database query failed: {query_error}"),
+    };
+
+    let max_depth = max_depth.map_or(usize::MAX, |max_depth| {
+        usize::try_from(max_depth).unwrap_or(usize::MAX)
+    });
+
+    // ancestors[depth] is the ancestor of the current task at this depth, the root being at 0
+    let mut ancestors: Vec<usize> = Vec::new();
+    let mut rows = HashMap::with_capacity(tasks.len());
+    for (index, task) in tasks.iter().enumerate() {
+        while ancestors
+            .last()
+            .is_some_and(|&ancestor| tasks[ancestor].right < task.left)
+        {
+            ancestors.pop();
+        }
+        ancestors.push(index);
+
+        let depth = (ancestors.len() - 1).min(max_depth);
+        let key = if depth == 0 {
+            RowKey {
+                name: tree_name.clone(),
+                uid: None,
+            }
+        } else {
+            let path: Vec<&str> = ancestors[1..=depth]
+                .iter()
+                .map(|&ancestor| tasks[ancestor].name.as_str())
+                .collect();
+            RowKey {
+                name: path.join(" > "),
+                uid: Some(tasks[ancestors[depth]].id.clone()),
+            }
+        };
+        rows.insert(task.id.clone(), key);
+    }
+
+    Ok(rows)
+}
+
+/// Prints a timesheet grid of the time spent on each tree, or on each task of a tree, on each day
+/// of the week or month containing the anchor of the grid
+///
+/// Frames are split between days in the local time at which they were recorded, and running
+/// frames count up to now. If the grid is rounded, the time of each cell is rounded to a multiple
+/// of the given unit, and totals are the sums of the rounded cells.
+///
+/// # Errors
+/// Returns an error if a date is ill-formed, if the forest is empty, if the given tree does not
+/// exist in forest or if a depth is given when reporting by tree
+///
+/// # Panics
+/// This function may panic if database operations fail
+pub async fn grid(
+    grid: Grid,
+    tag_filter: &[String],
+    tree_name: Option<String>,
+    grouping: ReportGrouping,
+    max_depth: Option<u32>,
+    output: OutputFormat,
+) -> Result<(), Box<dyn Error>> {
+    if max_depth.is_some() && grouping == ReportGrouping::Tree {
+        return Err("--depth can only be used with --by task".into());
+    }
+
+    let anchor = timetracking::parse_user_datetime(&grid.anchor)?.date_naive();
+
+    let pool = dbutils::load_db().await;
+
+    // days covered by the timesheet
+    let first_day = match grid.period {
+        GridPeriod::Week => anchor
+            .week(dbutils::get_week_start(&pool).await)
+            .first_day(),
+        GridPeriod::Month => anchor
+            .with_day(1)
+            .ok_or("Cannot compute the first day of the month")?,
+    };
+    let end_day = match grid.period {
+        GridPeriod::Week => first_day.checked_add_days(Days::new(7)),
+        GridPeriod::Month => first_day.checked_add_months(Months::new(1)),
+    }
+    .ok_or("Cannot compute the end of the timesheet")?;
+    let days: Vec<NaiveDate> = first_day
+        .iter_days()
+        .take_while(|day| *day < end_day)
+        .collect();
+
+    // boundaries of each day in local time as if it was UTC, the last one being the end of the
+    // timesheet, so that frames count in the days of the timezone they were recorded in
+    let boundaries: Vec<i64> = days
+        .iter()
+        .chain(std::iter::once(&end_day))
+        .map(|day| timetracking::zoned_midnight(*day))
+        .collect();
+    let from = timetracking::local_midnight(first_day)?;
+    let to = timetracking::local_midnight(end_day)?;
+    let now = Local::now().timestamp_millis();
+
+    // trees are only filtered when reporting by tree, tasks always belong to a single tree
+    let tree_name = match (grouping, tree_name) {
+        (ReportGrouping::Task, None) => Some(dbutils::get_current_tree_name(&pool).await?),
+        (_, tree_name) => tree_name,
+    };
+
+    let mut conn = pool
+        .acquire()
+        .await
+        .expect("Acquiring connection to database should succeed");
+
+    let task_rows = match (grouping, &tree_name) {
+        (ReportGrouping::Task, Some(tree_name)) => {
+            Some(task_rows(&mut conn, tree_name, max_depth).await?)
+        }
+        (_, Some(tree_name)) => {
+            let query_result = sqlx::query!(
+                r#"
+                SELECT name
+                FROM tree
+                WHERE name = ?;
+                "#,
+                tree_name,
+            )
+            .fetch_optional(&mut *conn)
+            .await;
+
+            // error handling
+            match query_result {
+                Ok(Some(_)) => None,
+                Ok(None) => return Err(format!("Tree '{tree_name}' not found").into()),
+                Err(query_error) => panic!("Database query failed: {query_error}"),
+            }
+        }
+        _ => None,
+    };
+
+    // get all frames overlapping the timesheet in the local time at which they were recorded,
+    // running frames ending now
+    let filter_by_tag = !tag_filter.is_empty();
+    let tags_json = tag::json_array(tag_filter);
+    let query_result = sqlx::query!(
+        r#"
+        SELECT
+            f.local_start AS "start!: i64",
+            COALESCE(f.local_end, ? + f.shift) AS "end!: i64",
+            t.id AS task_id,
+            t.tree_name
+        FROM zoned_frame f INNER JOIN task t ON f.task_id = t.id
+        WHERE
+            f.local_start < ? AND COALESCE(f.local_end, ? + f.shift) > ? AND
+            (? IS NULL OR t.tree_name = ?) AND (
+                -- if tags are given, only keep tasks that have one of the tags or
+                -- which have an ancestor with one of the tags
+                NOT ? OR EXISTS (
+                    SELECT *
+                    FROM task a INNER JOIN task_tag tt ON tt.task_id = a.id
+                    WHERE
+                        a.tree_name = t.tree_name AND
+                        a."left" <= t."left" AND t."right" <= a."right" AND
+                        tt.tag_name IN (SELECT value FROM json_each(?))
+                )
+            )
+        ORDER BY t.tree_name, t."left";
+        "#,
+        now,
+        boundaries[days.len()],
+        now,
+        boundaries[0],
+        tree_name,
+        tree_name,
+        filter_by_tag,
+        tags_json,
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    // error handling
+    let frames = match query_result {
+        Ok(records) => records,
+        Err(query_error) => panic!("Database query failed: {query_error}"),
+    };
+
+    // sum the time spent on each row on each day, in milliseconds
+    let mut rows: Vec<(RowKey, Vec<i64>)> = Vec::new();
+    for frame in frames {
+        let key = match &task_rows {
+            Some(task_rows) => match task_rows.get(&frame.task_id) {
+                Some(key) => RowKey {
+                    name: key.name.clone(),
+                    uid: key.uid.clone(),
+                },
+                None => continue,
+            },
+            None => RowKey {
+                name: frame.tree_name,
+                uid: None,
+            },
+        };
+
+        let index = match rows
+            .iter()
+            .position(|(row, _)| row.name == key.name && row.uid == key.uid)
+        {
+            Some(index) => index,
+            None => {
+                rows.push((key, vec![0; days.len()]));
+                rows.len() - 1
+            }
+        };
+
+        // split the frame between the days it overlaps
+        for (day, bounds) in boundaries.windows(2).enumerate() {
+            let overlap = frame.end.min(bounds[1]) - frame.start.max(bounds[0]);
+            if overlap > 0 {
+                rows[index].1[day] += overlap;
+            }
+        }
+    }
+
+    // rows are ordered like tasks of the tree, so order them by name only when reporting by tree
+    if task_rows.is_none() {
+        rows.sort_by(|a, b| a.0.name.cmp(&b.0.name));
+    }
+
+    // round each cell, so that totals are consistent with the grid
+    let round_cell = |milliseconds: i64| match grid.round {
+        Some((unit, mode)) => mode.round(milliseconds / 1000, unit.num_seconds()),
+        None => milliseconds / 1000,
+    };
+    let rows: Vec<TimesheetRow> = rows
+        .into_iter()
+        .map(|(key, cells)| {
+            let seconds: Vec<i64> = cells.into_iter().map(round_cell).collect();
+            TimesheetRow {
+                name: key.name,
+                uid: key.uid,
+                total: seconds.iter().sum(),
+                seconds,
+            }
+        })
+        .collect();
+    let totals: Vec<i64> = (0..days.len())
+        .map(|day| rows.iter().map(|row| row.seconds[day]).sum())
+        .collect();
+
+    output::print(
+        &Timesheet {
+            from: output::format_rfc3339(from),
+            to: output::format_rfc3339(to),
+            days: days
+                .iter()
+                .map(|day| day.format("%Y-%m-%d").to_string())
+                .collect(),
+            dates: days,
+            total: totals.iter().sum(),
+            totals,
+            rows,
+        },
+        output,
+    )
+}
@@ -1,14 +1,18 @@
-use chrono::{DateTime, Local, NaiveDateTime, NaiveTime, TimeDelta, Utc};
+use chrono::{DateTime, Datelike, Days, Local, Months, NaiveDate, NaiveTime, TimeDelta, Weekday};
+use serde::Serialize;
+use sqlx::SqliteConnection;
+use std::collections::HashMap;
 use std::error::Error;
 
 use super::ansi;
 use super::dbutils;
+use super::frame;
 use super::notetaking;
+use super::output::{self, Render};
+use super::tag;
+use super::task;
 use super::tree;
-use forest_types::Uid;
-
-const DATE_FORMAT: &str = "%Y-%m-%d";
-const TIME_FORMAT: &str = "%H:%M";
+use forest_types::{OutputFormat, ReportGrouping, Uid};
 
 /// Returns a human-friendly representation of WHEN was the given datetime compared to now
 fn when(datetime: DateTime<Local>) -> String {
@@ -35,120 +39,251 @@ fn when(datetime: DateTime<Local>) -> String {
 /// Parses a user datetime and returns the parse Datetime.
 /// If input user datetime is `None`, returns `Local::now()`.
 ///
-/// Input format are "%Y-%m-%d %H:%M" "%H:%M".
+/// See `forest_types::parse_datetime` for accepted formats, eg. "2024-06-01 9:30", "17:30",
+/// "yesterday 17:30", "monday 9:00", "-15m" or "2h ago".
 ///
 /// # Errors
 /// Returns an error if the input string is ill-formed
-fn parse_user_datetime(
+pub(super) fn parse_user_datetime(
     user_datetime_str: &Option<String>,
 ) -> Result<DateTime<Local>, Box<dyn Error>> {
-    // Date time format to use for parsing user input
-    let mut datetime_format = String::from(DATE_FORMAT);
-    datetime_format.push(' ');
-    datetime_format.push_str(TIME_FORMAT);
-
-    let datetime: DateTime<Local> = match user_datetime_str {
+    let now = Local::now();
+    match user_datetime_str {
         // if user datetime provided, parse it
-        Some(datetime) => {
-            // try to parse DATE AND TIME format "%Y-%m-%d %H:%M"
-            let naive_datetime = match NaiveDateTime::parse_from_str(datetime, &datetime_format) {
-                Ok(naive_datetime) => naive_datetime,
-
-                // if failed to parse DATE AND TIME format "%Y-%m-%d %H:%M",
-                // try to parse only TIME format "%H:%M"
-                Err(_) => match NaiveTime::parse_from_str(datetime, TIME_FORMAT) {
-                    Ok(naive_time) => Utc::now().date_naive().and_time(naive_time),
-
-                    // failed to parse both formats
-                    Err(_) => {
-                        return Err(format!(
-                            "Illegal date format. Date format should be \"{}\" or \"{}\"",
-                            datetime_format, TIME_FORMAT
-                        )
-                        .into());
-                    }
-                },
-            };
+        Some(datetime) => Ok(forest_types::parse_datetime(datetime, &now)?),
+        // if no user datetime provided, return current time
+        None => Ok(now),
+    }
+}
 
-            // fit the parsed date time to the local timezone
-            naive_datetime
-                .and_local_timezone(Local)
-                .single()
-                .expect("Timezone convertion should not fail. See https://docs.rs/chrono/latest/chrono/offset/type.MappedLocalTime.html#variant.None")
+/// Returns a description of what is being recorded: either a whole tree or a task of a tree,
+/// given by the path from its top-level ancestor
+pub(super) fn format_target(tree_name: &str, path: &[String]) -> String {
+    let tree_name = ansi::format(tree_name, ansi::ForestFormat::TreeName);
+    if path.is_empty() {
+        format!("tree {tree_name}")
+    } else {
+        let path: Vec<String> = path
+            .iter()
+            .map(|name| ansi::format(name, ansi::ForestFormat::TaskName))
+            .collect();
+        format!("task {} of tree {tree_name}", path.join(" > "))
+    }
+}
+
+/// Returns a human-friendly representation of a duration in seconds
+pub(super) fn format_duration(seconds: i64) -> String {
+    let time_delta = TimeDelta::seconds(seconds);
+    format!(
+        "{}h {}m",
+        time_delta.num_hours(),
+        time_delta.num_minutes() % 60
+    )
+}
+
+/// Returns the id of the task to record time on and the name of its tree
+///
+/// `target` may either be the name of a tree or the uid of a task. A tree name takes precedence
+/// over a task uid. If neither `target` nor `task_uid` is given, the current tree is used. Time
+/// recorded on a tree is attached to its root task.
+///
+/// # Errors
+/// Returns an error if the forest is empty or if the given tree or task does not exist in forest
+///
+/// # Panics
+/// This function may panic if database operations fail
+pub(super) async fn resolve_target(
+    pool: &sqlx::sqlite::SqlitePool,
+    target: Option<String>,
+    task_uid: Option<String>,
+) -> Result<(String, String), Box<dyn Error>> {
+    let mut conn = pool
+        .acquire()
+        .await
+        .expect("Acquiring connection to database should succeed");
+
+    // find out whether the positional argument refers to a tree or to a task
+    let (tree_name, task_uid) = match (target, task_uid) {
+        (_, Some(task_uid)) => (None, Some(task_uid)),
+        (Some(target), None) => {
+            let query_result = sqlx::query!(
+                r#"
+                SELECT name
+                FROM tree
+                WHERE name = ?;
+                "#,
+                target,
+            )
+            .fetch_optional(&mut *conn)
+            .await;
+
+            match query_result {
+                Ok(Some(_)) => (Some(target), None),
+                Ok(None) => {
+                    if forest_types::uid_parser(&target).is_err() {
+                        return Err(format!("No tree or task matching '{target}' found").into());
+                    }
+                    (None, Some(target))
+                }
+                Err(query_error) => panic!("Database query failed: {query_error}"),
+            }
         }
-        // if no user datetime provided, return current time
-        None => Local::now(),
+        (None, None) => match dbutils::find_directory_context()? {
+            // a task pinned to the current directory takes precedence over its tree
+            Some(dbutils::DirectoryContext {
+                task_uid: Some(task_uid),
+                ..
+            }) => (None, Some(task_uid)),
+            _ => (Some(dbutils::get_current_tree_name(pool).await?), None),
+        },
+    };
+
+    let task_uid = match task_uid {
+        Some(task_uid) => Some(task::find_uid_from_partial_in_forest(&task_uid, None).await?),
+        None => None,
     };
 
-    Ok(datetime)
+    // get the task to record time on: either the given task or the root task of the tree
+    let query_result = sqlx::query!(
+        r#"
+        SELECT "id", tree_name
+        FROM task
+        WHERE id = ? OR (tree_name = ? AND "left" = 1);
+        "#,
+        task_uid,
+        tree_name,
+    )
+    .fetch_one(&mut *conn)
+    .await;
+
+    match query_result {
+        Ok(record) => Ok((record.id, record.tree_name)),
+        Err(query_error) => match query_error {
+            sqlx::Error::RowNotFound => {
+                Err(format!("Tree '{}' not found", tree_name.unwrap_or_default()).into())
+            }
+            other_error => panic!("Database query failed: {other_error}"),
+        },
+    }
+}
+
+/// Frame currently recording time
+struct OpenFrame {
+    id: String,
+    start: i64,
+    tree_name: String,
+    task_id: String,
+    session_id: Option<String>,
+}
+
+/// Returns the frame currently recording time, if any
+///
+/// # Panics
+/// This function may panic if database operations fail
+async fn get_open_frame(conn: &mut SqliteConnection) -> Option<OpenFrame> {
+    // note: a database index guarantees that at most one frame is running
+    let query_result = sqlx::query_as!(
+        OpenFrame,
+        r#"
+        SELECT f.id, "start", "tree_name", t.id AS task_id, f.session_id
+        FROM frame f INNER JOIN task t ON f.task_id = t.id
+        WHERE f."end" is NULL;
+        "#,
+    )
+    .fetch_optional(&mut *conn)
+    .await;
+
+    match query_result {
+        Ok(frame) => frame,
+        Err(query_error) => panic!("Database query failed: {query_error}"),
+    }
 }
-/// Starts recording time spent on a tree
+
+/// Starts recording time spent on a tree or on a task
+///
+/// `target` may either be the name of a tree or the uid of a task. A tree name takes precedence
+/// over a task uid. If neither `target` nor `task_uid` is given, time is recorded on the current
+/// tree. The current recording, if any, is stopped when the new one starts. Frames overlapping the
+/// new recording are trimmed if `adjust` is set.
 ///
 /// # Errors
-/// Returns an error if the forest is empty or if the given tree name does not exist in forest
+/// Returns an error if the forest is empty, if the given tree or task does not exist in forest, if
+/// the new recording would start before the current one, or if it would overlap other frames
 ///
 /// # Panics
 /// This function may panic if database operations fail
 pub async fn start(
-    tree_name: Option<String>,
+    target: Option<String>,
+    task_uid: Option<String>,
     datetime: Option<String>,
+    adjust: bool,
 ) -> Result<(), Box<dyn Error>> {
     let start_datetime = parse_user_datetime(&datetime)?;
+    let start_time = start_datetime.timestamp_millis();
 
     let pool = dbutils::load_db().await;
 
-    // get tree name if one was provided, current tree name otherwise
-    let tree_name = match tree_name {
-        Some(name) => name,
-        None => dbutils::get_current_tree_name(&pool).await?,
-    };
+    let (task_id, tree_name) = resolve_target(&pool, target, task_uid).await?;
 
     let mut conn = pool
         .acquire()
         .await
         .expect("Acquiring connection to database should succeed");
 
-    // get root task id of tree
+    let running_frame = get_open_frame(&mut conn).await;
+    if let Some(running_frame) = &running_frame {
+        if start_time <= running_frame.start {
+            return Err("A recording cannot start before the current recording started".into());
+        }
+
+        // make sure the new recording can start before stopping the current one
+        if !adjust {
+            frame::resolve_overlaps(&mut conn, start_time, None, &[&running_frame.id], false)
+                .await?;
+        }
+
+        stop_recording(&mut conn, start_datetime, true).await?;
+    }
+
+    let mut transaction = pool
+        .begin()
+        .await
+        .expect("Beginning a transaction should succeed");
+
+    frame::resolve_overlaps(&mut transaction, start_time, None, &[], adjust).await?;
+
+    // a new recording cannot resume a paused one afterwards
     let query_result = sqlx::query!(
         r#"
-        SELECT "id"
-        FROM task
-        WHERE tree_name = ? AND "left" = 1;
+        UPDATE frame
+        SET paused = 0
+        WHERE paused;
         "#,
-        tree_name,
     )
-    .fetch_one(&mut *conn)
+    .execute(&mut *transaction)
     .await;
 
-    let task = match query_result {
-        Ok(record) => record,
-        Err(query_error) => match query_error {
-            sqlx::Error::RowNotFound => {
-                return Err(format!("Tree '{tree_name}' not found").into());
-            }
-            other_error => panic!("Database query failed: {other_error}"),
-        },
-    };
-
-    // stop any previous recording
-    let _ = stop(datetime, true).await;
+    // error handling
+    if let Err(query_error) = query_result {
+        panic!("Database query failed: {query_error}");
+    }
 
     // insert a new time frame into the frame table
     let new_frame_uid = Uid::new();
-    let start_time = start_datetime.timestamp_millis();
     let end_time: Option<i32> = None;
-    let root_task_id = task.id;
+    let utc_offset = start_datetime.offset().local_minus_utc();
     let query_result = sqlx::query!(
         r#"
-        INSERT INTO frame("id", "start", "end", "task_id")
-        VALUES(?, ?, ?, ?);
+        INSERT INTO frame("id", "start", "end", "task_id", "utc_offset")
+        VALUES(?, ?, ?, ?, ?);
         "#,
         new_frame_uid,
         start_time,
         end_time,
-        root_task_id,
+        task_id,
+        utc_offset,
     )
-    .execute(&mut *conn)
+    .execute(&mut *transaction)
     .await;
 
     // error handling
@@ -161,7 +296,7 @@ pub async fn start(
         Err(query_error) => match query_error {
             sqlx::Error::Database(db_error) => match db_error.kind() {
                 sqlx::error::ErrorKind::UniqueViolation => {
-                    panic!("Frame id should be unique");
+                    panic!("Frame id should be unique and a single frame should be running");
                 }
                 _ => panic!("Database query failed: {db_error}"),
             },
@@ -169,9 +304,15 @@ pub async fn start(
         },
     }
 
+    transaction
+        .commit()
+        .await
+        .expect("Committing a transaction should succeed");
+
+    let path = task::path(&mut conn, &task_id).await;
     println!(
-        "Started recording time on tree {} at {}",
-        ansi::format(&tree_name, ansi::ForestFormat::TreeName),
+        "Started recording time on {} at {}",
+        format_target(&tree_name, &path),
         ansi::format(
             &start_datetime.format("%H:%M").to_string(),
             ansi::ForestFormat::Time
@@ -183,10 +324,10 @@ pub async fn start(
     Ok(())
 }
 
-/// Stops the current time recording(s)
+/// Stops the current time recording, or the paused one
 ///
 /// # Errors
-/// Returns an error if no recording were started
+/// Returns an error if no recording was started or if it would stop before it started
 ///
 /// # Panics
 /// This function may panic if database operations fail
@@ -200,195 +341,1427 @@ pub async fn stop(datetime: Option<String>, create_note: bool) -> Result<(), Box
         .await
         .expect("Acquiring connection to database should succeed");
 
-    // get all started time tracking frames
-    // note: there should only be one simultaneous time recording  at any time
+    stop_recording(&mut conn, stop_datetime, create_note).await
+}
+
+/// Stops the current time recording at the given date and time
+///
+/// If the recording is paused, it simply cannot be resumed anymore.
+///
+/// # Errors
+/// Returns an error if no recording was started or if it would stop before it started
+///
+/// # Panics
+/// This function may panic if database operations fail
+async fn stop_recording(
+    conn: &mut SqliteConnection,
+    stop_datetime: DateTime<Local>,
+    create_note: bool,
+) -> Result<(), Box<dyn Error>> {
+    // get the started time tracking frame
+    let Some(frame) = get_open_frame(conn).await else {
+        return stop_paused_recording(conn, create_note).await;
+    };
+
+    let end_time = stop_datetime.timestamp_millis();
+    if end_time <= frame.start {
+        return Err("A recording cannot stop before it started".into());
+    }
+
+    // update end time of started time tracking frame
     let query_result = sqlx::query!(
         r#"
-        SELECT "start", "tree_name"
-        FROM frame f INNER JOIN task t ON f.task_id = t.id
-        WHERE f."end" is NULL;
+        UPDATE frame
+        SET "end" = ?
+        WHERE id = ?;
         "#,
+        end_time,
+        frame.id,
     )
-    .fetch_all(&mut *conn)
+    .execute(&mut *conn)
     .await;
 
     // error handling
-    let started_frames = match query_result {
-        Ok(records) => {
-            if records.is_empty() {
-                return Err("No recording was started".into());
-            } else {
-                records
+    match query_result {
+        Ok(result) => {
+            if result.rows_affected() != 1 {
+                panic!("Stopping a recording should update a single row");
             }
         }
         Err(query_error) => panic!("Database query failed: {query_error}"),
     };
 
-    // update end time of started time tracking frame(s)
-    let end_time = stop_datetime.timestamp_millis();
+    if create_note {
+        // create a new note to write what was done in this work session
+        notetaking::add(Some(frame.tree_name.clone()), Some(frame.id.clone())).await?;
+    }
+
+    let start_time: DateTime<Local> = DateTime::from_timestamp_millis(frame.start).unwrap().into();
+    let path = task::path(&mut *conn, &frame.task_id).await;
+    println!(
+        "Stopped recording time on {}, started {} ({} {})",
+        format_target(&frame.tree_name, &path),
+        ansi::format(&when(start_time), ansi::ForestFormat::Time),
+        ansi::format(
+            &start_time.format("%Y-%m-%d").to_string(),
+            ansi::ForestFormat::Date
+        ),
+        ansi::format(
+            &start_time.format("%H:%M").to_string(),
+            ansi::ForestFormat::Time
+        ),
+    );
+
+    Ok(())
+}
+
+/// Stops the paused time recording, so that it cannot be resumed anymore
+///
+/// # Errors
+/// Returns an error if no recording was paused
+///
+/// # Panics
+/// This function may panic if database operations fail
+async fn stop_paused_recording(
+    conn: &mut SqliteConnection,
+    create_note: bool,
+) -> Result<(), Box<dyn Error>> {
     let query_result = sqlx::query!(
         r#"
         UPDATE frame
-        SET "end" = ?
-        WHERE "end" is NULL;
+        SET paused = 0
+        WHERE paused
+        RETURNING
+            id,
+            "end" AS "end!: i64",
+            task_id,
+            (SELECT tree_name FROM task WHERE id = frame.task_id) AS "tree_name!: String";
         "#,
-        end_time
     )
-    .execute(&mut *conn)
+    .fetch_optional(&mut *conn)
     .await;
 
     // error handling
-    match query_result {
-        Ok(result) => {
-            if result.rows_affected() < 1 {
-                panic!("Stopping a recording should at least update one row");
-            }
-        }
+    let frame = match query_result {
+        Ok(Some(record)) => record,
+        Ok(None) => return Err("No recording was started".into()),
         Err(query_error) => panic!("Database query failed: {query_error}"),
     };
 
-    // in case multiple time recordings were started
-    // print stopping message for each
-    for frame in started_frames {
-        if create_note {
-            // create a new note to write what was done in this work session
-            notetaking::add(Some(frame.tree_name.clone()), true).await?;
-        }
-
-        let start_time: DateTime<Local> =
-            DateTime::from_timestamp_millis(frame.start).unwrap().into();
-        println!(
-            "Stopped recording time on tree {}, started {} ({} {})",
-            ansi::format(&frame.tree_name, ansi::ForestFormat::TreeName),
-            ansi::format(&when(start_time), ansi::ForestFormat::Time),
-            ansi::format(
-                &start_time.format("%Y-%m-%d").to_string(),
-                ansi::ForestFormat::Date
-            ),
-            ansi::format(
-                &start_time.format("%H:%M").to_string(),
-                ansi::ForestFormat::Time
-            ),
-        );
+    if create_note {
+        // create a new note to write what was done in this work session
+        notetaking::add(Some(frame.tree_name.clone()), Some(frame.id.clone())).await?;
     }
 
+    let pause_time: DateTime<Local> = DateTime::from_timestamp_millis(frame.end).unwrap().into();
+    let path = task::path(&mut *conn, &frame.task_id).await;
+    println!(
+        "Stopped recording time on {}, paused {} ({} {})",
+        format_target(&frame.tree_name, &path),
+        ansi::format(&when(pause_time), ansi::ForestFormat::Time),
+        ansi::format(
+            &pause_time.format("%Y-%m-%d").to_string(),
+            ansi::ForestFormat::Date
+        ),
+        ansi::format(
+            &pause_time.format("%H:%M").to_string(),
+            ansi::ForestFormat::Time
+        ),
+    );
+
     Ok(())
 }
 
-/// Prints the name of the current tree and current time tracking frames
+/// Pauses the current time recording, without creating a note
 ///
 /// # Errors
-/// Returns an error if the forest is empty
+/// Returns an error if no recording was started or if it would pause before it started
 ///
 /// # Panics
 /// This function may panic if database operations fail
-pub async fn status() -> Result<(), Box<dyn Error>> {
-    let pool = dbutils::load_db().await;
+pub async fn pause(datetime: Option<String>) -> Result<(), Box<dyn Error>> {
+    let pause_datetime = parse_user_datetime(&datetime)?;
+    let pause_time = pause_datetime.timestamp_millis();
 
-    let current_tree_name = dbutils::get_current_tree_name(&pool).await?;
+    let pool = dbutils::load_db().await;
 
     let mut conn = pool
         .acquire()
         .await
         .expect("Acquiring connection to database should succeed");
 
-    // get current frame if any
+    let frame = get_open_frame(&mut conn)
+        .await
+        .ok_or("No recording was started")?;
+
+    if pause_time <= frame.start {
+        return Err("A recording cannot pause before it started".into());
+    }
+
     let query_result = sqlx::query!(
         r#"
-        SELECT "start", "tree_name"
-        FROM frame f
-        INNER JOIN task t ON f.task_id = t.id
-        WHERE f."end" is NULL;
+        UPDATE frame
+        SET "end" = ?, paused = 1
+        WHERE id = ?;
         "#,
+        pause_time,
+        frame.id,
     )
-    .fetch_optional(&mut *conn)
+    .execute(&mut *conn)
     .await;
 
     // error handling
-    let current_frame = match query_result {
-        Ok(record) => record,
-        Err(query_error) => panic!("Database query failed: {query_error}"),
-    };
+    if let Err(query_error) = query_result {
+        panic!("Database query failed: {query_error}");
+    }
 
-    // print current tree
+    let path = task::path(&mut conn, &frame.task_id).await;
     println!(
-        "On tree {}",
-        ansi::format(&current_tree_name, ansi::ForestFormat::TreeName)
+        "Paused recording time on {} at {}",
+        format_target(&frame.tree_name, &path),
+        ansi::format(
+            &pause_datetime.format("%H:%M").to_string(),
+            ansi::ForestFormat::Time
+        )
     );
 
-    // print current time tracking recording if any
-    match current_frame {
-        Some(frame) => {
-            let start_time: DateTime<Local> =
-                DateTime::from_timestamp_millis(frame.start).unwrap().into();
-            println!(
-                "Recording time on tree {}, started {} ({} {})",
-                ansi::format(&frame.tree_name, ansi::ForestFormat::TreeName),
-                ansi::format(&when(start_time), ansi::ForestFormat::Time),
-                ansi::format(
-                    &start_time.format("%Y-%m-%d").to_string(),
-                    ansi::ForestFormat::Date
-                ),
-                ansi::format(
-                    &start_time.format("%H:%M").to_string(),
-                    ansi::ForestFormat::Time
-                ),
-            );
-        }
-        None => println!("No recording started."),
-    }
-
     Ok(())
 }
 
-/// Prints tree names and time spent on each
+/// Resumes the paused time recording, on the same tree or task and within the same session
 ///
 /// # Errors
-/// Returns an error if the forest is empty
+/// Returns an error if no recording was paused, if it would resume before it was paused, or if
+/// the resumed recording would overlap other frames
 ///
 /// # Panics
 /// This function may panic if database operations fail
-pub async fn report() {
+pub async fn resume(datetime: Option<String>) -> Result<(), Box<dyn Error>> {
+    let resume_datetime = parse_user_datetime(&datetime)?;
+    let resume_time = resume_datetime.timestamp_millis();
+
     let pool = dbutils::load_db().await;
 
-    let mut conn = pool
-        .acquire()
+    let mut transaction = pool
+        .begin()
         .await
-        .expect("Acquiring connection to database should succeed");
+        .expect("Beginning a transaction should succeed");
 
-    // get total time spent on every tree
     let query_result = sqlx::query!(
         r#"
-        -- get total time spent on each tree
-
-        SELECT tree_name as name, SUM(f."end" - f."start") as total_time_spent
-        FROM frame f
-        RIGHT JOIN task t ON f.task_id = t.id
-        GROUP BY tree_name;
+        UPDATE frame
+        SET paused = 0
+        WHERE paused
+        RETURNING
+            "end" AS "end!: i64",
+            COALESCE(session_id, id) AS "session_id!: String",
+            task_id,
+            (SELECT tree_name FROM task WHERE id = frame.task_id) AS "tree_name!: String";
         "#,
     )
-    .fetch_all(&mut *conn)
+    .fetch_optional(&mut *transaction)
     .await;
 
     // error handling
-    let records = match query_result {
-        Ok(records) => records,
+    let frame = match query_result {
+        Ok(Some(record)) => record,
+        Ok(None) => return Err("No recording was paused".into()),
         Err(query_error) => panic!("Database query failed: {query_error}"),
     };
 
-    // print tree names and time spent
-    for tree in records {
-        let time_delta = TimeDelta::milliseconds(tree.total_time_spent.unwrap_or(0));
-        let hours = time_delta.num_hours();
-        let minutes = time_delta.num_minutes() % 60;
-        print!(
-            "{} - {}h {}m",
-            ansi::format(&tree.name, ansi::ForestFormat::TreeName),
-            hours,
-            minutes
-        );
+    if resume_time <= frame.end {
+        return Err("A recording cannot resume before it was paused".into());
+    }
 
-        println!();
-        println!();
+    frame::resolve_overlaps(&mut transaction, resume_time, None, &[], false).await?;
+
+    let new_frame_uid = Uid::new();
+    let utc_offset = resume_datetime.offset().local_minus_utc();
+    let query_result = sqlx::query!(
+        r#"
+        INSERT INTO frame("id", "start", "end", "task_id", "session_id", "utc_offset")
+        VALUES(?, ?, NULL, ?, ?, ?);
+        "#,
+        new_frame_uid,
+        resume_time,
+        frame.task_id,
+        frame.session_id,
+        utc_offset,
+    )
+    .execute(&mut *transaction)
+    .await;
+
+    // error handling
+    if let Err(query_error) = query_result {
+        panic!("Database query failed: {query_error}");
+    }
+
+    let path = task::path(&mut transaction, &frame.task_id).await;
+
+    transaction
+        .commit()
+        .await
+        .expect("Committing a transaction should succeed");
+
+    println!(
+        "Resumed recording time on {} at {}, after a break of {}",
+        format_target(&frame.tree_name, &path),
+        ansi::format(
+            &resume_datetime.format("%H:%M").to_string(),
+            ansi::ForestFormat::Time
+        ),
+        format_duration((resume_time - frame.end) / 1000),
+    );
+
+    Ok(())
+}
+
+/// Discards the current time recording entirely
+///
+/// If the recording resumed a paused one, the latter is paused again.
+///
+/// # Errors
+/// Returns an error if no recording was started
+///
+/// # Panics
+/// This function may panic if database operations fail
+pub async fn cancel() -> Result<(), Box<dyn Error>> {
+    let pool = dbutils::load_db().await;
+
+    let mut transaction = pool
+        .begin()
+        .await
+        .expect("Beginning a transaction should succeed");
+
+    let frame = get_open_frame(&mut transaction)
+        .await
+        .ok_or("No recording was started")?;
+
+    let query_result = sqlx::query!(
+        r#"
+        DELETE FROM frame
+        WHERE id = ?;
+        "#,
+        frame.id,
+    )
+    .execute(&mut *transaction)
+    .await;
+
+    // error handling
+    if let Err(query_error) = query_result {
+        panic!("Database query failed: {query_error}");
     }
+
+    // the last remaining frame of the session is paused again
+    let query_result = sqlx::query!(
+        r#"
+        UPDATE frame
+        SET paused = 1
+        WHERE id = (
+            SELECT id
+            FROM frame
+            WHERE COALESCE(session_id, id) = ?
+            ORDER BY "start" DESC
+            LIMIT 1
+        );
+        "#,
+        frame.session_id,
+    )
+    .execute(&mut *transaction)
+    .await;
+
+    // error handling
+    if let Err(query_error) = query_result {
+        panic!("Database query failed: {query_error}");
+    }
+
+    let path = task::path(&mut transaction, &frame.task_id).await;
+
+    transaction
+        .commit()
+        .await
+        .expect("Committing a transaction should succeed");
+
+    let start_time: DateTime<Local> = DateTime::from_timestamp_millis(frame.start).unwrap().into();
+    println!(
+        "Cancelled recording time on {}, started {} ({} {})",
+        format_target(&frame.tree_name, &path),
+        ansi::format(&when(start_time), ansi::ForestFormat::Time),
+        ansi::format(
+            &start_time.format("%Y-%m-%d").to_string(),
+            ansi::ForestFormat::Date
+        ),
+        ansi::format(
+            &start_time.format("%H:%M").to_string(),
+            ansi::ForestFormat::Time
+        ),
+    );
+    if frame.session_id.is_some() {
+        println!("The previous recording of this session is paused again");
+    }
+
+    Ok(())
+}
+
+/// Starts recording time on the most recently tracked tree or task
+///
+/// As with `start`, the current recording, if any, is stopped first.
+///
+/// # Errors
+/// Returns an error if no time was ever recorded, or if the recording cannot start
+///
+/// # Panics
+/// This function may panic if database operations fail
+pub async fn restart(datetime: Option<String>, adjust: bool) -> Result<(), Box<dyn Error>> {
+    let pool = dbutils::load_db().await;
+
+    let mut conn = pool
+        .acquire()
+        .await
+        .expect("Acquiring connection to database should succeed");
+
+    let query_result = sqlx::query!(
+        r#"
+        SELECT t.id, t.tree_name, t."left"
+        FROM frame f INNER JOIN task t ON f.task_id = t.id
+        ORDER BY f."start" DESC
+        LIMIT 1;
+        "#,
+    )
+    .fetch_optional(&mut *conn)
+    .await;
+
+    // error handling
+    let last_task = match query_result {
+        Ok(Some(record)) => record,
+        Ok(None) => return Err("No time was ever recorded".into()),
+        Err(query_error) => panic!("Database query failed: {query_error}"),
+    };
+
+    // time tracked on the root task of a tree was tracked on the tree itself
+    if last_task.left == 1 {
+        start(Some(last_task.tree_name), None, datetime, adjust).await
+    } else {
+        start(None, Some(last_task.id), datetime, adjust).await
+    }
+}
+
+/// Current tree and current time recording
+#[derive(Serialize)]
+struct Status {
+    tree: String,
+    /// `.forest` file pinning the current tree, if not set by `forest switch`
+    pinned_by: Option<String>,
+    pinned_task: Option<String>,
+    recording: Option<Recording>,
+}
+
+/// Time recording in progress, or paused
+///
+/// A recording is made of a session of frames separated by breaks, from pausing to resuming it.
+#[derive(Serialize)]
+struct Recording {
+    tree: String,
+    task: Option<String>,
+    path: Vec<String>,
+    start: String,
+    session_start: String,
+    break_seconds: i64,
+    paused_since: Option<String>,
+
+    #[serde(skip)]
+    timestamps: (i64, Option<i64>),
+}
+
+/// Flat record of the status
+#[derive(Serialize)]
+struct StatusRow {
+    tree: String,
+    pinned_by: Option<String>,
+    pinned_task: Option<String>,
+    recording_tree: Option<String>,
+    recording_task: Option<String>,
+    recording_path: Option<String>,
+    recording_start: Option<String>,
+    recording_session_start: Option<String>,
+    recording_break_seconds: Option<i64>,
+    recording_paused_since: Option<String>,
+}
+
+impl Render for Status {
+    type Row = StatusRow;
+
+    fn print_text(&self) {
+        // print current tree and where it comes from
+        match &self.pinned_by {
+            Some(path) => {
+                println!(
+                    "On tree {} (pinned by {path})",
+                    ansi::format(&self.tree, ansi::ForestFormat::TreeName)
+                );
+                if let Some(task_uid) = &self.pinned_task {
+                    println!(
+                        "Pinned task {}",
+                        ansi::format(task_uid, ansi::ForestFormat::Uid)
+                    );
+                }
+            }
+            None => println!(
+                "On tree {} (set by forest switch)",
+                ansi::format(&self.tree, ansi::ForestFormat::TreeName)
+            ),
+        }
+
+        // print current time tracking recording if any
+        match &self.recording {
+            Some(recording) => {
+                let (session_start, paused_since) = recording.timestamps;
+                let start_time: DateTime<Local> = DateTime::from_timestamp_millis(session_start)
+                    .unwrap()
+                    .into();
+                if let Some(paused_since) = paused_since {
+                    let pause_time: DateTime<Local> = DateTime::from_timestamp_millis(paused_since)
+                        .unwrap()
+                        .into();
+                    print!(
+                        "Paused recording time on {} since {},",
+                        format_target(&recording.tree, &recording.path),
+                        ansi::format(
+                            &pause_time.format("%H:%M").to_string(),
+                            ansi::ForestFormat::Time
+                        ),
+                    );
+                } else {
+                    print!(
+                        "Recording time on {},",
+                        format_target(&recording.tree, &recording.path)
+                    );
+                }
+                print!(
+                    " started {} ({} {})",
+                    ansi::format(&when(start_time), ansi::ForestFormat::Time),
+                    ansi::format(
+                        &start_time.format("%Y-%m-%d").to_string(),
+                        ansi::ForestFormat::Date
+                    ),
+                    ansi::format(
+                        &start_time.format("%H:%M").to_string(),
+                        ansi::ForestFormat::Time
+                    ),
+                );
+                if recording.break_seconds > 0 {
+                    print!(
+                        " with {} of breaks",
+                        format_duration(recording.break_seconds)
+                    );
+                }
+                println!();
+            }
+            None => println!("No recording started."),
+        }
+    }
+
+    fn rows(&self) -> Vec<Self::Row> {
+        let recording = self.recording.as_ref();
+        vec![StatusRow {
+            tree: self.tree.clone(),
+            pinned_by: self.pinned_by.clone(),
+            pinned_task: self.pinned_task.clone(),
+            recording_tree: recording.map(|recording| recording.tree.clone()),
+            recording_task: recording.and_then(|recording| recording.task.clone()),
+            recording_path: recording.map(|recording| recording.path.join(" > ")),
+            recording_start: recording.map(|recording| recording.start.clone()),
+            recording_session_start: recording.map(|recording| recording.session_start.clone()),
+            recording_break_seconds: recording.map(|recording| recording.break_seconds),
+            recording_paused_since: recording.and_then(|recording| recording.paused_since.clone()),
+        }]
+    }
+}
+
+/// Prints the name of the current tree and current time tracking frames
+///
+/// # Errors
+/// Returns an error if the forest is empty
+///
+/// # Panics
+/// This function may panic if database operations fail
+pub async fn status(output: OutputFormat) -> Result<(), Box<dyn Error>> {
+    let pool = dbutils::load_db().await;
+
+    let current_tree_name = dbutils::get_current_tree_name(&pool).await?;
+    let context = dbutils::find_directory_context()?;
+
+    let mut conn = pool
+        .acquire()
+        .await
+        .expect("Acquiring connection to database should succeed");
+
+    // get current or paused frame if any, along with the start of its session and the time
+    // spent on the previous frames of the session
+    let query_result = sqlx::query!(
+        r#"
+        SELECT
+            f."start", f."end", "tree_name", t.id AS task_id, t."left",
+            (
+                SELECT MIN(s."start")
+                FROM frame s
+                WHERE COALESCE(s.session_id, s.id) = COALESCE(f.session_id, f.id)
+            ) AS "session_start!: i64",
+            (
+                SELECT SUM(s."end" - s."start")
+                FROM frame s
+                WHERE COALESCE(s.session_id, s.id) = COALESCE(f.session_id, f.id) AND s.id != f.id
+            ) AS "session_time: i64"
+        FROM frame f
+        INNER JOIN task t ON f.task_id = t.id
+        WHERE f."end" is NULL OR f.paused;
+        "#,
+    )
+    .fetch_optional(&mut *conn)
+    .await;
+
+    // error handling
+    let current_frame = match query_result {
+        Ok(record) => record,
+        Err(query_error) => panic!("Database query failed: {query_error}"),
+    };
+
+    let recording = match current_frame {
+        Some(frame) => Some(Recording {
+            path: task::path(&mut conn, &frame.task_id).await,
+            task: (frame.left != 1).then_some(frame.task_id),
+            tree: frame.tree_name,
+            start: output::format_rfc3339(frame.start),
+            session_start: output::format_rfc3339(frame.session_start),
+            // breaks are the part of the session before this frame that was not recorded
+            break_seconds: (frame.start - frame.session_start - frame.session_time.unwrap_or(0))
+                / 1000,
+            paused_since: frame.end.map(output::format_rfc3339),
+            timestamps: (frame.session_start, frame.end),
+        }),
+        None => None,
+    };
+
+    output::print(
+        &Status {
+            tree: current_tree_name,
+            pinned_by: context
+                .as_ref()
+                .map(|context| context.path.display().to_string()),
+            pinned_task: context.and_then(|context| context.task_uid),
+            recording,
+        },
+        output,
+    )
+}
+
+/// Period of time covered by a report
+pub enum Period {
+    /// from and to the given dates and times, if any
+    Between(Option<String>, Option<String>),
+
+    /// the current day
+    Today,
+
+    /// the current week
+    Week,
+
+    /// the current month
+    Month,
+}
+
+/// Range of time covered by a report, as timestamps in milliseconds
+///
+/// Frames are compared with `local_from` and `local_to`, the bounds in local time as if it was
+/// UTC, so that they count in the days of the timezone they were recorded in. Frames that are still
+/// running count up to `now`.
+struct TimeRange {
+    from: i64,
+    to: i64,
+    local_from: i64,
+    local_to: i64,
+    now: i64,
+}
+
+/// Returns the timestamp of the local midnight starting the given day
+///
+/// # Errors
+/// Returns an error if midnight does not exist in the local timezone on this day
+pub(super) fn local_midnight(date: NaiveDate) -> Result<i64, Box<dyn Error>> {
+    // when clocks are turned forward at midnight, the day starts at the end of the gap
+    (0..=3)
+        .find_map(|hour| {
+            date.and_time(NaiveTime::from_hms_opt(hour, 0, 0)?)
+                .and_local_timezone(Local)
+                .earliest()
+        })
+        .map(|datetime| datetime.timestamp_millis())
+        .ok_or_else(|| format!("Cannot determine the local time of midnight on {date}").into())
+}
+
+/// Returns the midnight starting the given day in local time as if it was UTC, in milliseconds,
+/// to be compared with the local times of frames in `zoned_frame`
+pub(super) fn zoned_midnight(date: NaiveDate) -> i64 {
+    date.and_time(NaiveTime::MIN).and_utc().timestamp_millis()
+}
+
+impl Period {
+    /// Returns the range of time covered by this period
+    ///
+    /// # Errors
+    /// Returns an error if a date is ill-formed or if the range ends before it starts
+    fn range(self, week_start: Weekday) -> Result<TimeRange, Box<dyn Error>> {
+        let now = Local::now();
+        let today = now.date_naive();
+
+        // bounds of whole days, as timestamps and in local time as if it was UTC
+        let day_bounds = |first_day: NaiveDate,
+                          next_first_day: NaiveDate|
+         -> Result<(i64, i64, i64, i64), Box<dyn Error>> {
+            Ok((
+                local_midnight(first_day)?,
+                local_midnight(next_first_day)?,
+                zoned_midnight(first_day),
+                zoned_midnight(next_first_day),
+            ))
+        };
+
+        // bound given by the user, as a timestamp and in local time, or open if there is none
+        let user_bound =
+            |datetime: &Option<String>, open: i64| -> Result<(i64, i64), Box<dyn Error>> {
+                if datetime.is_none() {
+                    return Ok((open, open));
+                }
+                let datetime = parse_user_datetime(datetime)?;
+                Ok((
+                    datetime.timestamp_millis(),
+                    datetime.naive_local().and_utc().timestamp_millis(),
+                ))
+            };
+
+        let (from, to, local_from, local_to) = match self {
+            Period::Between(from, to) => {
+                let (from, local_from) = user_bound(&from, i64::MIN)?;
+                let (to, local_to) = user_bound(&to, i64::MAX)?;
+                (from, to, local_from, local_to)
+            }
+            Period::Today => {
+                let tomorrow = today
+                    .checked_add_days(Days::new(1))
+                    .ok_or("Cannot compute the date of tomorrow")?;
+                day_bounds(today, tomorrow)?
+            }
+            Period::Week => {
+                let first_day = today.week(week_start).first_day();
+                let next_first_day = first_day
+                    .checked_add_days(Days::new(7))
+                    .ok_or("Cannot compute the date of next week")?;
+                day_bounds(first_day, next_first_day)?
+            }
+            Period::Month => {
+                let first_day = today
+                    .with_day(1)
+                    .ok_or("Cannot compute the first day of the month")?;
+                let next_first_day = first_day
+                    .checked_add_months(Months::new(1))
+                    .ok_or("Cannot compute the date of next month")?;
+                day_bounds(first_day, next_first_day)?
+            }
+        };
+
+        if from >= to {
+            return Err("The end of the report period should be after its start".into());
+        }
+
+        Ok(TimeRange {
+            from,
+            to,
+            local_from,
+            local_to,
+            now: now.timestamp_millis(),
+        })
+    }
+}
+
+impl TimeRange {
+    /// Returns the bounds of this range that are not open
+    fn bounds(&self) -> ReportBounds {
+        let from = (self.from != i64::MIN).then_some(self.from);
+        let to = (self.to != i64::MAX).then_some(self.to);
+        ReportBounds {
+            from: from.map(output::format_rfc3339),
+            to: to.map(output::format_rfc3339),
+            timestamps: (from, to),
+        }
+    }
+}
+
+/// Bounds of the period covered by a report, if any
+#[derive(Serialize)]
+struct ReportBounds {
+    from: Option<String>,
+    to: Option<String>,
+
+    #[serde(skip)]
+    timestamps: (Option<i64>, Option<i64>),
+}
+
+impl ReportBounds {
+    /// Prints the period covered by a report, if it is bounded
+    fn print_text(&self) {
+        if self.timestamps == (None, None) {
+            return;
+        }
+
+        let format_bound = |timestamp: Option<i64>| match timestamp {
+            Some(timestamp) => task::format_timestamp(timestamp, ansi::ForestFormat::Date),
+            None => String::from("..."),
+        };
+        println!(
+            "From {} to {}",
+            format_bound(self.timestamps.0),
+            format_bound(self.timestamps.1)
+        );
+        println!();
+    }
+}
+
+/// Returns the time spent in breaks to append to a duration, if any
+fn format_breaks(seconds: i64) -> String {
+    if seconds > 0 {
+        format!(" (breaks {})", format_duration(seconds))
+    } else {
+        String::new()
+    }
+}
+
+/// Returns the time spent in breaks within sessions of each tree, in milliseconds
+///
+/// A break spans from the end of a frame to the start of the next frame of the same session, and
+/// is clipped to the report range in the local time of the frames. Tags and trees are filtered as in reports.
+///
+/// # Panics
+/// This function may panic if database operations fail
+async fn break_times(
+    conn: &mut SqliteConnection,
+    tag_filter: &[String],
+    tree_name: Option<&String>,
+    range: &TimeRange,
+) -> HashMap<String, i64> {
+    let filter_by_tag = !tag_filter.is_empty();
+    let tags_json = tag::json_array(tag_filter);
+    let query_result = sqlx::query!(
+        r#"
+        WITH session_break AS (
+            SELECT
+                f.task_id,
+                (
+                    SELECT MAX(p.local_end)
+                    FROM zoned_frame p
+                    WHERE
+                        COALESCE(p.session_id, p.id) = f.session_id AND
+                        p.local_end <= f.local_start
+                ) AS "start",
+                f.local_start AS "end"
+            FROM zoned_frame f
+            WHERE f.session_id IS NOT NULL
+        )
+        SELECT
+            t.tree_name,
+            SUM(MIN(b."end", ?) - MAX(b."start", ?)) AS "break_time!: i64"
+        FROM session_break b INNER JOIN task t ON b.task_id = t.id
+        WHERE
+            b."start" < ? AND b."end" > ? AND
+            (? IS NULL OR t.tree_name = ?) AND (
+                -- same tag filtering as reports
+                NOT ? OR EXISTS (
+                    SELECT *
+                    FROM task a INNER JOIN task_tag tt ON tt.task_id = a.id
+                    WHERE
+                        a.tree_name = t.tree_name AND
+                        a."left" <= t."left" AND t."right" <= a."right" AND
+                        tt.tag_name IN (SELECT value FROM json_each(?))
+                )
+            )
+        GROUP BY t.tree_name;
+        "#,
+        range.local_to,
+        range.local_from,
+        range.local_to,
+        range.local_from,
+        tree_name,
+        tree_name,
+        filter_by_tag,
+        tags_json,
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    match query_result {
+        Ok(records) => records
+            .into_iter()
+            .map(|record| (record.tree_name, record.break_time))
+            .collect(),
+        Err(query_error) => panic!("Database query failed: {query_error}"),
+    }
+}
+
+/// Time spent on each tree and on each of their tasks
+#[derive(Serialize)]
+struct TreeReport {
+    #[serde(flatten)]
+    bounds: ReportBounds,
+    trees: Vec<TreeTime>,
+}
+
+/// Time spent on a tree
+#[derive(Serialize)]
+struct TreeTime {
+    name: String,
+    seconds: i64,
+    break_seconds: i64,
+    tasks: Vec<TaskTime>,
+}
+
+/// Time spent on a task, including its subtasks
+#[derive(Serialize)]
+struct TaskTime {
+    uid: String,
+    name: String,
+    depth: i64,
+    seconds: i64,
+}
+
+/// Flat record of a report by tree: tree records have no task
+#[derive(Serialize)]
+struct TreeReportRow {
+    tree: String,
+    task_uid: Option<String>,
+    task: Option<String>,
+    depth: i64,
+    seconds: i64,
+    break_seconds: Option<i64>,
+}
+
+impl Render for TreeReport {
+    type Row = TreeReportRow;
+
+    fn print_text(&self) {
+        self.bounds.print_text();
+
+        // print tree names and time spent
+        for tree in &self.trees {
+            println!(
+                "{} - {}{}",
+                ansi::format(&tree.name, ansi::ForestFormat::TreeName),
+                format_duration(tree.seconds),
+                format_breaks(tree.break_seconds),
+            );
+
+            // print time spent on tasks of this tree, indented by depth
+            for task in &tree.tasks {
+                println!(
+                    "{}{} - {}",
+                    "  ".repeat(usize::try_from(task.depth).unwrap_or_default()),
+                    ansi::format(&task.name, ansi::ForestFormat::TaskName),
+                    format_duration(task.seconds),
+                );
+            }
+
+            println!();
+        }
+    }
+
+    fn rows(&self) -> Vec<Self::Row> {
+        let mut rows = Vec::new();
+        for tree in &self.trees {
+            rows.push(TreeReportRow {
+                tree: tree.name.clone(),
+                task_uid: None,
+                task: None,
+                depth: 0,
+                seconds: tree.seconds,
+                break_seconds: Some(tree.break_seconds),
+            });
+            rows.extend(tree.tasks.iter().map(|task| TreeReportRow {
+                tree: tree.name.clone(),
+                task_uid: Some(task.uid.clone()),
+                task: Some(task.name.clone()),
+                depth: task.depth,
+                seconds: task.seconds,
+                break_seconds: None,
+            }));
+        }
+        rows
+    }
+}
+
+/// Time spent on each task of a tree
+#[derive(Serialize)]
+struct TaskReport {
+    tree: String,
+    #[serde(flatten)]
+    bounds: ReportBounds,
+    seconds: i64,
+    break_seconds: i64,
+    tasks: Vec<TaskReportItem>,
+}
+
+/// Time spent on a task, on its own and including its subtasks
+#[derive(Serialize)]
+struct TaskReportItem {
+    uid: String,
+    name: String,
+    parent: Option<String>,
+    depth: u32,
+    seconds: i64,
+    own_seconds: i64,
+
+    #[serde(skip)]
+    bounds: (i64, i64),
+}
+
+/// Flat record of a report by task
+#[derive(Serialize)]
+struct TaskReportRow {
+    tree: String,
+    uid: String,
+    name: String,
+    parent: Option<String>,
+    depth: u32,
+    seconds: i64,
+    own_seconds: i64,
+}
+
+impl Render for TaskReport {
+    type Row = TaskReportRow;
+
+    fn print_text(&self) {
+        self.bounds.print_text();
+
+        println!(
+            "{} - {}{}",
+            ansi::format(&self.tree, ansi::ForestFormat::TreeName),
+            format_duration(self.seconds),
+            format_breaks(self.break_seconds),
+        );
+
+        // the root of the tree is not listed, but it is needed to compute indents
+        let bounds: Vec<(i64, i64)> = std::iter::once((i64::MIN, i64::MAX))
+            .chain(self.tasks.iter().map(|task| task.bounds))
+            .collect();
+
+        for (task, indent) in self.tasks.iter().zip(task::tree_indents(&bounds)) {
+            println!(
+                "{}{} {} - {} (own {})",
+                indent,
+                ansi::format(
+                    Uid::try_from(task.uid.clone()).unwrap().short(),
+                    ansi::ForestFormat::Uid
+                ),
+                ansi::format(&task.name, ansi::ForestFormat::TaskName),
+                format_duration(task.seconds),
+                format_duration(task.own_seconds),
+            );
+        }
+    }
+
+    fn rows(&self) -> Vec<Self::Row> {
+        self.tasks
+            .iter()
+            .map(|task| TaskReportRow {
+                tree: self.tree.clone(),
+                uid: task.uid.clone(),
+                name: task.name.clone(),
+                parent: task.parent.clone(),
+                depth: task.depth,
+                seconds: task.seconds,
+                own_seconds: task.own_seconds,
+            })
+            .collect()
+    }
+}
+
+/// Prints time spent on each tree, or on each task of a tree
+///
+/// If `tag_filter` is not empty, only time spent on tasks with one of the given tags (or on their
+/// subtasks) is counted.
+///
+/// When reporting by tree, only the given tree is reported if `tree_name` is provided. When
+/// reporting by task, the given tree or the current tree is reported, and tasks deeper than `depth`
+/// are collapsed into their ancestors.
+///
+/// # Errors
+/// Returns an error if the forest is empty, if the given tree does not exist in forest or if a
+/// depth is given when reporting by tree
+///
+/// # Panics
+/// This function may panic if database operations fail
+pub async fn report(
+    tag_filter: &[String],
+    tree_name: Option<String>,
+    grouping: ReportGrouping,
+    depth: Option<u32>,
+    period: Period,
+    output: OutputFormat,
+) -> Result<(), Box<dyn Error>> {
+    if depth.is_some() && grouping == ReportGrouping::Tree {
+        return Err("--depth can only be used with --by task".into());
+    }
+
+    let pool = dbutils::load_db().await;
+
+    let range = period.range(dbutils::get_week_start(&pool).await)?;
+
+    let mut conn = pool
+        .acquire()
+        .await
+        .expect("Acquiring connection to database should succeed");
+
+    match grouping {
+        ReportGrouping::Tree => {
+            report_by_tree(&mut conn, tag_filter, tree_name, &range, output).await
+        }
+        ReportGrouping::Task => {
+            let tree_name = match tree_name {
+                Some(name) => name,
+                None => dbutils::get_current_tree_name(&pool).await?,
+            };
+            report_by_task(&mut conn, tag_filter, &tree_name, depth, &range, output).await
+        }
+    }
+}
+
+/// Prints tree names and time spent on each, along with the time spent on each task
+///
+/// Time spent on a task includes time spent on its subtasks, and time spent on a tree includes
+/// time spent on all of its tasks.
+///
+/// # Errors
+/// Returns an error if the given tree does not exist in forest
+///
+/// # Panics
+/// This function may panic if database operations fail
+async fn report_by_tree(
+    conn: &mut SqliteConnection,
+    tag_filter: &[String],
+    tree_name: Option<String>,
+    range: &TimeRange,
+    output: OutputFormat,
+) -> Result<(), Box<dyn Error>> {
+    // the given tree should exist, even if no time was spent on it
+    if let Some(tree_name) = &tree_name {
+        let query_result = sqlx::query!(
+            r#"
+            SELECT name
+            FROM tree
+            WHERE name = ?;
+            "#,
+            tree_name,
+        )
+        .fetch_optional(&mut *conn)
+        .await;
+
+        // error handling
+        match query_result {
+            Ok(Some(_)) => (),
+            Ok(None) => return Err(format!("Tree '{tree_name}' not found").into()),
+            Err(query_error) => panic!("Database query failed: {query_error}"),
+        }
+    }
+
+    // get total time spent on every tree
+    let filter_by_tag = !tag_filter.is_empty();
+    let tags_json = tag::json_array(tag_filter);
+    let query_result = sqlx::query!(
+        r#"
+        -- get total time spent on each tree
+
+        -- frames are clipped to the report range in the local time at which they were recorded,
+        -- and running frames count up to now
+        SELECT
+            tree_name as name,
+            SUM(MIN(COALESCE(f.local_end, ? + f.shift), ?) - MAX(f.local_start, ?))
+                AS "total_time_spent: i64"
+        FROM zoned_frame f
+        RIGHT JOIN task t
+            ON f.task_id = t.id AND
+            f.local_start < ? AND COALESCE(f.local_end, ? + f.shift) > ?
+        WHERE
+            (? IS NULL OR t.tree_name = ?) AND (
+                -- if tags are given, only keep tasks that have one of the tags or
+                -- which have an ancestor with one of the tags
+                NOT ? OR EXISTS (
+                    SELECT *
+                    FROM task a INNER JOIN task_tag tt ON tt.task_id = a.id
+                    WHERE
+                        a.tree_name = t.tree_name AND
+                        a."left" <= t."left" AND t."right" <= a."right" AND
+                        tt.tag_name IN (SELECT value FROM json_each(?))
+                )
+            )
+        GROUP BY tree_name;
+        "#,
+        range.now,
+        range.local_to,
+        range.local_from,
+        range.local_to,
+        range.now,
+        range.local_from,
+        tree_name,
+        tree_name,
+        filter_by_tag,
+        tags_json,
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    // error handling
+    let records = match query_result {
+        Ok(records) => records,
+        Err(query_error) => panic!("Database query failed: {query_error}"),
+    };
+
+    // get time spent on every task, rolled up from its subtasks
+    let query_result = sqlx::query!(
+        r#"
+        SELECT
+            a.id, a.tree_name, a.name,
+            (
+                SELECT COUNT(*)
+                FROM task p
+                WHERE p.tree_name = a.tree_name AND p."left" < a."left" AND a."right" < p."right"
+            ) AS "depth!: i64",
+            SUM(MIN(COALESCE(f.local_end, ? + f.shift), ?) - MAX(f.local_start, ?))
+                AS "time_spent: i64"
+        FROM task a
+        INNER JOIN task t
+            ON t.tree_name = a.tree_name AND a."left" <= t."left" AND t."right" <= a."right"
+        INNER JOIN zoned_frame f
+            ON f.task_id = t.id AND
+            f.local_start < ? AND COALESCE(f.local_end, ? + f.shift) > ?
+        WHERE
+            a."left" != 1 AND
+            (? IS NULL OR a.tree_name = ?) AND (
+                -- same tag filtering as above
+                NOT ? OR EXISTS (
+                    SELECT *
+                    FROM task b INNER JOIN task_tag tt ON tt.task_id = b.id
+                    WHERE
+                        b.tree_name = t.tree_name AND
+                        b."left" <= t."left" AND t."right" <= b."right" AND
+                        tt.tag_name IN (SELECT value FROM json_each(?))
+                )
+            )
+        GROUP BY a.id
+        ORDER BY a.tree_name, a."left";
+        "#,
+        range.now,
+        range.local_to,
+        range.local_from,
+        range.local_to,
+        range.now,
+        range.local_from,
+        tree_name,
+        tree_name,
+        filter_by_tag,
+        tags_json,
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    // error handling
+    let tasks = match query_result {
+        Ok(records) => records,
+        Err(query_error) => panic!("Database query failed: {query_error}"),
+    };
+
+    let break_times = break_times(conn, tag_filter, tree_name.as_ref(), range).await;
+
+    let trees = records
+        .into_iter()
+        .map(|tree| TreeTime {
+            tasks: tasks
+                .iter()
+                .filter(|task| task.tree_name == tree.name)
+                .filter_map(|task| {
+                    task.time_spent.map(|time_spent| TaskTime {
+                        uid: task.id.clone(),
+                        name: task.name.clone(),
+                        depth: task.depth,
+                        seconds: time_spent / 1000,
+                    })
+                })
+                .collect(),
+            seconds: tree.total_time_spent.unwrap_or(0) / 1000,
+            break_seconds: break_times.get(&tree.name).copied().unwrap_or(0) / 1000,
+            name: tree.name,
+        })
+        .collect();
+
+    output::print(
+        &TreeReport {
+            bounds: range.bounds(),
+            trees,
+        },
+        output,
+    )
+}
+
+/// Prints the tasks of a tree along with the time spent on each task and on its subtasks
+///
+/// Tasks on which no time was spent are not displayed. Tasks deeper than `max_depth` are not
+/// displayed either, and the time spent on them is counted as time spent on their displayed
+/// ancestor.
+///
+/// # Errors
+/// Returns an error if the given tree does not exist in forest
+///
+/// # Panics
+/// This function may panic if database operations fail
+async fn report_by_task(
+    conn: &mut SqliteConnection,
+    tag_filter: &[String],
+    tree_name: &String,
+    max_depth: Option<u32>,
+    range: &TimeRange,
+    output: OutputFormat,
+) -> Result<(), Box<dyn Error>> {
+    // get time spent on each task of the tree, and on each subtree
+    let filter_by_tag = !tag_filter.is_empty();
+    let tags_json = tag::json_array(tag_filter);
+    let query_result = sqlx::query!(
+        r#"
+        WITH counted_frame AS (
+            -- frames of the tree that are counted: if tags are given, only keep frames of tasks
+            -- that have one of the tags or which have an ancestor with one of the tags
+            -- frames are clipped to the report range in the local time at which they were
+            -- recorded, and running frames count up to now
+            SELECT
+                f.task_id,
+                MIN(COALESCE(f.local_end, ? + f.shift), ?) - MAX(f.local_start, ?) AS duration
+            FROM zoned_frame f INNER JOIN task t ON f.task_id = t.id
+            WHERE
+                f.local_start < ? AND COALESCE(f.local_end, ? + f.shift) > ? AND
+                t.tree_name = ? AND (
+                    NOT ? OR EXISTS (
+                        SELECT *
+                        FROM task a INNER JOIN task_tag tt ON tt.task_id = a.id
+                        WHERE
+                            a.tree_name = t.tree_name AND
+                            a."left" <= t."left" AND t."right" <= a."right" AND
+                            tt.tag_name IN (SELECT value FROM json_each(?))
+                    )
+                )
+        )
+        SELECT
+            t.id, t.name, t."left", t."right",
+            (
+                SELECT SUM(cf.duration)
+                FROM counted_frame cf
+                WHERE cf.task_id = t.id
+            ) AS "own_time: i64",
+            (
+                SELECT SUM(cf.duration)
+                FROM counted_frame cf INNER JOIN task d ON cf.task_id = d.id
+                WHERE t."left" <= d."left" AND d."right" <= t."right"
+            ) AS "subtree_time: i64"
+        FROM task t
+        WHERE t.tree_name = ?
+        ORDER BY t."left";
+        "#,
+        range.now,
+        range.local_to,
+        range.local_from,
+        range.local_to,
+        range.now,
+        range.local_from,
+        tree_name,
+        filter_by_tag,
+        tags_json,
+        tree_name,
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    // error handling
+    let tasks = match query_result {
+        Ok(records) => {
+            if records.is_empty() {
+                return Err(format!("Tree '{tree_name}' not found").into());
+            }
+            records
+        }
+        Err(query_error) => panic!("Database query failed: {query_error}"),
+    };
+
+    // compute the depth of each task, the root task being at depth 0
+    let mut depths: Vec<u32> = Vec::with_capacity(tasks.len());
+    let mut ancestors: Vec<i64> = Vec::new();
+    for task in &tasks {
+        while ancestors.last().is_some_and(|&right| right < task.left) {
+            ancestors.pop();
+        }
+        depths.push(u32::try_from(ancestors.len()).unwrap_or(u32::MAX));
+        ancestors.push(task.right);
+    }
+
+    // only display the root and the tasks on which time was spent, down to the maximum depth
+    let displayed: Vec<usize> = (0..tasks.len())
+        .filter(|&index| {
+            index == 0
+                || (tasks[index].subtree_time.unwrap_or(0) > 0
+                    && max_depth.is_none_or(|max_depth| depths[index] <= max_depth))
+        })
+        .collect();
+
+    // the parent of each task is its closest displayed ancestor, the root having no parent
+    let mut report_tasks: Vec<TaskReportItem> = Vec::new();
+    let mut ancestors: Vec<usize> = Vec::new();
+    for &index in &displayed {
+        let task = &tasks[index];
+        while ancestors
+            .last()
+            .is_some_and(|&ancestor| tasks[ancestor].right < task.left)
+        {
+            ancestors.pop();
+        }
+        if index != 0 {
+            let subtree_time = task.subtree_time.unwrap_or(0);
+
+            // collapsed tasks count as time spent on their deepest displayed ancestor
+            let own_time = if max_depth.is_some_and(|max_depth| depths[index] == max_depth) {
+                subtree_time
+            } else {
+                task.own_time.unwrap_or(0)
+            };
+
+            report_tasks.push(TaskReportItem {
+                uid: task.id.clone(),
+                name: task.name.clone(),
+                parent: ancestors
+                    .last()
+                    .filter(|&&ancestor| ancestor != 0)
+                    .map(|&ancestor| tasks[ancestor].id.clone()),
+                depth: depths[index],
+                seconds: subtree_time / 1000,
+                own_seconds: own_time / 1000,
+                bounds: (task.left, task.right),
+            });
+        }
+        ancestors.push(index);
+    }
+
+    let break_times = break_times(conn, tag_filter, Some(tree_name), range).await;
+
+    output::print(
+        &TaskReport {
+            tree: tree_name.clone(),
+            bounds: range.bounds(),
+            seconds: tasks[0].subtree_time.unwrap_or(0) / 1000,
+            break_seconds: break_times.get(tree_name).copied().unwrap_or(0) / 1000,
+            tasks: report_tasks,
+        },
+        output,
+    )
 }
@@ -1,8 +1,12 @@
+use serde::Serialize;
+use sqlx::SqliteConnection;
 use std::error::Error;
 
 use super::ansi;
 use super::dbutils;
-use forest_types::{ListFormat, Uid};
+use super::output::{self, Render};
+use super::tag;
+use forest_types::{ListFormat, OutputFormat, Uid};
 
 /// Adds a tree to the forest
 ///
@@ -91,6 +95,54 @@ pub async fn add(name: String, description: String, edit: bool) -> Result<(), Bo
     Ok(())
 }
 
+/// Adds a tree of the given name to the forest along with its root, if it does not exist yet
+///
+/// Returns whether the tree was added.
+///
+/// # Panics
+/// This function may panic if database operations fail
+pub(super) async fn create_if_not_exist(conn: &mut SqliteConnection, name: &String) -> bool {
+    let query_result = sqlx::query!(
+        r#"
+        INSERT OR IGNORE INTO tree("name", "description")
+        VALUES (?, "")
+        "#,
+        name,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    // error handling
+    match query_result {
+        Ok(result) => {
+            if result.rows_affected() == 0 {
+                return false;
+            }
+        }
+        Err(query_error) => panic!("Database query failed: {query_error}"),
+    }
+
+    // add new tree root to task table
+    let new_task_uid = Uid::new();
+    let query_result = sqlx::query!(
+        r#"
+        INSERT INTO task("id", "tree_name", "left", "right", "name", "description")
+        VALUES (?, ?, 1, 2, "", "")
+        "#,
+        new_task_uid,
+        name,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    // error handling
+    if let Err(query_error) = query_result {
+        panic!("Database query failed: {query_error}");
+    }
+
+    true
+}
+
 /// Removes the given tree
 ///
 /// # Errors
@@ -130,6 +182,8 @@ pub async fn remove(name: &String) -> Result<(), Box<dyn Error>> {
         Err(query_error) => panic!("Database query failed: {query_error}"),
     }
 
+    tag::remove_unused(&mut conn).await;
+
     println!(
         "Removed tree {}",
         ansi::format(name, ansi::ForestFormat::TreeName)
@@ -138,6 +192,102 @@ pub async fn remove(name: &String) -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+/// Listing of the trees of the forest
+#[derive(Serialize)]
+struct TreeList {
+    trees: Vec<TreeListItem>,
+
+    #[serde(skip)]
+    format: ListFormat,
+}
+
+/// Tree of the forest along with its first task
+#[derive(Serialize)]
+struct TreeListItem {
+    name: String,
+    current: bool,
+    next_task: Option<NextTask>,
+}
+
+/// First task of a tree
+#[derive(Serialize)]
+struct NextTask {
+    uid: String,
+    name: String,
+}
+
+/// Flat record of a tree listing
+#[derive(Serialize)]
+struct TreeListRow {
+    name: String,
+    current: bool,
+    next_task_uid: Option<String>,
+    next_task_name: Option<String>,
+}
+
+impl Render for TreeList {
+    type Row = TreeListRow;
+
+    fn print_text(&self) {
+        // display depends on formatting config
+        match self.format {
+            ListFormat::Short => {
+                for tree in &self.trees {
+                    // in short formatting, only display tree names
+                    // identify current tree
+                    if tree.current {
+                        print!("* ");
+                    } else {
+                        print!("  ");
+                    }
+                    println!("{}", ansi::format(&tree.name, ansi::ForestFormat::TreeName));
+                    println!();
+                }
+            }
+            ListFormat::Long => {
+                for tree in &self.trees {
+                    // print tree name
+                    if tree.current {
+                        print!("* ");
+                    } else {
+                        print!("  ");
+                    }
+
+                    println!("{}", ansi::format(&tree.name, ansi::ForestFormat::TreeName));
+
+                    // print first tasks
+                    if let Some(task) = &tree.next_task {
+                        print!(
+                            "    Next task: {} {}",
+                            ansi::format(
+                                Uid::try_from(task.uid.clone()).unwrap().short(),
+                                ansi::ForestFormat::TaskName
+                            ),
+                            ansi::format(&task.name, ansi::ForestFormat::TaskName)
+                        );
+                        print!(" ({})", ansi::format(&task.uid, ansi::ForestFormat::Uid));
+                        println!();
+                        println!();
+                    }
+                }
+                println!();
+            }
+        }
+    }
+
+    fn rows(&self) -> Vec<Self::Row> {
+        self.trees
+            .iter()
+            .map(|tree| TreeListRow {
+                name: tree.name.clone(),
+                current: tree.current,
+                next_task_uid: tree.next_task.as_ref().map(|task| task.uid.clone()),
+                next_task_name: tree.next_task.as_ref().map(|task| task.name.clone()),
+            })
+            .collect()
+    }
+}
+
 /// Prints the current status of the forest
 ///
 /// # Errors
@@ -145,7 +295,7 @@ pub async fn remove(name: &String) -> Result<(), Box<dyn Error>> {
 ///
 /// # Panics
 /// This function may panic if database operations fail
-pub async fn list(format: ListFormat) -> Result<(), Box<dyn Error>> {
+pub async fn list(format: ListFormat, output: OutputFormat) -> Result<(), Box<dyn Error>> {
     let pool = dbutils::load_db().await;
 
     let current_tree_name = dbutils::get_current_tree_name(&pool).await?;
@@ -175,61 +325,24 @@ pub async fn list(format: ListFormat) -> Result<(), Box<dyn Error>> {
         Err(query_error) => panic!("Database query failed: {query_error}"),
     };
 
-    // display depends on formatting config
-    match format {
-        ListFormat::Short => {
-            for task in tasks {
-                // in short formatting, only display tree names
-                if task.left == 1 {
-                    // identify current tree
-                    if task.tree_name.eq(&current_tree_name) {
-                        print!("* ");
-                    } else {
-                        print!("  ");
-                    }
-                    println!(
-                        "{}",
-                        ansi::format(&task.tree_name, ansi::ForestFormat::TreeName)
-                    );
-                    println!();
-                }
-            }
-        }
-        ListFormat::Long => {
-            for task in tasks {
-                // print tree name
-                if task.left == 1 {
-                    if task.tree_name.eq(&current_tree_name) {
-                        print!("* ");
-                    } else {
-                        print!("  ");
-                    }
-
-                    println!(
-                        "{}",
-                        ansi::format(&task.tree_name, ansi::ForestFormat::TreeName)
-                    );
-
-                // print first tasks
-                } else {
-                    print!(
-                        "    Next task: {} {}",
-                        ansi::format(
-                            Uid::try_from(task.id.clone()).unwrap().short(),
-                            ansi::ForestFormat::TaskName
-                        ),
-                        ansi::format(&task.name, ansi::ForestFormat::TaskName)
-                    );
-                    print!(" ({})", ansi::format(&task.id, ansi::ForestFormat::Uid));
-                    println!();
-                    println!();
-                }
-            }
-            println!();
+    // root tasks start a new tree, and first tasks are attached to the last tree
+    let mut trees: Vec<TreeListItem> = Vec::new();
+    for task in tasks {
+        if task.left == 1 {
+            trees.push(TreeListItem {
+                current: task.tree_name.eq(&current_tree_name),
+                name: task.tree_name,
+                next_task: None,
+            });
+        } else if let Some(tree) = trees.last_mut() {
+            tree.next_task = Some(NextTask {
+                uid: task.id,
+                name: task.name,
+            });
         }
     }
 
-    Ok(())
+    output::print(&TreeList { trees, format }, output)
 }
 
 /// Prints the description of the given tree
@@ -443,5 +556,16 @@ pub async fn switch(name: &String) -> Result<(), Box<dyn Error>> {
         ansi::format(name, ansi::ForestFormat::TreeName)
     );
 
+    // the switch does not apply where a `.forest` file pins another tree
+    if let Ok(Some(context)) = dbutils::find_directory_context() {
+        if context.tree_name != *name {
+            println!(
+                "Note: tree {} stays current here, as pinned by {}",
+                ansi::format(&context.tree_name, ansi::ForestFormat::TreeName),
+                context.path.display()
+            );
+        }
+    }
+
     Ok(())
 }
@@ -1,30 +1,53 @@
-use clap::Parser;
+use clap::{CommandFactory, Parser};
+use clap_complete::CompleteEnv;
+use forest_types::TaskStatus;
 use std::process;
 
 mod cli;
+mod completion;
 mod forest;
 
 #[tokio::main]
 async fn main() {
+    // answer dynamic completion requests from the shell, if any
+    CompleteEnv::with_factory(|| completion::add_completers(cli::Cli::command())).complete();
+
     let cli_parser = cli::Cli::parse();
 
+    if let Some(name) = cli_parser.forest {
+        forest::profile::select(name).unwrap_or_else(|e| {
+            eprintln!("forest: {e}");
+            process::exit(1);
+        });
+    }
+
     match cli_parser.command {
         cli::Commands::Task { command } => match command {
-            cli::TaskCommands::List => forest::task::list().await.unwrap_or_else(|e| {
-                eprintln!("list: {e}");
-                process::exit(1);
-            }),
+            cli::TaskCommands::List { status, tags } => {
+                forest::task::list(&status, &tags, cli_parser.output)
+                    .await
+                    .unwrap_or_else(|e| {
+                        eprintln!("list: {e}");
+                        process::exit(1);
+                    })
+            }
 
             cli::TaskCommands::Add {
                 name,
                 parent_uid,
                 description,
                 edit,
+                due,
+                scheduled,
+                recurrence,
             } => forest::task::add(
                 name,
                 parent_uid.as_ref(),
                 description.unwrap_or_default(),
                 edit,
+                due,
+                scheduled,
+                recurrence,
             )
             .await
             .unwrap_or_else(|e| {
@@ -32,6 +55,17 @@ async fn main() {
                 process::exit(1);
             }),
 
+            cli::TaskCommands::Import {
+                format,
+                file,
+                parent_uid,
+            } => forest::task::import(format, &file, parent_uid.as_ref())
+                .await
+                .unwrap_or_else(|e| {
+                    eprintln!("import: {e}");
+                    process::exit(1);
+                }),
+
             cli::TaskCommands::Remove { uid } => {
                 forest::task::remove(&uid).await.unwrap_or_else(|e| {
                     eprintln!("remove: {e}");
@@ -70,10 +104,134 @@ async fn main() {
                         process::exit(1);
                     });
             }
+
+            cli::TaskCommands::Done { uid, recursive } => {
+                forest::task::set_status(&uid, TaskStatus::Done, recursive)
+                    .await
+                    .unwrap_or_else(|e| {
+                        eprintln!("done: {e}");
+                        process::exit(1);
+                    });
+            }
+
+            cli::TaskCommands::Start { uid } => {
+                forest::task::set_status(&uid, TaskStatus::InProgress, false)
+                    .await
+                    .unwrap_or_else(|e| {
+                        eprintln!("start: {e}");
+                        process::exit(1);
+                    });
+            }
+
+            cli::TaskCommands::Cancel { uid, recursive } => {
+                forest::task::set_status(&uid, TaskStatus::Cancelled, recursive)
+                    .await
+                    .unwrap_or_else(|e| {
+                        eprintln!("cancel: {e}");
+                        process::exit(1);
+                    });
+            }
+
+            cli::TaskCommands::Reopen { uid } => {
+                forest::task::set_status(&uid, TaskStatus::Todo, false)
+                    .await
+                    .unwrap_or_else(|e| {
+                        eprintln!("reopen: {e}");
+                        process::exit(1);
+                    });
+            }
+
+            cli::TaskCommands::Move {
+                uid,
+                tree_name,
+                parent_uid,
+                position,
+            } => {
+                forest::task::move_subtree(&uid, tree_name.as_ref(), parent_uid.as_ref(), position)
+                    .await
+                    .unwrap_or_else(|e| {
+                        eprintln!("move: {e}");
+                        process::exit(1);
+                    });
+            }
+
+            cli::TaskCommands::Copy {
+                uid,
+                tree_name,
+                parent_uid,
+                position,
+            } => {
+                forest::task::copy_subtree(&uid, tree_name.as_ref(), parent_uid.as_ref(), position)
+                    .await
+                    .unwrap_or_else(|e| {
+                        eprintln!("copy: {e}");
+                        process::exit(1);
+                    });
+            }
+
+            cli::TaskCommands::Depend { uid, blocker_uid } => {
+                forest::task::depend(&uid, &blocker_uid)
+                    .await
+                    .unwrap_or_else(|e| {
+                        eprintln!("depend: {e}");
+                        process::exit(1);
+                    });
+            }
+
+            cli::TaskCommands::Undepend { uid, blocker_uid } => {
+                forest::task::undepend(&uid, &blocker_uid)
+                    .await
+                    .unwrap_or_else(|e| {
+                        eprintln!("undepend: {e}");
+                        process::exit(1);
+                    });
+            }
+
+            cli::TaskCommands::Recur {
+                uid,
+                recurrence,
+                clear,
+            } => {
+                let recurrence = if clear { None } else { recurrence };
+                forest::task::recur(&uid, recurrence)
+                    .await
+                    .unwrap_or_else(|e| {
+                        eprintln!("recur: {e}");
+                        process::exit(1);
+                    });
+            }
+
+            cli::TaskCommands::Due {
+                uid,
+                due,
+                scheduled,
+                clear,
+            } => {
+                forest::task::due(&uid, due, scheduled, clear)
+                    .await
+                    .unwrap_or_else(|e| {
+                        eprintln!("due: {e}");
+                        process::exit(1);
+                    });
+            }
+
+            cli::TaskCommands::Tag { uid, tags } => {
+                forest::task::tag(&uid, &tags).await.unwrap_or_else(|e| {
+                    eprintln!("tag: {e}");
+                    process::exit(1);
+                });
+            }
+
+            cli::TaskCommands::Untag { uid, tags } => {
+                forest::task::untag(&uid, &tags).await.unwrap_or_else(|e| {
+                    eprintln!("untag: {e}");
+                    process::exit(1);
+                });
+            }
         },
         cli::Commands::Tree { command } => match command {
             cli::TreeCommands::List { format } => {
-                forest::tree::list(format.unwrap_or_default())
+                forest::tree::list(format.unwrap_or_default(), cli_parser.output)
                     .await
                     .unwrap_or_else(|e| {
                         eprintln!("list: {e}");
@@ -126,8 +284,11 @@ async fn main() {
         },
 
         cli::Commands::Note { command } => match command {
-            cli::NoteCommands::List { show_time_tracking } => {
-                forest::notetaking::list(show_time_tracking)
+            cli::NoteCommands::List {
+                show_time_tracking,
+                tags,
+            } => {
+                forest::notetaking::list(show_time_tracking, &tags, cli_parser.output)
                     .await
                     .unwrap_or_else(|e| {
                         eprintln!("note list: {e}");
@@ -136,7 +297,7 @@ async fn main() {
             }
 
             cli::NoteCommands::Add { tree_name } => {
-                forest::notetaking::add(tree_name, false)
+                forest::notetaking::add(tree_name, None)
                     .await
                     .unwrap_or_else(|e| {
                         eprintln!("note add: {e}");
@@ -162,6 +323,24 @@ async fn main() {
                     process::exit(1);
                 });
             }
+
+            cli::NoteCommands::Tag { uid, tags } => {
+                forest::notetaking::tag(&uid, &tags)
+                    .await
+                    .unwrap_or_else(|e| {
+                        eprintln!("note tag: {e}");
+                        process::exit(1);
+                    });
+            }
+
+            cli::NoteCommands::Untag { uid, tags } => {
+                forest::notetaking::untag(&uid, &tags)
+                    .await
+                    .unwrap_or_else(|e| {
+                        eprintln!("note untag: {e}");
+                        process::exit(1);
+                    });
+            }
         },
 
         cli::Commands::Switch { name } => {
@@ -171,8 +350,13 @@ async fn main() {
             });
         }
 
-        cli::Commands::Start { tree_name, at } => {
-            forest::timetracking::start(tree_name, at)
+        cli::Commands::Start {
+            target,
+            task,
+            at,
+            adjust,
+        } => {
+            forest::timetracking::start(target, task, at, adjust)
                 .await
                 .unwrap_or_else(|e| {
                     eprintln!("start: {e}");
@@ -190,15 +374,254 @@ async fn main() {
                     process::exit(1);
                 });
         }
-        cli::Commands::Status => {
-            forest::timetracking::status().await.unwrap_or_else(|e| {
-                eprintln!("status: {e}");
+        cli::Commands::Cancel => {
+            forest::timetracking::cancel().await.unwrap_or_else(|e| {
+                eprintln!("cancel: {e}");
+                process::exit(1);
+            });
+        }
+
+        cli::Commands::Restart { at, adjust } => {
+            forest::timetracking::restart(at, adjust)
+                .await
+                .unwrap_or_else(|e| {
+                    eprintln!("restart: {e}");
+                    process::exit(1);
+                });
+        }
+
+        cli::Commands::Import {
+            from,
+            file,
+            dry_run,
+        } => {
+            forest::import::import(from, &file, dry_run)
+                .await
+                .unwrap_or_else(|e| {
+                    eprintln!("import: {e}");
+                    process::exit(1);
+                });
+        }
+
+        cli::Commands::Backup { file } => {
+            forest::backup::backup(&file).await.unwrap_or_else(|e| {
+                eprintln!("backup: {e}");
                 process::exit(1);
             });
         }
 
-        cli::Commands::Report => {
-            forest::timetracking::report().await;
+        cli::Commands::Restore { file, mode } => {
+            forest::backup::restore(&file, mode)
+                .await
+                .unwrap_or_else(|e| {
+                    eprintln!("restore: {e}");
+                    process::exit(1);
+                });
+        }
+
+        cli::Commands::Profile { command } => match command {
+            cli::ProfileCommands::List => {
+                forest::profile::list(cli_parser.output).unwrap_or_else(|e| {
+                    eprintln!("profile list: {e}");
+                    process::exit(1);
+                });
+            }
+            cli::ProfileCommands::Create { name } => {
+                forest::profile::create(&name).unwrap_or_else(|e| {
+                    eprintln!("profile create: {e}");
+                    process::exit(1);
+                });
+            }
+            cli::ProfileCommands::Use { name } => {
+                forest::profile::use_profile(&name).unwrap_or_else(|e| {
+                    eprintln!("profile use: {e}");
+                    process::exit(1);
+                });
+            }
+        },
+
+        cli::Commands::Db { command } => match command {
+            cli::DbCommands::Migrate => {
+                forest::db::migrate().await.unwrap_or_else(|e| {
+                    eprintln!("db migrate: {e}");
+                    process::exit(1);
+                });
+            }
+            cli::DbCommands::Status => {
+                forest::db::status(cli_parser.output)
+                    .await
+                    .unwrap_or_else(|e| {
+                        eprintln!("db status: {e}");
+                        process::exit(1);
+                    });
+            }
+        },
+
+        cli::Commands::Export { command } => match command {
+            cli::ExportCommands::Ics { from, to } => {
+                forest::export::ics(from, to).await.unwrap_or_else(|e| {
+                    eprintln!("export ics: {e}");
+                    process::exit(1);
+                });
+            }
+        },
+
+        cli::Commands::Pause { at } => {
+            forest::timetracking::pause(at).await.unwrap_or_else(|e| {
+                eprintln!("pause: {e}");
+                process::exit(1);
+            });
+        }
+
+        cli::Commands::Resume { at } => {
+            forest::timetracking::resume(at).await.unwrap_or_else(|e| {
+                eprintln!("resume: {e}");
+                process::exit(1);
+            });
+        }
+
+        cli::Commands::Status => {
+            forest::timetracking::status(cli_parser.output)
+                .await
+                .unwrap_or_else(|e| {
+                    eprintln!("status: {e}");
+                    process::exit(1);
+                });
+        }
+
+        cli::Commands::Report {
+            tags,
+            tree_name,
+            by,
+            depth,
+            from,
+            grid: Some(grid),
+            round,
+            round_mode,
+            ..
+        } => {
+            let grid = forest::timesheet::Grid {
+                period: grid,
+                anchor: from,
+                round: round.map(|round| (round, round_mode)),
+            };
+            forest::timesheet::grid(grid, &tags, tree_name, by, depth, cli_parser.output)
+                .await
+                .unwrap_or_else(|e| {
+                    eprintln!("report: {e}");
+                    process::exit(1);
+                });
+        }
+
+        cli::Commands::Report {
+            tags,
+            tree_name,
+            by,
+            depth,
+            from,
+            to,
+            today,
+            week,
+            month,
+            grid: None,
+            ..
+        } => {
+            let period = if today {
+                forest::timetracking::Period::Today
+            } else if week {
+                forest::timetracking::Period::Week
+            } else if month {
+                forest::timetracking::Period::Month
+            } else {
+                forest::timetracking::Period::Between(from, to)
+            };
+            forest::timetracking::report(&tags, tree_name, by, depth, period, cli_parser.output)
+                .await
+                .unwrap_or_else(|e| {
+                    eprintln!("report: {e}");
+                    process::exit(1);
+                });
+        }
+
+        cli::Commands::Frame { command } => match command {
+            cli::FrameCommands::List {
+                tree_name,
+                from,
+                to,
+            } => forest::frame::list(tree_name, from, to, cli_parser.output)
+                .await
+                .unwrap_or_else(|e| {
+                    eprintln!("list: {e}");
+                    process::exit(1);
+                }),
+
+            cli::FrameCommands::Add {
+                target,
+                task,
+                start,
+                end,
+                adjust,
+            } => forest::frame::add(target, task, start, end, adjust)
+                .await
+                .unwrap_or_else(|e| {
+                    eprintln!("add: {e}");
+                    process::exit(1);
+                }),
+
+            cli::FrameCommands::Edit {
+                uid,
+                start,
+                end,
+                tree_name,
+                task,
+                adjust,
+            } => forest::frame::edit(&uid, start, end, tree_name, task, adjust)
+                .await
+                .unwrap_or_else(|e| {
+                    eprintln!("edit: {e}");
+                    process::exit(1);
+                }),
+
+            cli::FrameCommands::Remove { uid } => {
+                forest::frame::remove(&uid).await.unwrap_or_else(|e| {
+                    eprintln!("remove: {e}");
+                    process::exit(1);
+                })
+            }
+
+            cli::FrameCommands::Split { uid, at } => {
+                forest::frame::split(&uid, at).await.unwrap_or_else(|e| {
+                    eprintln!("split: {e}");
+                    process::exit(1);
+                })
+            }
+
+            cli::FrameCommands::Merge {
+                uid,
+                other_uid,
+                adjust,
+            } => forest::frame::merge(&uid, &other_uid, adjust)
+                .await
+                .unwrap_or_else(|e| {
+                    eprintln!("merge: {e}");
+                    process::exit(1);
+                }),
+        },
+
+        cli::Commands::Config { command } => match command {
+            cli::ConfigCommands::WeekStart { day } => {
+                forest::config::week_start(day).await.unwrap_or_else(|e| {
+                    eprintln!("config: {e}");
+                    process::exit(1);
+                });
+            }
+        },
+
+        cli::Commands::Agenda { days } => {
+            forest::agenda::show(days).await.unwrap_or_else(|e| {
+                eprintln!("agenda: {e}");
+                process::exit(1);
+            });
         }
     }
 }